@@ -22,6 +22,7 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.schema = table.schema.clone();
 
         for column in table
             .columns
@@ -41,32 +42,17 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
             ));
         }
 
-        for index in &table.indices {
+        // Expression and partial indexes cannot be represented in the datamodel.
+        for index in table.indices.iter().filter(|index| index.is_plain()) {
             let fk_on_index = table
                 .foreign_keys
                 .iter()
                 .find(|fk| columns_match(&fk.columns, &index.columns));
 
-            let compound_name = || {
-                model
-                    .fields
-                    .iter()
-                    .find(|f| {
-                        !f.database_names.is_empty()
-                            && columns_match(&f.database_names, &index.columns)
-                    })
-                    .expect("Error finding field matching a compound index.")
-                    .name
-                    .clone()
-            };
-
             let index_to_add = match (fk_on_index, index.columns.len(), index.is_unique()) {
                 (Some(_), _, true) => None, // just make the relation 1:1 and dont print the unique index
-                (Some(_), 1, false) => Some(calculate_index(index)),
-                (Some(_), _, false) => Some(calculate_compound_index(index, compound_name())),
                 (None, 1, true) => None, // this is expressed by the @unique already
-                (None, _, true) => Some(calculate_index(index)),
-                (None, _, false) => Some(calculate_index(index)),
+                _ => Some(calculate_index(index, &model)),
             };
 
             index_to_add.map(|i| model.add_index(i));
@@ -80,6 +66,12 @@ pub fn calculate_model(schema: &SqlSchema) -> SqlIntrospectionResult<Datamodel>
     }
 
     for e in schema.enums.iter() {
+        // Enums aren't placed in schemas in the datamodel. The default schema is described first,
+        // so its enums win over the same-named enums of the other schemas.
+        if data_model.has_enum(&e.name) {
+            continue;
+        }
+
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
             values: e
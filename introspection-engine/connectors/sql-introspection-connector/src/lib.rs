@@ -21,6 +21,7 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    schemas: Vec<String>,
 }
 
 impl SqlIntrospectionConnector {
@@ -39,9 +40,16 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            schemas: Vec::new(),
         })
     }
 
+    /// Sets the additional schemas to introspect, besides the one in the connection string.
+    pub fn with_schemas(mut self, schemas: Vec<String>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlError>>) -> ConnectorResult<O> {
         fut.await.map_err(|sql_introspection_error| {
             sql_introspection_error.into_connector_error(&self.connection_info)
@@ -67,7 +75,7 @@ impl SqlIntrospectionConnector {
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
         Ok(self
             .describer
-            .describe(self.connection_info.schema_name())
+            .describe_with_schemas(self.connection_info.schema_name(), &self.schemas)
             .await?)
     }
 }
@@ -1,13 +1,13 @@
 use datamodel::{
-    DefaultValue as DMLDef, Field, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
+    ComputedValue, DefaultValue as DMLDef, Field, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
     RelationInfo, ScalarType, ScalarValue as SV, ValueGenerator as VG,
 };
 use log::debug;
 use once_cell::sync::Lazy;
 use regex::Regex;
 use sql_schema_describer::{
-    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType,
-    SqlSchema, Table,
+    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, GeneratedColumnKind,
+    Index, IndexMethod, IndexType, SortOrder, SqlSchema, Table,
 };
 
 //checks
@@ -103,29 +103,97 @@ pub fn calculate_many_to_many_field(
         is_updated_at: false,
         data_source_fields: vec![],
         is_commented_out: false,
+        computed: None,
     }
 }
 
-pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
+/// Indexes refer to the fields of the model, relation fields standing for all of their foreign
+/// key columns. Indexes that can not be expressed that way, e.g. because they cover only some
+/// of the columns of a foreign key, refer to the columns by name instead.
+pub(crate) fn calculate_index(index: &Index, model: &Model) -> IndexDefinition {
     debug!("Handling index  {:?}", index);
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
         IndexType::Normal => datamodel::dml::IndexType::Normal,
     };
+    let (fields, sort_orders) = match index_fields(index, model) {
+        Some(fields_and_sort_orders) => fields_and_sort_orders,
+        None => (index.columns.clone(), calculate_sort_orders(&index.sort_orders)),
+    };
     let index_definition: IndexDefinition = IndexDefinition {
         name: Some(index.name.clone()),
-        fields: index.columns.clone(),
+        fields,
         tpe,
+        sort_orders,
+        method: index.method.map(calculate_index_method),
     };
     index_definition
 }
 
-pub(crate) fn calculate_compound_index(index: &Index, name: String) -> IndexDefinition {
-    debug!("Handling compound index  {:?}", name);
-    IndexDefinition {
-        name: Some(index.name.clone()),
-        fields: vec![name],
-        tpe: datamodel::dml::IndexType::Normal,
+/// Matches the index columns, in order, to the fields of the model. A field covering several
+/// columns needs to have the same sort order on all of them.
+fn index_fields(index: &Index, model: &Model) -> Option<(Vec<String>, Vec<datamodel::dml::SortOrder>)> {
+    let mut fields = Vec::new();
+    let mut sort_orders = Vec::new();
+    let mut position = 0;
+
+    while position < index.columns.len() {
+        let remaining_columns = &index.columns[position..];
+        let (field, column_count) = model
+            .fields
+            .iter()
+            .map(|field| (field, field_column_names(field)))
+            .filter(|(_, columns)| remaining_columns.starts_with(columns))
+            .max_by_key(|(_, columns)| columns.len())
+            .map(|(field, columns)| (field, columns.len()))?;
+
+        let sort_order = index.sort_order(position);
+        if (position..position + column_count).any(|column| index.sort_order(column) != sort_order) {
+            return None;
+        }
+
+        fields.push(field.name.clone());
+        sort_orders.push(sort_order);
+        position += column_count;
+    }
+
+    // Like in the schema describer, all ascending sort orders are left out.
+    let sort_orders = match sort_orders.contains(&SortOrder::Desc) {
+        true => calculate_sort_orders(&sort_orders),
+        false => Vec::new(),
+    };
+
+    Some((fields, sort_orders))
+}
+
+/// The columns backing a field. Fields are named after their column unless they cover several.
+fn field_column_names(field: &Field) -> Vec<String> {
+    match field.database_names.is_empty() {
+        true => vec![field.name.clone()],
+        false => field.database_names.clone(),
+    }
+}
+
+fn calculate_sort_orders(sort_orders: &[SortOrder]) -> Vec<datamodel::dml::SortOrder> {
+    sort_orders
+        .iter()
+        .map(|sort_order| match sort_order {
+            SortOrder::Asc => datamodel::dml::SortOrder::Asc,
+            SortOrder::Desc => datamodel::dml::SortOrder::Desc,
+        })
+        .collect()
+}
+
+fn calculate_index_method(method: IndexMethod) -> datamodel::dml::IndexMethod {
+    match method {
+        IndexMethod::BTree => datamodel::dml::IndexMethod::BTree,
+        IndexMethod::Hash => datamodel::dml::IndexMethod::Hash,
+        IndexMethod::Gist => datamodel::dml::IndexMethod::Gist,
+        IndexMethod::Gin => datamodel::dml::IndexMethod::Gin,
+        IndexMethod::Brin => datamodel::dml::IndexMethod::Brin,
+        IndexMethod::SpGist => datamodel::dml::IndexMethod::SpGist,
+        IndexMethod::FullText => datamodel::dml::IndexMethod::FullText,
+        IndexMethod::Spatial => datamodel::dml::IndexMethod::Spatial,
     }
 }
 
@@ -152,6 +220,10 @@ pub(crate) fn calculate_scalar_field(schema: &SqlSchema, table: &Table, column:
     let is_id = is_id(&column, &table);
     let default_value = calculate_default(table, &column, &arity);
     let is_unique = table.is_column_unique(&column.name) && !is_id;
+    let computed = column.generated.as_ref().map(|generated| ComputedValue {
+        expression: escape_string_literal(&generated.expression),
+        stored: generated.kind == GeneratedColumnKind::Stored,
+    });
 
     Field {
         name: column.name.clone(),
@@ -166,6 +238,7 @@ pub(crate) fn calculate_scalar_field(schema: &SqlSchema, table: &Table, column:
         is_updated_at: false,
         data_source_fields: vec![],
         is_commented_out,
+        computed,
     }
 }
 
@@ -236,6 +309,7 @@ pub(crate) fn calculate_relation_field(
         is_updated_at: false,
         data_source_fields: vec![],
         is_commented_out: false,
+        computed: None,
     }
 }
 
@@ -298,6 +372,7 @@ pub(crate) fn calculate_backrelation_field(
         is_updated_at: false,
         data_source_fields: vec![],
         is_commented_out: false,
+        computed: None,
     }
 }
 
@@ -309,7 +384,9 @@ pub(crate) fn calculate_default(
     match (&column.default, &column.tpe.family) {
         (_, _) if *arity == FieldArity::List => None,
         (None, _) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
-        (Some(SQLDef::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
+        (Some(SQLDef::DBGENERATED(expr)), _) => Some(DMLDef::Expression(VG::new_dbgenerated(
+            escape_string_literal(expr),
+        ))),
         (Some(SQLDef::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::VALUE(val)), ColumnTypeFamily::Boolean) => match parse_int(val) {
             Some(x) => Some(DMLDef::Single(SV::Boolean(x != 0))),
@@ -328,7 +405,7 @@ pub(crate) fn calculate_default(
         }
         (Some(SQLDef::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
         (Some(SQLDef::VALUE(_)), ColumnTypeFamily::DateTime) => {
-            Some(DMLDef::Expression(VG::new_dbgenerated()))
+            Some(DMLDef::Expression(VG::new_dbgenerated(String::new())))
         } //todo parse datetime value
         (Some(SQLDef::VALUE(val)), ColumnTypeFamily::Enum(_)) => {
             Some(DMLDef::Single(SV::ConstantLiteral(val.into())))
@@ -337,6 +414,17 @@ pub(crate) fn calculate_default(
     }
 }
 
+/// Escapes a raw SQL expression so it can be rendered inside a datamodel string literal. Quotes,
+/// backslashes, line breaks, tabs and interpolation starts (`${`) can't appear unescaped there.
+fn escape_string_literal(expr: &str) -> String {
+    expr.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+        .replace("${", "\\${")
+}
+
 pub(crate) fn is_id(column: &Column, table: &Table) -> bool {
     table
         .primary_key
@@ -33,6 +33,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     .to_string(),
             ),
             is_embedded: false,
+            schema: None,
             is_generated: false,
             is_commented_out: true,
             indices: vec![],
@@ -65,16 +66,19 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out,
+                        computed: None,
                     }
                 })
                 .collect(),
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: col_types
                 .iter()
                 .map(|family| Column {
@@ -86,6 +90,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 })
                 .collect(),
             indices: vec![],
@@ -111,6 +116,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            schema: None,
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -126,6 +132,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "required".to_string(),
@@ -142,6 +149,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "list".to_string(),
@@ -156,6 +164,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
             ],
             is_generated: false,
@@ -163,11 +172,13 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "optional".to_string(),
@@ -178,6 +189,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "required".to_string(),
@@ -188,6 +200,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 },
                 Column {
                     name: "list".to_string(),
@@ -198,6 +211,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -223,6 +237,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            schema: None,
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -238,6 +253,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "int_default".to_string(),
@@ -252,6 +268,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "bool_default".to_string(),
@@ -266,6 +283,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "float_default".to_string(),
@@ -280,6 +298,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "string_default".to_string(),
@@ -296,6 +315,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
             ],
             is_generated: false,
@@ -303,15 +323,19 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: Some("unique".into()),
                 fields: vec!["no_default".into(), "int_default".into()],
                 tpe: dml::IndexType::Unique,
+                sort_orders: vec![],
+                method: None,
             }],
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "no_default".to_string(),
@@ -322,6 +346,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "int_default".to_string(),
@@ -332,6 +357,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some(DefaultValue::VALUE("'1'".to_string())),
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "bool_default".to_string(),
@@ -342,6 +368,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some(DefaultValue::VALUE("'1'".to_string())),
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "float_default".to_string(),
@@ -352,6 +379,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some(DefaultValue::VALUE("'1.0'".to_string())),
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "string_default".to_string(),
@@ -362,12 +390,17 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: Some(DefaultValue::VALUE("default".to_string())),
                     auto_increment: false,
+                    generated: None,
                 },
             ],
             indices: vec![Index {
                 name: "unique".to_string(),
                 columns: vec!["no_default".into(), "int_default".into()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
@@ -390,6 +423,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
@@ -406,6 +440,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 }],
                 is_generated: false,
                 indices: vec![],
@@ -417,6 +452,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
@@ -431,6 +467,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 }],
                 is_generated: false,
                 indices: vec![],
@@ -442,6 +479,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
@@ -458,6 +496,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 }],
                 is_generated: false,
                 indices: vec![],
@@ -465,12 +504,14 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
@@ -480,6 +521,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -490,6 +532,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
             },
             Table {
                 name: "Table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
@@ -499,6 +542,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -509,6 +553,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
             },
             Table {
                 name: "Table3".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
@@ -518,6 +563,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -547,6 +593,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            schema: None,
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -562,6 +609,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "unique".to_string(),
@@ -576,6 +624,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
             ],
             is_generated: false,
@@ -583,11 +632,13 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "non_unique".to_string(),
@@ -598,6 +649,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "unique".to_string(),
@@ -608,12 +660,17 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
             ],
             indices: vec![Index {
                 name: "unique".to_string(),
                 columns: vec!["unique".to_string()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
@@ -636,6 +693,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -653,6 +711,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                     Field {
                         name: "name".to_string(),
@@ -667,6 +726,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                 ],
                 is_generated: false,
@@ -678,6 +738,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -693,6 +754,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                     Field {
                         name: "city-id".to_string(),
@@ -712,6 +774,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                     Field {
                         name: "city-name".to_string(),
@@ -731,6 +794,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                 ],
                 is_generated: false,
@@ -739,12 +803,14 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -755,6 +821,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -765,6 +832,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
                     },
                 ],
                 indices: vec![],
@@ -776,6 +844,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -786,6 +855,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
                     },
                     Column {
                         name: "city-id".to_string(),
@@ -796,6 +866,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
                     },
                     Column {
                         name: "city-name".to_string(),
@@ -806,6 +877,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
                     },
                 ],
                 indices: vec![],
@@ -815,6 +887,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                     constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
@@ -836,6 +909,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            schema: None,
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -853,6 +927,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "name".to_string(),
@@ -867,6 +942,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
                 Field {
                     name: "lastname".to_string(),
@@ -881,6 +957,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_updated_at: false,
                     data_source_fields: vec![],
                     is_commented_out: false,
+                    computed: None,
                 },
             ],
             is_generated: false,
@@ -888,15 +965,19 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: Some("name_last_name_unique".to_string()),
                 fields: vec!["name".to_string(), "lastname".to_string()],
                 tpe: datamodel::dml::IndexType::Unique,
+                sort_orders: vec![],
+                method: None,
             }],
             id_fields: vec![],
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -907,6 +988,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 },
                 Column {
                     name: "name".to_string(),
@@ -917,6 +999,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "lastname".to_string(),
@@ -927,12 +1010,17 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
             ],
             indices: vec![Index {
                 name: "name_last_name_unique".to_string(),
                 columns: vec!["name".to_string(), "lastname".to_string()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             }],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -957,6 +1045,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -974,6 +1063,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                     Field {
                         name: "name".to_string(),
@@ -988,6 +1078,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                     Field {
                         name: "User".to_string(),
@@ -1007,6 +1098,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                 ],
                 is_generated: false,
@@ -1018,6 +1110,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                schema: None,
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -1035,6 +1128,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                     Field {
                         name: "city_id".to_string(),
@@ -1054,6 +1148,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_updated_at: false,
                         data_source_fields: vec![],
                         is_commented_out: false,
+                        computed: None,
                     },
                 ],
                 is_generated: false,
@@ -1062,12 +1157,14 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -1078,6 +1175,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -1088,6 +1186,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
                     },
                 ],
                 indices: vec![],
@@ -1099,6 +1198,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -1109,6 +1209,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
                     },
                     Column {
                         name: "city_id".to_string(),
@@ -1119,6 +1220,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
                     },
                 ],
                 indices: vec![],
@@ -1130,6 +1232,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                     constraint_name: None,
                     columns: vec!["city_id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
@@ -1162,6 +1265,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
                 },
             ],
         }],
+        composite_types: vec![],
     };
 
     let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1169,6 +1273,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![],
@@ -1177,3 +1282,93 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
 
     assert_eq!(data_model, ref_data_model);
 }
+
+#[test]
+fn indexes_on_foreign_key_columns_refer_to_the_relation_field() {
+    let column = |name: &str, family: ColumnTypeFamily| Column {
+        name: name.to_string(),
+        tpe: ColumnType {
+            raw: "".to_string(),
+            family,
+            arity: ColumnArity::Required,
+        },
+        default: None,
+        auto_increment: false,
+        generated: None,
+    };
+    let index = |name: &str, columns: &[&str]| Index {
+        name: name.to_string(),
+        columns: columns.iter().map(|column| column.to_string()).collect(),
+        tpe: IndexType::Normal,
+        sort_orders: vec![],
+        expressions: vec![],
+        predicate: None,
+        method: None,
+    };
+
+    let schema = SqlSchema {
+        tables: vec![
+            Table {
+                name: "City".to_string(),
+                schema: None,
+                columns: vec![
+                    column("id", ColumnTypeFamily::Int),
+                    column("name", ColumnTypeFamily::String),
+                ],
+                indices: vec![Index {
+                    tpe: IndexType::Unique,
+                    ..index("city_id_name", &["id", "name"])
+                }],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                foreign_keys: vec![],
+            },
+            Table {
+                name: "User".to_string(),
+                schema: None,
+                columns: vec![
+                    column("id", ColumnTypeFamily::Int),
+                    column("city_id", ColumnTypeFamily::Int),
+                    column("city_name", ColumnTypeFamily::String),
+                    column("created_at", ColumnTypeFamily::DateTime),
+                ],
+                indices: vec![
+                    index("user_city", &["city_id", "city_name"]),
+                    index("user_city_created_at", &["city_id", "city_name", "created_at"]),
+                    // Covers the foreign key columns in a different order than the foreign key.
+                    index("user_city_reversed", &["city_name", "city_id"]),
+                ],
+                primary_key: Some(PrimaryKey {
+                    columns: vec!["id".to_string()],
+                    sequence: None,
+                }),
+                foreign_keys: vec![ForeignKey {
+                    constraint_name: None,
+                    columns: vec!["city_id".to_string(), "city_name".to_string()],
+                    referenced_table: "City".to_string(),
+                    referenced_schema: None,
+                    on_delete_action: ForeignKeyAction::NoAction,
+                    referenced_columns: vec!["id".to_string(), "name".to_string()],
+                }],
+            },
+        ],
+        enums: vec![],
+        sequences: vec![],
+    };
+
+    let data_model = calculate_model(&schema).expect("calculate data model");
+    let index_fields: Vec<Vec<&str>> = data_model
+        .find_model("User")
+        .unwrap()
+        .indices
+        .iter()
+        .map(|index| index.fields.iter().map(String::as_str).collect())
+        .collect();
+
+    assert_eq!(
+        index_fields,
+        vec![vec!["City"], vec!["City", "created_at"], vec!["city_name", "city_id"]]
+    );
+}
@@ -349,7 +349,7 @@ async fn introspecting_default_values_should_work(api: &TestApi) {
                 string_char         String?         @default("abcdefgh")
                 string_text         String?         @default("abcdefgh")
                 string_varchar      String?         @default("abcd")
-                time_date           DateTime?       @default(dbgenerated())
+                time_date           DateTime?       @default(dbgenerated("CURRENT_DATE"))
                 time_time           DateTime?       @default(now())
                 time_timestamp      DateTime?       @default(now())
                 time_timestamptz    DateTime?       @default(now())
@@ -402,11 +402,11 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
                 enum_static             color?      @default(black)
                 float_static            Float?      @default(1.43)
                 id                      Int         @default(autoincrement()) @id
-                int_function            Int?        @default(dbgenerated())
-                int_sequence            Int?        @default(dbgenerated())
+                int_function            Int?        @default(dbgenerated("date_part('year'::text, '2001-02-16 20:38:40'::timestamp without time zone)"))
+                int_sequence            Int?        @default(dbgenerated("nextval('test_seq'::regclass)"))
                 int_serial              Int        @default(autoincrement())
                 int_static              Int?        @default(2)
-                string_function         String?     @default(dbgenerated())
+                string_function         String?     @default(dbgenerated("('Concatenated'::text || '\n'::text)"))
                 string_static_char      String?     @default("test")
                 string_static_text      String?     @default("test")
                 string_static_text_null String?     
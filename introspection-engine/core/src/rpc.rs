@@ -65,16 +65,14 @@ impl RpcImpl {
 
     async fn load_connector(schema: &String) -> Result<Box<dyn IntrospectionConnector>, Error> {
         let config = datamodel::parse_configuration(&schema)?;
-        let url = config
-            .datasources
-            .first()
-            .ok_or_else(|| {
-                CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema."))
-            })?
-            .url()
-            .to_owned()
-            .value;
-        Ok(Box::new(SqlIntrospectionConnector::new(&url).await?))
+        let source = config.datasources.first().ok_or_else(|| {
+            CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema."))
+        })?;
+        let connector = SqlIntrospectionConnector::new(&source.url().value)
+            .await?
+            .with_schemas(source.schemas().to_vec());
+
+        Ok(Box::new(connector))
     }
 
     pub async fn introspect_internal(schema: String) -> RpcResult<String> {
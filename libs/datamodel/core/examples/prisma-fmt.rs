@@ -20,5 +20,8 @@ fn main() {
     let file_name = matches.value_of("INPUT").unwrap();
     let file = fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name));
 
-    datamodel::ast::reformat::Reformatter::reformat_to(&file, &mut std::io::stdout().lock(), 2);
+    if let Err(errors) = datamodel::ast::reformat::Reformatter::reformat_to(&file, &mut std::io::stdout().lock(), 2) {
+        eprint!("{}", errors.to_pretty_string(file_name, &file));
+        std::process::exit(1);
+    }
 }
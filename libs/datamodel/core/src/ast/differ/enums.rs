@@ -1,15 +1,15 @@
 use super::{directives::DirectiveDiffer, enum_values::EnumValueDiffer};
-use datamodel::ast;
+use crate::ast;
 
 /// Implements the logic to diff a pair of [AST enums](/datamodel/ast/struct.Datamodel.html).
 #[derive(Debug)]
-pub(crate) struct EnumDiffer<'a> {
-    pub(crate) previous: &'a ast::Enum,
-    pub(crate) next: &'a ast::Enum,
+pub struct EnumDiffer<'a> {
+    pub previous: &'a ast::Enum,
+    pub next: &'a ast::Enum,
 }
 
 impl<'a> EnumDiffer<'a> {
-    pub(crate) fn value_pairs<'b>(&'b self) -> impl Iterator<Item = EnumValueDiffer<'a>> + 'b {
+    pub fn value_pairs<'b>(&'b self) -> impl Iterator<Item = EnumValueDiffer<'a>> + 'b {
         self.previous_values().filter_map(move |previous_value| {
             self.next_values()
                 .find(|next_value| values_match(previous_value, next_value))
@@ -21,7 +21,7 @@ impl<'a> EnumDiffer<'a> {
     }
 
     /// Iterator over the values present in `next` but not `previous`.
-    pub(crate) fn created_values(&self) -> impl Iterator<Item = &ast::EnumValue> {
+    pub fn created_values(&self) -> impl Iterator<Item = &ast::EnumValue> {
         self.next_values().filter(move |next_value| {
             self.previous_values()
                 .find(|previous_value| values_match(previous_value, next_value))
@@ -30,7 +30,7 @@ impl<'a> EnumDiffer<'a> {
     }
 
     /// Iterator over the values present in `previous` but not `next`.
-    pub(crate) fn deleted_values(&self) -> impl Iterator<Item = &ast::EnumValue> {
+    pub fn deleted_values(&self) -> impl Iterator<Item = &ast::EnumValue> {
         self.previous_values().filter(move |previous_value| {
             self.next_values()
                 .find(|next_value| values_match(previous_value, next_value))
@@ -39,7 +39,7 @@ impl<'a> EnumDiffer<'a> {
     }
 
     /// Enum directives (`@@`) created in `next`.
-    pub(crate) fn created_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn created_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.next_directives().filter(move |next_directive| {
             self.previous_directives()
                 .find(|previous_directive| enum_directives_match(previous_directive, next_directive))
@@ -48,7 +48,7 @@ impl<'a> EnumDiffer<'a> {
     }
 
     /// Enum directives (`@@`) deleted in `next`.
-    pub(crate) fn deleted_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn deleted_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.previous_directives().filter(move |previous_directive| {
             self.next_directives()
                 .find(|next_directive| enum_directives_match(previous_directive, next_directive))
@@ -57,7 +57,7 @@ impl<'a> EnumDiffer<'a> {
     }
 
     /// Iterator over the enum directives (`@@`) present in both `previous` and `next`.
-    pub(crate) fn directive_pairs(&'a self) -> impl Iterator<Item = DirectiveDiffer<'a>> {
+    pub fn directive_pairs(&'a self) -> impl Iterator<Item = DirectiveDiffer<'a>> {
         self.previous_directives().filter_map(move |previous_directive| {
             self.next_directives()
                 .find(|next_directive| enum_directives_match(previous_directive, next_directive))
@@ -97,7 +97,7 @@ fn enum_directives_match(previous: &ast::Directive, next: &ast::Directive) -> bo
 mod tests {
     use super::super::TopDiffer;
     use super::*;
-    use datamodel::ast::parser::parse;
+    use crate::ast::parser::parse;
 
     #[test]
     fn datamodel_differ_enum_differ_works() {
@@ -0,0 +1,18 @@
+//! Pairs up the models, fields, enums, datasources and directives of two schema ASTs, to tell
+//! what was created, deleted or kept between them. Items are matched by name.
+
+mod directives;
+mod enum_values;
+mod enums;
+mod fields;
+mod models;
+mod source;
+mod top_level;
+
+pub use directives::DirectiveDiffer;
+pub use enum_values::EnumValueDiffer;
+pub use enums::EnumDiffer;
+pub use fields::FieldDiffer;
+pub use models::{directive_is_regular, directive_is_repeated, ModelDiffer};
+pub use source::SourceArgumentsDiffer;
+pub use top_level::TopDiffer;
@@ -2,13 +2,13 @@ use super::{
     directives::{directives_are_identical, directives_match, DirectiveDiffer},
     FieldDiffer,
 };
-use datamodel::ast;
+use crate::ast;
 
 /// Implements the logic to diff a pair of [AST models](/datamodel/ast/struct.Model.html).
 #[derive(Debug)]
-pub(crate) struct ModelDiffer<'a> {
-    pub(crate) previous: &'a ast::Model,
-    pub(crate) next: &'a ast::Model,
+pub struct ModelDiffer<'a> {
+    pub previous: &'a ast::Model,
+    pub next: &'a ast::Model,
 }
 
 /// Diff two models in a schema AST.
@@ -18,7 +18,7 @@ pub(crate) struct ModelDiffer<'a> {
 /// Most directives can appear only once, so we call them regular directives.
 impl<'a> ModelDiffer<'a> {
     /// Iterator over the fields present in `next` but not `previous`.
-    pub(crate) fn created_fields(&self) -> impl Iterator<Item = &ast::Field> {
+    pub fn created_fields(&self) -> impl Iterator<Item = &ast::Field> {
         self.next_fields().filter(move |next_field| {
             self.previous_fields()
                 .find(|previous_field| fields_match(previous_field, next_field))
@@ -27,7 +27,7 @@ impl<'a> ModelDiffer<'a> {
     }
 
     /// Iterator over the fields present in `previous` but not `next`.
-    pub(crate) fn deleted_fields(&self) -> impl Iterator<Item = &ast::Field> {
+    pub fn deleted_fields(&self) -> impl Iterator<Item = &ast::Field> {
         self.previous_fields().filter(move |previous_field| {
             self.next_fields()
                 .find(|next_field| fields_match(previous_field, next_field))
@@ -36,7 +36,7 @@ impl<'a> ModelDiffer<'a> {
     }
 
     /// Iterator over the fields present in both `previous` and `next`.
-    pub(crate) fn field_pairs(&self) -> impl Iterator<Item = FieldDiffer<'_>> {
+    pub fn field_pairs(&self) -> impl Iterator<Item = FieldDiffer<'_>> {
         self.previous_fields().filter_map(move |previous_field| {
             self.next_fields()
                 .find(|next_field| fields_match(previous_field, next_field))
@@ -48,7 +48,7 @@ impl<'a> ModelDiffer<'a> {
     }
 
     /// Regular model directives (`@@`) created in `next`.
-    pub(crate) fn created_regular_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn created_regular_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.next_regular_directives().filter(move |next_directive| {
             self.previous_regular_directives()
                 .find(|previous_directive| directives_match(previous_directive, next_directive))
@@ -57,7 +57,7 @@ impl<'a> ModelDiffer<'a> {
     }
 
     /// Regular model directives (`@@`) deleted in `next`.
-    pub(crate) fn deleted_regular_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn deleted_regular_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.previous_regular_directives().filter(move |previous_directive| {
             self.next_regular_directives()
                 .find(|next_directive| directives_match(previous_directive, next_directive))
@@ -66,7 +66,7 @@ impl<'a> ModelDiffer<'a> {
     }
 
     /// Iterator over the regular model directives (`@@`) present in both `previous` and `next`.
-    pub(crate) fn regular_directive_pairs(&self) -> impl Iterator<Item = DirectiveDiffer<'_>> {
+    pub fn regular_directive_pairs(&self) -> impl Iterator<Item = DirectiveDiffer<'_>> {
         self.previous_regular_directives()
             .filter_map(move |previous_directive| {
                 self.next_regular_directives()
@@ -78,7 +78,7 @@ impl<'a> ModelDiffer<'a> {
             })
     }
 
-    pub(crate) fn created_repeated_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn created_repeated_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.next_repeated_directives().filter(move |next_directive| {
             self.previous_repeated_directives()
                 .find(|previous_directive| directives_are_identical(previous_directive, next_directive))
@@ -86,7 +86,7 @@ impl<'a> ModelDiffer<'a> {
         })
     }
 
-    pub(crate) fn deleted_repeated_directives(&self) -> impl Iterator<Item = &ast::Directive> {
+    pub fn deleted_repeated_directives(&self) -> impl Iterator<Item = &ast::Directive> {
         self.previous_repeated_directives().filter(move |previous_directive| {
             self.next_repeated_directives()
                 .find(|next_directive| directives_are_identical(previous_directive, next_directive))
@@ -127,12 +127,12 @@ fn fields_match(previous: &ast::Field, next: &ast::Field) -> bool {
 const REPEATED_MODEL_DIRECTIVES: &[&str] = &["unique", "index"];
 
 /// See ModelDiffer docs.
-pub(super) fn directive_is_regular(directive: &&ast::Directive) -> bool {
+pub fn directive_is_regular(directive: &&ast::Directive) -> bool {
     !directive_is_repeated(directive)
 }
 
 /// See ModelDiffer docs.
-pub(super) fn directive_is_repeated(directive: &&ast::Directive) -> bool {
+pub fn directive_is_repeated(directive: &&ast::Directive) -> bool {
     REPEATED_MODEL_DIRECTIVES.contains(&directive.name.name.as_str())
 }
 
@@ -140,7 +140,7 @@ pub(super) fn directive_is_repeated(directive: &&ast::Directive) -> bool {
 mod tests {
     use super::super::TopDiffer;
     use super::*;
-    use datamodel::ast::parser::parse;
+    use crate::ast::parser::parse;
 
     fn dog_datamodels_test(test_fn: impl FnOnce(ModelDiffer<'_>)) {
         let previous = r#"
@@ -1,14 +1,14 @@
 use super::directives::arguments_match;
-use datamodel::ast;
+use crate::ast;
 
 #[derive(Debug)]
-pub(crate) struct SourceArgumentsDiffer<'a> {
-    pub(crate) previous: &'a ast::SourceConfig,
-    pub(crate) next: &'a ast::SourceConfig,
+pub struct SourceArgumentsDiffer<'a> {
+    pub previous: &'a ast::SourceConfig,
+    pub next: &'a ast::SourceConfig,
 }
 
 impl<'a> SourceArgumentsDiffer<'a> {
-    pub(crate) fn deleted_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
+    pub fn deleted_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
         self.previous_arguments().filter(move |previous_argument| {
             self.next_arguments()
                 .find(|next_argument| arguments_match(previous_argument, next_argument))
@@ -16,7 +16,7 @@ impl<'a> SourceArgumentsDiffer<'a> {
         })
     }
 
-    pub(crate) fn created_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
+    pub fn created_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
         self.next_arguments().filter(move |next_argument| {
             self.previous_arguments()
                 .find(|previous_argument| arguments_match(previous_argument, next_argument))
@@ -24,7 +24,7 @@ impl<'a> SourceArgumentsDiffer<'a> {
         })
     }
 
-    pub(crate) fn argument_pairs(&self) -> impl Iterator<Item = (&ast::Argument, &ast::Argument)> {
+    pub fn argument_pairs(&self) -> impl Iterator<Item = (&ast::Argument, &ast::Argument)> {
         self.previous_arguments().filter_map(move |previous_argument| {
             self.next_arguments()
                 .find(|next_argument| arguments_match(previous_argument, next_argument))
@@ -45,7 +45,7 @@ impl<'a> SourceArgumentsDiffer<'a> {
 mod tests {
     use super::super::TopDiffer;
     use super::*;
-    use datamodel::ast::parser::parse;
+    use crate::ast::parser::parse;
 
     fn custom_datasource_test_setup(test_fn: impl FnOnce(SourceArgumentsDiffer<'_>)) {
         let previous = r#"
@@ -18,6 +18,8 @@ pub struct Field {
     pub span: Span,
     /// The location of this field in the text representation.
     pub is_commented_out: bool,
+    /// Was the type declared with the legacy `Type!` or `[Type]` syntax.
+    pub is_legacy_type_syntax: bool,
 }
 
 impl WithIdentifier for Field {
@@ -8,10 +8,12 @@ mod generator_config;
 mod identifier;
 mod model;
 mod source_config;
+mod source_file;
 mod span;
 mod top;
 mod traits;
 
+pub mod differ;
 pub mod parser;
 pub mod reformat;
 pub mod renderer;
@@ -26,6 +28,7 @@ pub use identifier::*;
 pub use model::*;
 pub use r#enum::*;
 pub use source_config::*;
+pub use source_file::SourceFile;
 pub use span::*;
 pub use top::*;
 pub use traits::*;
@@ -15,6 +15,8 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Was this declared with the `type` keyword, as a composite type embedded into models.
+    pub is_composite_type: bool,
 }
 
 impl WithIdentifier for Model {
@@ -10,6 +10,7 @@ use pest::Parser;
 #[grammar = "ast/parser/datamodel.pest"]
 pub struct PrismaDatamodelParser;
 
+use super::source_file::move_to_file;
 use crate::ast::*;
 use crate::error::{DatamodelError, ErrorCollection};
 
@@ -111,7 +112,7 @@ fn doc_comments_to_string(comments: &[String]) -> Option<Comment> {
 
 // Directive parsing
 
-fn parse_directive(token: &pest::iterators::Pair<'_, Rule>) -> Directive {
+pub(crate) fn parse_directive(token: &pest::iterators::Pair<'_, Rule>) -> Directive {
     let mut name: Option<Identifier> = None;
     let mut arguments: Vec<Argument> = vec![];
 
@@ -180,21 +181,17 @@ fn parse_base_type(token: &pest::iterators::Pair<'_, Rule>) -> String {
     }
 }
 
+/// Parses a field type into its arity and base type, and whether it uses legacy syntax.
 fn parse_field_type(
     token: &pest::iterators::Pair<'_, Rule>,
-) -> Result<(FieldArity, String), DatamodelError> {
+) -> Result<(FieldArity, String, bool), DatamodelError> {
     match_first! { token, current,
-        Rule::optional_type => Ok((FieldArity::Optional, parse_base_type(&current))),
-        Rule::base_type =>  Ok((FieldArity::Required, parse_base_type(&current))),
-        Rule::list_type =>  Ok((FieldArity::List, parse_base_type(&current))),
-        Rule::legacy_required_type => Err(DatamodelError::new_legacy_parser_error(
-            "Fields are required by default, `!` is no longer required.",
-            Span::from_pest(current.as_span())
-        )),
-        Rule::legacy_list_type => Err(DatamodelError::new_legacy_parser_error(
-            "To specify a list, please use `Type[]` instead of `[Type]`.",
-            Span::from_pest(current.as_span())
-        )),
+        Rule::optional_type => Ok((FieldArity::Optional, parse_base_type(&current), false)),
+        Rule::base_type =>  Ok((FieldArity::Required, parse_base_type(&current), false)),
+        Rule::list_type =>  Ok((FieldArity::List, parse_base_type(&current), false)),
+        // Legacy types are accepted, validation reports a warning for them.
+        Rule::legacy_required_type => Ok((FieldArity::Required, parse_base_type(&current), true)),
+        Rule::legacy_list_type => Ok((FieldArity::List, parse_base_type(&current), true)),
         Rule::unsupported_optional_list_type => Err(DatamodelError::new_legacy_parser_error(
             "Optional lists are not supported. Use either `Type[]` or `Type?`.",
             Span::from_pest(current.as_span())
@@ -203,10 +200,10 @@ fn parse_field_type(
     }
 }
 
-fn parse_field(token: &pest::iterators::Pair<'_, Rule>) -> Result<Field, DatamodelError> {
+pub(crate) fn parse_field(token: &pest::iterators::Pair<'_, Rule>) -> Result<Field, DatamodelError> {
     let mut name: Option<Identifier> = None;
     let mut directives: Vec<Directive> = Vec::new();
-    let mut field_type: Option<((FieldArity, String), Span)> = None;
+    let mut field_type: Option<((FieldArity, String, bool), Span)> = None;
     let mut comments: Vec<String> = Vec::new();
 
     match_children! { token, current,
@@ -226,7 +223,7 @@ fn parse_field(token: &pest::iterators::Pair<'_, Rule>) -> Result<Field, Datamod
     }
 
     match (name, field_type) {
-        (Some(name), Some(((arity, field_type), field_type_span))) => Ok(Field {
+        (Some(name), Some(((arity, field_type, is_legacy_type_syntax), field_type_span))) => Ok(Field {
             field_type: Identifier {
                 name: field_type,
                 span: field_type_span,
@@ -238,6 +235,7 @@ fn parse_field(token: &pest::iterators::Pair<'_, Rule>) -> Result<Field, Datamod
             documentation: doc_comments_to_string(&comments),
             span: Span::from_pest(token.as_span()),
             is_commented_out: false,
+            is_legacy_type_syntax,
         }),
         _ => panic!(
             "Encountered impossible field declaration during parsing: {:?}",
@@ -252,14 +250,11 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
     let mut directives: Vec<Directive> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comments: Vec<String> = Vec::new();
+    let mut is_composite_type = false;
 
     match_children! { token, current,
         Rule::MODEL_KEYWORD => { },
-        Rule::TYPE_KEYWORD => { errors.push(
-            DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()))
-        ) },
+        Rule::TYPE_KEYWORD => is_composite_type = true,
         Rule::identifier => name = Some(current.to_id()),
         Rule::directive => directives.push(parse_directive(&current)),
         Rule::field_declaration => {
@@ -283,6 +278,7 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
             documentation: doc_comments_to_string(&comments),
             span: Span::from_pest(token.as_span()),
             commented_out: false,
+            is_composite_type,
         }),
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
@@ -331,7 +327,7 @@ fn parse_enum(token: &pest::iterators::Pair<'_, Rule>) -> Result<Enum, ErrorColl
 }
 
 // Enum value parsing
-fn parse_enum_value(token: &pest::iterators::Pair<'_, Rule>) -> Result<EnumValue, DatamodelError> {
+pub(crate) fn parse_enum_value(token: &pest::iterators::Pair<'_, Rule>) -> Result<EnumValue, DatamodelError> {
     let mut name: Option<Identifier> = None;
     let mut directives: Vec<Directive> = vec![];
 
@@ -355,7 +351,7 @@ fn parse_enum_value(token: &pest::iterators::Pair<'_, Rule>) -> Result<EnumValue
     }
 }
 
-fn parse_key_value(token: &pest::iterators::Pair<'_, Rule>) -> Argument {
+pub(crate) fn parse_key_value(token: &pest::iterators::Pair<'_, Rule>) -> Argument {
     let mut name: Option<Identifier> = None;
     let mut value: Option<Expression> = None;
 
@@ -435,7 +431,7 @@ fn parse_generator(token: &pest::iterators::Pair<'_, Rule>) -> GeneratorConfig {
 }
 
 // Custom type parsing
-fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
+pub(crate) fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
     let mut name: Option<Identifier> = None;
     let mut directives: Vec<Directive> = vec![];
     let mut base_type: Option<(String, Span)> = None;
@@ -465,6 +461,7 @@ fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
             documentation: doc_comments_to_string(&comments),
             span: Span::from_pest(token.as_span()),
             is_commented_out: false,
+            is_legacy_type_syntax: false,
         },
         _ => panic!(
             "Encountered impossible custom type declaration during parsing: {:?}",
@@ -477,56 +474,188 @@ fn parse_type(token: &pest::iterators::Pair<'_, Rule>) -> Field {
 
 /// Parses a Prisma V2 datamodel document into an internal AST representation.
 pub fn parse(datamodel_string: &str) -> Result<SchemaAst, ErrorCollection> {
+    let (ast, errors) = parse_with_recovery(datamodel_string);
+    errors.ok()?;
+
+    Ok(ast)
+}
+
+/// Parses a schema, recovering from syntax errors at block boundaries.
+///
+/// Returns the AST of all well-formed blocks, together with the errors of all other blocks.
+pub fn parse_with_recovery(datamodel_string: &str) -> (SchemaAst, ErrorCollection) {
     let mut errors = ErrorCollection::new();
-    let datamodel_result = PrismaDatamodelParser::parse(Rule::datamodel, datamodel_string);
-
-    match datamodel_result {
-        Ok(mut datamodel_wrapped) => {
-            let datamodel = datamodel_wrapped.next().unwrap();
-            let mut top_level_definitions: Vec<Top> = vec![];
-
-            match_children! { datamodel, current,
-                Rule::model_declaration => match parse_model(&current) {
-                    Ok(model) => top_level_definitions.push(Top::Model(model)),
-                    Err(mut err) => errors.append(&mut err)
-                },
-                Rule::enum_declaration => match parse_enum(&current){
-                    Ok(enm) => top_level_definitions.push(Top::Enum(enm)),
-                    Err(mut err) => errors.append(&mut err)
-                },
-                Rule::source_block => top_level_definitions.push(Top::Source(parse_source(&current))),
-                Rule::generator_block => top_level_definitions.push(Top::Generator(parse_generator(&current))),
-                Rule::type_declaration => top_level_definitions.push(Top::Type(parse_type(&current))),
-                Rule::doc_comment => (),
-                Rule::EOI => {},
-                _ => panic!("Encountered impossible datamodel declaration during parsing: {:?}", current.tokens())
+    let mut tops: Vec<Top> = vec![];
+
+    match parse_to_pairs(datamodel_string) {
+        Ok(datamodel) => parse_tops(&datamodel, &mut tops, &mut errors),
+        Err(mut parse_errors) => {
+            // Parse every block on its own, with the rest of the schema blanked out. This keeps
+            // all spans intact, and a syntax error only loses the block it occurs in.
+            for block in split_into_blocks(datamodel_string) {
+                let masked = mask_outside(datamodel_string, &block);
+                let block_end = block.start + datamodel_string[block.clone()].trim_end().len();
+
+                match PrismaDatamodelParser::parse(Rule::datamodel, &masked) {
+                    Ok(mut datamodel) => parse_tops(&datamodel.next().unwrap(), &mut tops, &mut errors),
+                    Err(err) => errors.push(to_parser_error(err, block_end)),
+                }
+            }
+
+            // Should the blocks parse on their own, but not together, report the original error.
+            if !errors.has_errors() {
+                errors.append(&mut parse_errors);
             }
+        }
+    }
+
+    (SchemaAst { tops }, errors)
+}
+
+/// Parses a schema split across several files into one AST. Spans and errors point into the
+/// file they come from, and the errors of all files are reported.
+pub fn parse_files(files: &[SourceFile]) -> Result<SchemaAst, ErrorCollection> {
+    let mut errors = ErrorCollection::new();
+    let mut tops: Vec<Top> = vec![];
+
+    for (idx, file) in files.iter().enumerate() {
+        let file_id = FileId(idx);
+        let (mut ast, file_errors) = parse_with_recovery(&file.contents);
+
+        move_to_file(&mut ast.tops, file_id);
+        tops.append(&mut ast.tops);
+
+        for err in file_errors.errors {
+            errors.push(err.in_file(file_id));
+        }
+    }
+
+    errors.ok()?;
 
-            errors.ok()?;
+    Ok(SchemaAst { tops })
+}
+
+fn parse_tops(datamodel: &pest::iterators::Pair<'_, Rule>, tops: &mut Vec<Top>, errors: &mut ErrorCollection) {
+    match_children! { datamodel, current,
+        Rule::model_declaration => match parse_model(&current) {
+            Ok(model) => tops.push(Top::Model(model)),
+            Err(mut err) => errors.append(&mut err)
+        },
+        Rule::enum_declaration => match parse_enum(&current){
+            Ok(enm) => tops.push(Top::Enum(enm)),
+            Err(mut err) => errors.append(&mut err)
+        },
+        Rule::source_block => tops.push(Top::Source(parse_source(&current))),
+        Rule::generator_block => tops.push(Top::Generator(parse_generator(&current))),
+        Rule::type_declaration => tops.push(Top::Type(parse_type(&current))),
+        Rule::doc_comment => (),
+        Rule::EOI => {},
+        _ => panic!("Encountered impossible datamodel declaration during parsing: {:?}", current.tokens())
+    }
+}
+
+/// Splits a schema into the ranges of its top level blocks. Doc comments directly above a block
+/// belong to it, anything in front of the first block forms a range of its own.
+fn split_into_blocks(datamodel_string: &str) -> Vec<std::ops::Range<usize>> {
+    let mut starts = vec![0];
+    let mut comment_start = None;
+    let mut offset = 0;
+
+    for line in datamodel_string.split('\n') {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("//") {
+            comment_start = comment_start.or(Some(offset));
+        } else {
+            if is_block_start(trimmed) {
+                starts.push(comment_start.unwrap_or(offset));
+            }
 
-            Ok(SchemaAst {
-                tops: top_level_definitions,
-            })
+            comment_start = None;
         }
+
+        offset += line.len() + 1;
+    }
+
+    starts.dedup();
+
+    let ends = starts.iter().skip(1).cloned().chain(std::iter::once(datamodel_string.len()));
+
+    starts.iter().cloned().zip(ends).map(|(start, end)| start..end).collect()
+}
+
+/// Whether the line opens a block, e.g. `model User {` or `type Id = String`. Only the opening
+/// brace tells apart a block from a field named like a keyword.
+fn is_block_start(line: &str) -> bool {
+    let keyword_end = line.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or_else(|| line.len());
+    let (keyword, rest) = line.split_at(keyword_end);
+
+    if !rest.starts_with(char::is_whitespace) {
+        return false;
+    }
+
+    let rest = rest.trim_start();
+    let name_end = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or_else(|| rest.len());
+
+    if name_end == 0 {
+        return false;
+    }
+
+    let rest = rest[name_end..].trim_start();
+
+    match keyword {
+        "model" | "enum" | "datasource" | "generator" => rest.starts_with('{'),
+        "type" => rest.starts_with('{') || rest.starts_with('='),
+        _ => false,
+    }
+}
+
+/// Replaces everything outside of the range with spaces, keeping line breaks and byte offsets.
+fn mask_outside(datamodel_string: &str, range: &std::ops::Range<usize>) -> String {
+    let mut masked = String::with_capacity(datamodel_string.len());
+
+    for (idx, c) in datamodel_string.char_indices() {
+        if range.contains(&idx) || c == '\n' || c == '\r' {
+            masked.push(c);
+        } else {
+            masked.extend(std::iter::repeat(' ').take(c.len_utf8()));
+        }
+    }
+
+    masked
+}
+
+pub(crate) fn parse_to_pairs(datamodel_string: &str) -> Result<pest::iterators::Pair<'_, Rule>, ErrorCollection> {
+    match PrismaDatamodelParser::parse(Rule::datamodel, datamodel_string) {
+        Ok(mut datamodel_wrapped) => Ok(datamodel_wrapped.next().unwrap()),
         Err(err) => {
-            let location = match err.location {
-                pest::error::InputLocation::Pos(pos) => Span::new(pos, pos),
-                pest::error::InputLocation::Span((from, to)) => Span::new(from, to),
-            };
-
-            let expected = match err.variant {
-                pest::error::ErrorVariant::ParsingError { positives, .. } => {
-                    get_expected_from_error(&positives)
-                }
-                _ => panic!("Could not construct parsing error. This should never happend."),
-            };
+            let mut errors = ErrorCollection::new();
+            errors.push(to_parser_error(err, datamodel_string.len()));
 
-            errors.push(DatamodelError::new_parser_error(&expected, location));
             Err(errors)
         }
     }
 }
 
+/// Converts a pest error, moving its location no further than `max_position`.
+fn to_parser_error(err: pest::error::Error<Rule>, max_position: usize) -> DatamodelError {
+    let location = match err.location {
+        pest::error::InputLocation::Pos(pos) => Span::new(pos.min(max_position), pos.min(max_position)),
+        pest::error::InputLocation::Span((from, to)) => {
+            Span::new(from.min(max_position), to.min(max_position))
+        }
+    };
+
+    let expected = match err.variant {
+        pest::error::ErrorVariant::ParsingError { positives, .. } => get_expected_from_error(&positives),
+        _ => panic!("Could not construct parsing error. This should never happend."),
+    };
+
+    DatamodelError::new_parser_error(&expected, location)
+}
+
 pub fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
     positives
         .iter()
@@ -1,26 +1,549 @@
-use crate::{ast, parse_datamodel, parse_schema_ast, render_schema_ast_to, validator::LowerDmlToAst};
+use crate::ast::{
+    self,
+    parser::{self, Rule},
+    renderer::{LineWriteable, Renderer, StringBuilder, TableFormat},
+};
+use crate::error::{DatamodelError, ErrorCollection};
+use crate::{dml, parse_datamodel_and_ignore_env_errors, validator::LowerDmlToAst};
+use pest::iterators::Pair;
 
-pub struct Reformatter {}
+/// Formats a schema.
+///
+/// The formatter works on the parse tree instead of the AST, because the AST does not know where
+/// comments and blank lines were. Every comment and every group of blank lines is kept in place,
+/// runs of blank lines are collapsed into one.
+pub struct Reformatter<'a> {
+    input: &'a str,
+    /// Only present if the schema is valid. Used to add the back relation fields and `@relation`
+    /// arguments the validator infers.
+    datamodel: Option<dml::Datamodel>,
+}
+
+/// The fields of a model after a roundtrip through the validator.
+struct LoweredModel {
+    fields: Vec<ast::Field>,
+    relation_fields: Vec<String>,
+}
+
+/// The tokens of a model, enum, datasource or generator declaration.
+struct Block<'i> {
+    /// Comments in front of the keyword.
+    comments: Vec<Pair<'i, Rule>>,
+    keyword: Option<Pair<'i, Rule>>,
+    name: String,
+    /// Everything between the braces, in order.
+    body: Vec<Pair<'i, Rule>>,
+}
+
+impl<'a> Reformatter<'a> {
+    /// Reformats a schema into the given output.
+    ///
+    /// If the schema is valid, implicit back relation fields and relation arguments are written
+    /// out. Otherwise the schema is formatted based on its syntax alone, like `reformat_ast_to`.
+    /// Fails only if the schema can not be parsed.
+    pub fn reformat_to(
+        input: &str,
+        output: &mut dyn std::io::Write,
+        indent_width: usize,
+    ) -> Result<(), ErrorCollection> {
+        let datamodel = parse_datamodel_and_ignore_env_errors(input).ok();
+
+        Reformatter { input, datamodel }.reformat_internal(output, indent_width)
+    }
+
+    /// Reformats a schema into the given output, without validating it.
+    ///
+    /// Works on any schema that parses, even if it has validation errors. Fails only if the schema
+    /// can not be parsed.
+    pub fn reformat_ast_to(
+        input: &str,
+        output: &mut dyn std::io::Write,
+        indent_width: usize,
+    ) -> Result<(), ErrorCollection> {
+        Reformatter { input, datamodel: None }.reformat_internal(output, indent_width)
+    }
+
+    fn reformat_internal(&self, output: &mut dyn std::io::Write, indent_width: usize) -> Result<(), ErrorCollection> {
+        // The grammar accepts some legacy syntax that only the AST parser rejects.
+        parser::parse(self.input)?;
+        let datamodel_token = parser::parse_to_pairs(self.input)?;
+
+        let mut buffer: Vec<u8> = Vec::new();
+        self.reformat_top_level(&mut Renderer::new(&mut buffer, indent_width), &datamodel_token);
+
+        output.write_all(&buffer).map_err(|err| {
+            let mut errors = ErrorCollection::new();
+            errors.push(DatamodelError::new_validation_error(
+                &format!("Could not write the formatted schema: {}", err),
+                ast::Span::empty(),
+            ));
+            errors
+        })
+    }
+
+    fn reformat_top_level(&self, target: &mut Renderer, token: &Pair<'_, Rule>) {
+        let tops: Vec<Pair<'_, Rule>> = token
+            .clone()
+            .into_inner()
+            .filter(|current| current.as_rule() != Rule::WHITESPACE && current.as_rule() != Rule::EOI)
+            .collect();
+
+        // Type aliases that directly follow each other are aligned together.
+        let mut types_table: Option<TableFormat> = None;
+        let mut previous: Option<&Pair<'_, Rule>> = None;
+        let mut idx = 0;
+
+        while idx < tops.len() {
+            let current = &tops[idx];
+            let is_type = current.as_rule() == Rule::type_declaration;
+
+            if let Some(previous) = previous {
+                let blank_line = self.has_blank_line(end_of(previous), start_of(current));
+                let continues_types = is_type && previous.as_rule() == Rule::type_declaration && !blank_line;
+
+                if !continues_types {
+                    if let Some(table) = types_table.take() {
+                        table.render(target);
+                    }
+
+                    // Blocks are always separated by a blank line.
+                    let between_blocks = previous.as_rule() != Rule::doc_comment && current.as_rule() != Rule::doc_comment;
+
+                    if blank_line || between_blocks {
+                        target.end_line();
+                    }
+                }
+            }
+
+            match current.as_rule() {
+                Rule::type_declaration => {
+                    let trailing_comment = self.trailing_comment(&tops, idx);
+                    let table = types_table.get_or_insert_with(TableFormat::new);
+
+                    self.reformat_type(table, current, trailing_comment.map(comment_text));
+
+                    if trailing_comment.is_some() {
+                        idx += 1;
+                    }
+                }
+                Rule::model_declaration => self.reformat_model(target, current),
+                Rule::enum_declaration => self.reformat_enum(target, current),
+                Rule::source_block => self.reformat_config_block(target, current, "datasource"),
+                Rule::generator_block => self.reformat_config_block(target, current, "generator"),
+                _ => {
+                    target.write(comment_text(current));
+                    target.end_line();
+                }
+            }
+
+            // A trailing comment belongs to the row of its type, so the next type can continue the table.
+            previous = Some(current);
+            idx += 1;
+        }
+
+        if let Some(table) = types_table {
+            table.render(target);
+        }
+    }
+
+    fn reformat_model(&self, target: &mut Renderer, token: &Pair<'_, Rule>) {
+        let block = split_block(token);
+        let lowered = self.lower_model(&block.name);
+
+        let keyword = match &block.keyword {
+            Some(keyword) if keyword.as_rule() == Rule::TYPE_KEYWORD => "type",
+            _ => "model",
+        };
+
+        self.reformat_leading_comments(target, &block.comments, block.keyword.as_ref());
+        Self::write_block_header(target, keyword, &block.name);
+
+        let mut table = TableFormat::new();
+        let mut field_names: Vec<String> = Vec::new();
+        let last_field_idx = block
+            .body
+            .iter()
+            .rposition(|current| current.as_rule() == Rule::field_declaration);
+
+        if last_field_idx.is_none() {
+            Self::reformat_missing_fields(&mut table, lowered.as_ref(), &field_names);
+        }
+
+        let mut previous: Option<&Pair<'_, Rule>> = None;
+        let mut idx = 0;
+
+        while idx < block.body.len() {
+            let current = &block.body[idx];
+
+            self.reformat_blank_line(&mut table.interleave_writer(), previous, current);
+
+            match current.as_rule() {
+                Rule::field_declaration => {
+                    if let Some(name) = self.reformat_field(&mut table, current, lowered.as_ref()) {
+                        field_names.push(name);
+                    }
+                }
+                Rule::directive => {
+                    let trailing_comment = self.trailing_comment(&block.body, idx);
+                    let mut writer = table.interleave_writer();
+
+                    writer.write("@");
+                    Renderer::render_field_directive(&mut writer, &parser::parse_directive(current));
 
-impl Reformatter {
-    pub fn reformat_to(input: &str, output: &mut dyn std::io::Write, _ident_width: usize) {
-        // the AST contains the datasources, generators, type aliases that are missing in the dml
-        // it also contains all the original positions within the file
-        let mut ast = parse_schema_ast(&input).unwrap();
-        let dml = parse_datamodel(&input).unwrap();
+                    if let Some(comment) = trailing_comment {
+                        writer.write(" ");
+                        writer.write(comment_text(comment));
+                        idx += 1;
+                    }
 
-        for top in ast.tops.iter_mut() {
-            match top {
-                ast::Top::Model(model) => {
-                    let lowerer = LowerDmlToAst::new();
-                    let dml_model = dml.find_model(&model.name.name).unwrap();
-                    let new_model = lowerer.lower_model(&dml_model, &dml).unwrap();
-                    std::mem::replace(top, ast::Top::Model(new_model));
+                    writer.end_line();
                 }
+                _ => Self::write_raw_line(&mut table.interleave_writer(), current),
+            }
+
+            if Some(idx) == last_field_idx {
+                Self::reformat_missing_fields(&mut table, lowered.as_ref(), &field_names);
+            }
+
+            previous = Some(&block.body[idx]);
+            idx += 1;
+        }
+
+        table.render(target);
+        Self::write_block_footer(target);
+    }
+
+    /// Writes a field and its comments. Returns the name of the field.
+    fn reformat_field(
+        &self,
+        table: &mut TableFormat,
+        token: &Pair<'_, Rule>,
+        lowered: Option<&LoweredModel>,
+    ) -> Option<String> {
+        let field = match parser::parse_field(token) {
+            Ok(field) => field,
+            Err(_) => {
+                Self::write_raw_line(&mut table.interleave_writer(), token);
+                return None;
+            }
+        };
+
+        let mut trailing_comment = None;
+        let mut seen_name = false;
+
+        for current in token.clone().into_inner() {
+            match current.as_rule() {
+                Rule::identifier => seen_name = true,
+                Rule::doc_comment if seen_name => trailing_comment = Some(current),
+                Rule::doc_comment => Self::write_raw_line(&mut table.interleave_writer(), &current),
                 _ => {}
             }
         }
 
-        render_schema_ast_to(output, &ast, 2);
+        let directives = lowered
+            .filter(|lowered| lowered.relation_fields.contains(&field.name.name))
+            .and_then(|lowered| lowered.fields.iter().find(|f| f.name.name == field.name.name))
+            .map(|lowered_field| &lowered_field.directives)
+            .unwrap_or(&field.directives);
+
+        Self::write_field_row(table, &field, directives, trailing_comment.as_ref().map(comment_text));
+
+        Some(field.name.name)
+    }
+
+    /// Writes the fields the validator added to a model, e.g. back relation fields.
+    fn reformat_missing_fields(table: &mut TableFormat, lowered: Option<&LoweredModel>, field_names: &[String]) {
+        let lowered = match lowered {
+            Some(lowered) => lowered,
+            None => return,
+        };
+
+        for field in lowered.fields.iter().filter(|f| !field_names.contains(&f.name.name)) {
+            Self::write_field_row(table, field, &field.directives, None);
+        }
+    }
+
+    fn write_field_row(
+        table: &mut TableFormat,
+        field: &ast::Field,
+        directives: &[ast::Directive],
+        trailing_comment: Option<&str>,
+    ) {
+        table.write(&field.name.name);
+
+        let mut type_builder = StringBuilder::new();
+        type_builder.write(&field.field_type.name);
+        Renderer::render_field_arity(&mut type_builder, &field.arity);
+        table.write(&type_builder.to_string());
+
+        if !directives.is_empty() || trailing_comment.is_some() {
+            let mut attributes_builder = StringBuilder::new();
+
+            for directive in directives {
+                attributes_builder.write(" ");
+                Renderer::render_field_directive(&mut attributes_builder, directive);
+            }
+
+            table.write(&attributes_builder.to_string());
+        }
+
+        if let Some(comment) = trailing_comment {
+            table.write(comment);
+        }
+
+        table.end_line();
+    }
+
+    fn reformat_enum(&self, target: &mut Renderer, token: &Pair<'_, Rule>) {
+        let block = split_block(token);
+
+        self.reformat_leading_comments(target, &block.comments, block.keyword.as_ref());
+        Self::write_block_header(target, "enum", &block.name);
+
+        let mut previous: Option<&Pair<'_, Rule>> = None;
+        let mut idx = 0;
+
+        while idx < block.body.len() {
+            let current = &block.body[idx];
+            let trailing_comment = self.trailing_comment(&block.body, idx);
+
+            self.reformat_blank_line(target, previous, current);
+
+            match current.as_rule() {
+                Rule::enum_field_declaration => match parser::parse_enum_value(current) {
+                    Ok(value) => {
+                        target.write(&value.name.name);
+
+                        for directive in &value.directives {
+                            target.write(" ");
+                            Renderer::render_field_directive(target, directive);
+                        }
+                    }
+                    Err(_) => target.write(current.as_str().trim()),
+                },
+                Rule::block_level_directive => {
+                    for directive in current.clone().into_inner().filter(|p| p.as_rule() == Rule::directive) {
+                        target.write("@");
+                        Renderer::render_field_directive(target, &parser::parse_directive(&directive));
+                    }
+                }
+                _ => target.write(comment_text(current)),
+            }
+
+            if current.as_rule() != Rule::doc_comment {
+                if let Some(comment) = trailing_comment {
+                    target.write(" ");
+                    target.write(comment_text(comment));
+                    idx += 1;
+                }
+            }
+
+            target.end_line();
+            previous = Some(&block.body[idx]);
+            idx += 1;
+        }
+
+        Self::write_block_footer(target);
+    }
+
+    fn reformat_config_block(&self, target: &mut Renderer, token: &Pair<'_, Rule>, keyword: &str) {
+        let block = split_block(token);
+
+        self.reformat_leading_comments(target, &block.comments, block.keyword.as_ref());
+        Self::write_block_header(target, keyword, &block.name);
+
+        let mut table = TableFormat::new();
+        let mut previous: Option<&Pair<'_, Rule>> = None;
+
+        for current in &block.body {
+            self.reformat_blank_line(&mut table.interleave_writer(), previous, current);
+
+            match current.as_rule() {
+                Rule::key_value => {
+                    let property = parser::parse_key_value(current);
+
+                    table.write(&property.name.name);
+                    table.write(" = ");
+                    table.write(&Renderer::render_value_to_string(&property.value));
+                    table.end_line();
+                }
+                _ => Self::write_raw_line(&mut table.interleave_writer(), current),
+            }
+
+            previous = Some(current);
+        }
+
+        table.render(target);
+        Self::write_block_footer(target);
+    }
+
+    fn reformat_type(&self, table: &mut TableFormat, token: &Pair<'_, Rule>, trailing_comment: Option<&str>) {
+        let comments: Vec<Pair<'_, Rule>> = token
+            .clone()
+            .into_inner()
+            .filter(|current| current.as_rule() == Rule::doc_comment)
+            .collect();
+        let keyword = token
+            .clone()
+            .into_inner()
+            .find(|current| current.as_rule() == Rule::TYPE_KEYWORD);
+
+        self.reformat_leading_comments(&mut table.interleave_writer(), &comments, keyword.as_ref());
+
+        let custom_type = parser::parse_type(token);
+
+        table.write("type ");
+        table.write(&custom_type.name.name);
+        table.write(" = ");
+        table.write(&custom_type.field_type.name);
+
+        if !custom_type.directives.is_empty() || trailing_comment.is_some() {
+            let mut attributes_builder = StringBuilder::new();
+
+            for directive in &custom_type.directives {
+                attributes_builder.write(" ");
+                Renderer::render_field_directive(&mut attributes_builder, directive);
+            }
+
+            table.write(&attributes_builder.to_string());
+        }
+
+        if let Some(comment) = trailing_comment {
+            table.write(comment);
+        }
+
+        table.end_line();
+    }
+
+    /// Writes the comments in front of a block, keeping the blank lines between them and the block.
+    fn reformat_leading_comments(
+        &self,
+        target: &mut dyn LineWriteable,
+        comments: &[Pair<'_, Rule>],
+        keyword: Option<&Pair<'_, Rule>>,
+    ) {
+        for (idx, comment) in comments.iter().enumerate() {
+            target.write(comment_text(comment));
+            target.end_line();
+
+            let next = comments.get(idx + 1).or(keyword);
+
+            if let Some(next) = next {
+                if self.has_blank_line(end_of(comment), start_of(next)) {
+                    target.end_line();
+                }
+            }
+        }
+    }
+
+    /// Ends an extra line if there was at least one blank line between two items of a block.
+    fn reformat_blank_line(
+        &self,
+        target: &mut dyn LineWriteable,
+        previous: Option<&Pair<'_, Rule>>,
+        current: &Pair<'_, Rule>,
+    ) {
+        if let Some(previous) = previous {
+            if self.has_blank_line(end_of(previous), start_of(current)) {
+                target.end_line();
+            }
+        }
+    }
+
+    /// Returns the comment that follows the item at `idx` on the same line, if there is one.
+    fn trailing_comment<'i, 'p>(&self, items: &'p [Pair<'i, Rule>], idx: usize) -> Option<&'p Pair<'i, Rule>> {
+        let current = items.get(idx)?;
+        let next = items.get(idx + 1)?;
+
+        match next.as_rule() {
+            Rule::doc_comment if !self.has_line_break(end_of(current), start_of(next)) => Some(next),
+            _ => None,
+        }
+    }
+
+    fn lower_model(&self, name: &str) -> Option<LoweredModel> {
+        let datamodel = self.datamodel.as_ref()?;
+        let model = datamodel.find_model(name)?;
+        let lowered = LowerDmlToAst::new().lower_model(model, datamodel).ok()?;
+
+        Some(LoweredModel {
+            fields: lowered.fields,
+            relation_fields: model
+                .fields()
+                .filter(|field| field.field_type.is_relation())
+                .map(|field| field.name.clone())
+                .collect(),
+        })
+    }
+
+    fn has_blank_line(&self, from: usize, to: usize) -> bool {
+        self.input
+            .get(from..to)
+            .map(|gap| gap.matches('\n').count() > 1)
+            .unwrap_or(false)
+    }
+
+    fn has_line_break(&self, from: usize, to: usize) -> bool {
+        self.input.get(from..to).map(|gap| gap.contains('\n')).unwrap_or(true)
     }
+
+    fn write_block_header(target: &mut Renderer, keyword: &str, name: &str) {
+        target.write(keyword);
+        target.write(" ");
+        target.write(name);
+        target.write(" {");
+        target.end_line();
+        target.indent_up();
+    }
+
+    fn write_block_footer(target: &mut Renderer) {
+        target.indent_down();
+        target.write("}");
+        target.end_line();
+    }
+
+    /// Writes a token as it is, used for comments.
+    fn write_raw_line(target: &mut dyn LineWriteable, token: &Pair<'_, Rule>) {
+        target.write(comment_text(token));
+        target.end_line();
+    }
+}
+
+fn split_block<'i>(token: &Pair<'i, Rule>) -> Block<'i> {
+    let mut block = Block {
+        comments: Vec::new(),
+        keyword: None,
+        name: String::new(),
+        body: Vec::new(),
+    };
+    let mut in_body = false;
+
+    for current in token.clone().into_inner() {
+        match current.as_rule() {
+            Rule::WHITESPACE | Rule::BLOCK_CLOSE => {}
+            Rule::BLOCK_OPEN => in_body = true,
+            _ if in_body => block.body.push(current),
+            Rule::doc_comment => block.comments.push(current),
+            Rule::identifier => block.name = current.as_str().to_owned(),
+            _ => block.keyword = Some(current),
+        }
+    }
+
+    block
+}
+
+fn comment_text<'i>(token: &Pair<'i, Rule>) -> &'i str {
+    token.as_str().trim()
+}
+
+/// The position of the first character of a token that is not whitespace.
+fn start_of(token: &Pair<'_, Rule>) -> usize {
+    let text = token.as_str();
+
+    token.as_span().start() + (text.len() - text.trim_start().len())
+}
+
+/// The position after the last character of a token that is not whitespace.
+fn end_of(token: &Pair<'_, Rule>) -> usize {
+    token.as_span().start() + token.as_str().trim_end().len()
 }
@@ -167,7 +167,9 @@ impl<'a> Renderer<'a> {
 
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        let keyword = if model.is_composite_type { "type" } else { "model" };
+
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
@@ -267,7 +269,7 @@ impl<'a> Renderer<'a> {
         target.end_line();
     }
 
-    fn render_field_arity(target: &mut dyn LineWriteable, field_arity: &ast::FieldArity) {
+    pub(crate) fn render_field_arity(target: &mut dyn LineWriteable, field_arity: &ast::FieldArity) {
         match field_arity {
             ast::FieldArity::List => target.write("[]"),
             ast::FieldArity::Optional => target.write("?"),
@@ -275,7 +277,7 @@ impl<'a> Renderer<'a> {
         };
     }
 
-    fn render_field_directive(target: &mut dyn LineWriteable, directive: &ast::Directive) {
+    pub(crate) fn render_field_directive(target: &mut dyn LineWriteable, directive: &ast::Directive) {
         target.write("@");
         target.write(&directive.name.name);
 
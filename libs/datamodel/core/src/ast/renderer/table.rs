@@ -132,6 +132,8 @@ impl TableFormat {
                         target.write(&format!("{}{}", col, " ".repeat(spacing)));
                     }
                 }
+                // An empty interleaved row is a blank line, it must not be indented.
+                Row::Interleaved(text) if text.is_empty() => {}
                 Row::Interleaved(text) => {
                     target.write(text);
                 }
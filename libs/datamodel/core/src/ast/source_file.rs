@@ -0,0 +1,130 @@
+use super::*;
+use std::path::Path;
+
+/// A named part of a schema, usually one `.prisma` file of a schema split across several files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    /// The name errors refer to the file by, usually its path.
+    pub name: String,
+    pub contents: String,
+}
+
+impl SourceFile {
+    pub fn new(name: &str, contents: &str) -> SourceFile {
+        SourceFile {
+            name: String::from(name),
+            contents: String::from(contents),
+        }
+    }
+
+    /// Loads a schema from disk. The path is either a single schema file, or a directory whose
+    /// `.prisma` files together form the schema, ordered by file name.
+    pub fn load_all(path: &Path) -> std::io::Result<Vec<SourceFile>> {
+        if !path.is_dir() {
+            return Ok(vec![SourceFile::load(path)?]);
+        }
+
+        let mut paths = Vec::new();
+
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+
+            if path.is_file() && path.extension().map(|ext| ext == "prisma").unwrap_or(false) {
+                paths.push(path);
+            }
+        }
+
+        paths.sort();
+        paths.iter().map(|path| SourceFile::load(path)).collect()
+    }
+
+    fn load(path: &Path) -> std::io::Result<SourceFile> {
+        Ok(SourceFile {
+            name: path.display().to_string(),
+            contents: std::fs::read_to_string(path)?,
+        })
+    }
+}
+
+/// Moves all spans of the given top level declarations into the given file.
+pub(crate) fn move_to_file(tops: &mut [Top], file_id: FileId) {
+    for top in tops {
+        match top {
+            Top::Model(model) => {
+                model.span = model.span.in_file(file_id);
+                move_identifier_to_file(&mut model.name, file_id);
+                model.fields.iter_mut().for_each(|field| move_field_to_file(field, file_id));
+                move_directives_to_file(&mut model.directives, file_id);
+            }
+            Top::Enum(enm) => {
+                enm.span = enm.span.in_file(file_id);
+                move_identifier_to_file(&mut enm.name, file_id);
+                move_directives_to_file(&mut enm.directives, file_id);
+
+                for value in &mut enm.values {
+                    value.span = value.span.in_file(file_id);
+                    move_identifier_to_file(&mut value.name, file_id);
+                    move_directives_to_file(&mut value.directives, file_id);
+                }
+            }
+            Top::Source(source) => {
+                source.span = source.span.in_file(file_id);
+                move_identifier_to_file(&mut source.name, file_id);
+                move_arguments_to_file(&mut source.properties, file_id);
+            }
+            Top::Generator(generator) => {
+                generator.span = generator.span.in_file(file_id);
+                move_identifier_to_file(&mut generator.name, file_id);
+                move_arguments_to_file(&mut generator.properties, file_id);
+            }
+            Top::Type(field) => move_field_to_file(field, file_id),
+        }
+    }
+}
+
+fn move_field_to_file(field: &mut Field, file_id: FileId) {
+    field.span = field.span.in_file(file_id);
+    move_identifier_to_file(&mut field.name, file_id);
+    move_identifier_to_file(&mut field.field_type, file_id);
+    move_directives_to_file(&mut field.directives, file_id);
+
+    if let Some(default_value) = &mut field.default_value {
+        move_expression_to_file(default_value, file_id);
+    }
+}
+
+fn move_directives_to_file(directives: &mut [Directive], file_id: FileId) {
+    for directive in directives {
+        directive.span = directive.span.in_file(file_id);
+        move_identifier_to_file(&mut directive.name, file_id);
+        move_arguments_to_file(&mut directive.arguments, file_id);
+    }
+}
+
+fn move_arguments_to_file(arguments: &mut [Argument], file_id: FileId) {
+    for argument in arguments {
+        argument.span = argument.span.in_file(file_id);
+        move_identifier_to_file(&mut argument.name, file_id);
+        move_expression_to_file(&mut argument.value, file_id);
+    }
+}
+
+fn move_identifier_to_file(identifier: &mut Identifier, file_id: FileId) {
+    identifier.span = identifier.span.in_file(file_id);
+}
+
+fn move_expression_to_file(expression: &mut Expression, file_id: FileId) {
+    match expression {
+        Expression::NumericValue(_, span)
+        | Expression::BooleanValue(_, span)
+        | Expression::StringValue(_, span)
+        | Expression::Any(_, span)
+        | Expression::ConstantValue(_, span) => *span = span.in_file(file_id),
+        Expression::Function(_, arguments, span) | Expression::Array(arguments, span) => {
+            *span = span.in_file(file_id);
+            arguments
+                .iter_mut()
+                .for_each(|argument| move_expression_to_file(argument, file_id));
+        }
+    }
+}
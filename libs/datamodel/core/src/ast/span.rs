@@ -1,25 +1,37 @@
+/// Identifies the file a span points into, as the index of the file in the list of files the
+/// schema was parsed from. Schemas parsed from a single string are file `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FileId(pub usize);
+
 /// Represents a location in a datamodel's text representation.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    pub file_id: FileId,
 }
 
 impl Span {
     pub fn new(start: usize, end: usize) -> Span {
-        Span { start, end }
+        Span {
+            start,
+            end,
+            file_id: FileId::default(),
+        }
     }
 
     // Creates a new empty span.
     pub fn empty() -> Span {
-        Span { start: 0, end: 0 }
+        Span::new(0, 0)
     }
     /// Creates a new ast::Span from a pest::Span.
     pub fn from_pest(s: pest::Span) -> Span {
-        Span {
-            start: s.start(),
-            end: s.end(),
-        }
+        Span::new(s.start(), s.end())
+    }
+
+    /// The same location in the given file.
+    pub fn in_file(self, file_id: FileId) -> Span {
+        Span { file_id, ..self }
     }
 }
 
@@ -34,5 +46,6 @@ pub fn lift_span(span: &Span, offset: usize) -> Span {
     Span {
         start: offset + span.start,
         end: offset + span.end,
+        file_id: span.file_id,
     }
 }
@@ -38,6 +38,7 @@ impl Top {
     pub fn get_type(&self) -> &str {
         match self {
             Top::Enum(_) => "enum",
+            Top::Model(model) if model.is_composite_type => "type",
             Top::Model(_) => "model",
             Top::Source(_) => "source",
             Top::Generator(_) => "generator",
@@ -208,8 +208,16 @@ impl ValueValidator {
 
     pub fn as_default_value(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                Ok(DefaultValue::Expression(ValueGenerator::new(name.to_string(), vec![])?))
+            ast::Expression::Function(name, args, span) => {
+                let args = args
+                    .iter()
+                    .map(|arg| ValueValidator::new(arg).as_str().map(dml::ScalarValue::String))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let generator = ValueGenerator::new(name.to_string(), args)
+                    .map_err(|err| DatamodelError::new_functional_evaluation_error(&err.description(), *span))?;
+
+                Ok(DefaultValue::Expression(generator))
             }
             _ => {
                 let x = ValueValidator::new(&self.value).as_type(scalar_type)?;
@@ -6,6 +6,7 @@ pub const MYSQL_SOURCE_NAME: &str = "mysql";
 pub struct MySqlSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
+    pub(super) shadow_database_url: Option<StringFromEnvVar>,
     pub(super) documentation: Option<String>,
 }
 
@@ -33,6 +34,10 @@ impl Source for MySqlSource {
         &self.documentation
     }
 
+    fn shadow_database_url(&self) -> Option<&StringFromEnvVar> {
+        self.shadow_database_url.as_ref()
+    }
+
     fn connector(&self) -> Box<dyn Connector> {
         Box::new(ExampleConnector::empty())
     }
@@ -18,11 +18,14 @@ impl SourceDefinition for MySqlSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        _schemas: Vec<String>,
+        shadow_database_url: Option<StringFromEnvVar>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, DatamodelError> {
         Ok(Box::new(MySqlSource {
             name: String::from(name),
             url: url,
+            shadow_database_url,
             documentation: documentation.clone(),
         }))
     }
@@ -6,6 +6,8 @@ pub const POSTGRES_SOURCE_NAME: &str = "postgresql";
 pub struct PostgresSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
+    pub(super) schemas: Vec<String>,
+    pub(super) shadow_database_url: Option<StringFromEnvVar>,
     pub(super) documentation: Option<String>,
 }
 
@@ -31,6 +33,14 @@ impl Source for PostgresSource {
         &self.documentation
     }
 
+    fn shadow_database_url(&self) -> Option<&StringFromEnvVar> {
+        self.shadow_database_url.as_ref()
+    }
+
+    fn schemas(&self) -> &[String] {
+        &self.schemas
+    }
+
     fn connector(&self) -> Box<dyn Connector> {
         Box::new(ExampleConnector::postgres())
     }
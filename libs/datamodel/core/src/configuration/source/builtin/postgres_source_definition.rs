@@ -18,11 +18,15 @@ impl SourceDefinition for PostgresSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        schemas: Vec<String>,
+        shadow_database_url: Option<StringFromEnvVar>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, DatamodelError> {
         Ok(Box::new(PostgresSource {
             name: String::from(name),
             url: url,
+            schemas,
+            shadow_database_url,
             documentation: documentation.clone(),
         }))
     }
@@ -6,6 +6,7 @@ pub const SQLITE_SOURCE_NAME: &str = "sqlite";
 pub struct SqliteSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
+    pub(super) shadow_database_url: Option<StringFromEnvVar>,
     pub(super) documentation: Option<String>,
 }
 
@@ -32,6 +33,10 @@ impl Source for SqliteSource {
         &self.documentation
     }
 
+    fn shadow_database_url(&self) -> Option<&StringFromEnvVar> {
+        self.shadow_database_url.as_ref()
+    }
+
     fn connector(&self) -> Box<dyn Connector> {
         Box::new(ExampleConnector::empty())
     }
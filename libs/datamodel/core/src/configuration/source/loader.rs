@@ -4,6 +4,7 @@ use super::{
 };
 use crate::ast;
 use crate::common::arguments::Arguments;
+use crate::common::value_validator::ValueListValidator;
 use crate::error::{DatamodelError, ErrorCollection};
 use crate::StringFromEnvVar;
 
@@ -71,6 +72,46 @@ impl SourceLoader {
             ));
         }
 
+        let schemas = match args.optional_arg("schemas") {
+            Some(schemas_arg) => {
+                let schemas = schemas_arg.as_array()?.to_str_vec()?;
+
+                if !schemas.is_empty() && provider != "postgresql" && provider != "postgres" {
+                    return Err(DatamodelError::new_validation_error(
+                        "The `schemas` property is only supported by the postgresql provider.",
+                        schemas_arg.span(),
+                    ));
+                }
+
+                schemas
+            }
+            None => Vec::new(),
+        };
+
+        let shadow_database_url = match args.optional_arg("shadowDatabaseUrl") {
+            Some(shadow_database_url_arg) => {
+                let (env_var, shadow_url) = match shadow_database_url_arg.as_str_from_env() {
+                    Ok(shadow_url) => shadow_url,
+                    Err(_) if ignore_env_var_errors => (None, "dummy://shadow-url".to_owned()),
+                    Err(err) => return Err(err),
+                };
+
+                // The migration engine wipes the shadow database, it must not be the main one.
+                if shadow_url == url && !ignore_env_var_errors {
+                    return Err(DatamodelError::new_validation_error(
+                        "The `shadowDatabaseUrl` must point to a different database than the `url`.",
+                        shadow_database_url_arg.span(),
+                    ));
+                }
+
+                Some(StringFromEnvVar {
+                    from_env_var: env_var,
+                    value: shadow_url,
+                })
+            }
+            None => None,
+        };
+
         for decl in &self.source_declarations {
             // The provider given in the config block identifies the source type.
             // TODO: The second condition is a fallback to mitigate the postgres -> postgresql rename. It should be
@@ -83,6 +124,8 @@ impl SourceLoader {
                         from_env_var: env_var_for_url,
                         value: url,
                     },
+                    schemas,
+                    shadow_database_url,
                     &ast_source.documentation.clone().map(|comment| comment.text),
                 )?));
             }
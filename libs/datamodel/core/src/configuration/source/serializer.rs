@@ -1,5 +1,5 @@
 use super::traits::Source;
-use crate::ast;
+use crate::{ast, StringFromEnvVar};
 
 pub struct SourceSerializer {}
 
@@ -31,6 +31,19 @@ impl SourceSerializer {
             }
         }
 
+        if !source.schemas().is_empty() {
+            let values = source
+                .schemas()
+                .iter()
+                .map(|schema| ast::Expression::StringValue(schema.clone(), ast::Span::empty()))
+                .collect();
+            arguments.push(ast::Argument::new_array("schemas", values));
+        }
+
+        if let Some(shadow_database_url) = source.shadow_database_url() {
+            arguments.push(string_from_env_var_argument("shadowDatabaseUrl", shadow_database_url));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(source.name()),
             properties: arguments,
@@ -39,3 +52,13 @@ impl SourceSerializer {
         }
     }
 }
+
+fn string_from_env_var_argument(name: &str, value: &StringFromEnvVar) -> ast::Argument {
+    match value.from_env_var {
+        Some(ref env_var) => {
+            let values = vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())];
+            ast::Argument::new_function(name, "env", values)
+        }
+        None => ast::Argument::new_string(name, &value.value),
+    }
+}
@@ -22,6 +22,14 @@ pub trait Source: Send + Sync {
     /// Documentation of this source.
     fn documentation(&self) -> &Option<String>;
 
+    /// Additional database schemas the source spans, besides the one given in the URL.
+    fn schemas(&self) -> &[String] {
+        &[]
+    }
+
+    /// The URL of the shadow database the migration engine replays the migration history into.
+    fn shadow_database_url(&self) -> Option<&StringFromEnvVar>;
+
     fn connector(&self) -> Box<dyn Connector>;
 }
 
@@ -36,6 +44,8 @@ pub trait SourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        schemas: Vec<String>,
+        shadow_database_url: Option<StringFromEnvVar>,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, DatamodelError>;
 }
@@ -0,0 +1,591 @@
+//! Summarizes the changes between two schemas for humans, e.g. to review a schema change.
+//!
+//! Unlike the migration steps the migration engine computes from the same
+//! [AST differ](../ast/differ/index.html), the summary is not meant to be applied to anything.
+//! It is rendered as text, one line per changed model, enum or datasource:
+//!
+//! ```text
+//! Model `User`: field `email` made required; index added on (`tenantId`, `createdAt`)
+//! ```
+//!
+//! or as JSON for tools.
+
+use crate::ast::{
+    self,
+    differ::{DirectiveDiffer, EnumDiffer, FieldDiffer, ModelDiffer, SourceArgumentsDiffer, TopDiffer},
+    renderer::{LineWriteable, Renderer, StringBuilder},
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Summarizes the changes from `previous` to `next`. Models, fields, enums and datasources are
+/// matched by name, so a rename shows up as a deletion and a creation.
+pub fn diff(previous: &ast::SchemaAst, next: &ast::SchemaAst) -> DatamodelDiff {
+    let differ = TopDiffer { previous, next };
+    let mut tops = Vec::new();
+
+    push_datasources(&mut tops, &differ);
+    push_type_aliases(&mut tops, &differ);
+    push_enums(&mut tops, &differ);
+    push_models(&mut tops, &differ);
+
+    DatamodelDiff { tops }
+}
+
+/// The changes between two schemas, grouped by the top-level item they happen in.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DatamodelDiff {
+    pub tops: Vec<TopDiff>,
+}
+
+impl DatamodelDiff {
+    pub fn is_empty(&self) -> bool {
+        self.tops.is_empty()
+    }
+
+    pub fn render_to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to render JSON")
+    }
+}
+
+/// Renders one line per changed top-level item.
+impl fmt::Display for DatamodelDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for top in &self.tops {
+            writeln!(f, "{}", top)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The changes to a model, composite type, enum, type alias or datasource. Always has at least
+/// one change.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TopDiff {
+    pub kind: TopKind,
+    pub name: String,
+    pub changes: Vec<Change>,
+}
+
+impl fmt::Display for TopDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`: ", self.kind, self.name)?;
+
+        for (idx, change) in self.changes.iter().enumerate() {
+            if idx > 0 {
+                f.write_str("; ")?;
+            }
+
+            write!(f, "{}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TopKind {
+    Model,
+    CompositeType,
+    Enum,
+    TypeAlias,
+    Datasource,
+}
+
+impl fmt::Display for TopKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            TopKind::Model => "Model",
+            TopKind::CompositeType => "Type",
+            TopKind::Enum => "Enum",
+            TopKind::TypeAlias => "Type alias",
+            TopKind::Datasource => "Datasource",
+        };
+
+        f.write_str(kind)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Arity {
+    Required,
+    Optional,
+    List,
+}
+
+impl From<ast::FieldArity> for Arity {
+    fn from(arity: ast::FieldArity) -> Self {
+        match arity {
+            ast::FieldArity::Required => Arity::Required,
+            ast::FieldArity::Optional => Arity::Optional,
+            ast::FieldArity::List => Arity::List,
+        }
+    }
+}
+
+/// A single change to a top-level item. Types are rendered the way they are written in the
+/// schema, attributes with their arguments.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "camelCase")]
+pub enum Change {
+    Created,
+    Deleted,
+    FieldCreated {
+        field: String,
+        r#type: String,
+    },
+    FieldDeleted {
+        field: String,
+    },
+    FieldTypeChanged {
+        field: String,
+        previous: String,
+        next: String,
+    },
+    FieldArityChanged {
+        field: String,
+        previous: Arity,
+        next: Arity,
+    },
+    /// The type of a type alias changed.
+    TypeChanged {
+        previous: String,
+        next: String,
+    },
+    /// An attribute was added to the item, or to its `target` field or enum value.
+    AttributeCreated {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+        attribute: String,
+    },
+    AttributeDeleted {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+        attribute: String,
+    },
+    AttributeChanged {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+        previous: String,
+        next: String,
+    },
+    /// An `@@index` or `@@unique` was added to a model.
+    IndexCreated {
+        fields: Vec<String>,
+        unique: bool,
+    },
+    IndexDeleted {
+        fields: Vec<String>,
+        unique: bool,
+    },
+    ValueCreated {
+        value: String,
+    },
+    ValueDeleted {
+        value: String,
+    },
+    PropertyCreated {
+        property: String,
+        value: String,
+    },
+    PropertyDeleted {
+        property: String,
+    },
+    PropertyChanged {
+        property: String,
+        previous: String,
+        next: String,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Created => f.write_str("created"),
+            Change::Deleted => f.write_str("deleted"),
+            Change::FieldCreated { field, r#type } => write!(f, "field `{}` (`{}`) added", field, r#type),
+            Change::FieldDeleted { field } => write!(f, "field `{}` removed", field),
+            Change::FieldTypeChanged { field, previous, next } => {
+                write!(f, "field `{}` type changed from `{}` to `{}`", field, previous, next)
+            }
+            Change::FieldArityChanged { field, next, .. } => match next {
+                Arity::Required => write!(f, "field `{}` made required", field),
+                Arity::Optional => write!(f, "field `{}` made optional", field),
+                Arity::List => write!(f, "field `{}` made a list", field),
+            },
+            Change::TypeChanged { previous, next } => write!(f, "type changed from `{}` to `{}`", previous, next),
+            Change::AttributeCreated { target, attribute } => {
+                write!(f, "attribute `{}` added", attribute)?;
+                write_target(f, "to", target)
+            }
+            Change::AttributeDeleted { target, attribute } => {
+                write!(f, "attribute `{}` removed", attribute)?;
+                write_target(f, "from", target)
+            }
+            Change::AttributeChanged { target, previous, next } => {
+                write!(f, "attribute `{}` changed to `{}`", previous, next)?;
+                write_target(f, "on", target)
+            }
+            Change::IndexCreated { fields, unique } => {
+                write!(f, "{} added on ({})", index_kind(*unique), render_field_list(fields))
+            }
+            Change::IndexDeleted { fields, unique } => {
+                write!(f, "{} removed on ({})", index_kind(*unique), render_field_list(fields))
+            }
+            Change::ValueCreated { value } => write!(f, "value `{}` added", value),
+            Change::ValueDeleted { value } => write!(f, "value `{}` removed", value),
+            Change::PropertyCreated { property, value } => write!(f, "property `{}` set to `{}`", property, value),
+            Change::PropertyDeleted { property } => write!(f, "property `{}` removed", property),
+            Change::PropertyChanged {
+                property,
+                previous,
+                next,
+            } => write!(f, "property `{}` changed from `{}` to `{}`", property, previous, next),
+        }
+    }
+}
+
+fn write_target(f: &mut fmt::Formatter<'_>, preposition: &str, target: &Option<String>) -> fmt::Result {
+    match target {
+        Some(target) => write!(f, " {} `{}`", preposition, target),
+        None => Ok(()),
+    }
+}
+
+fn index_kind(unique: bool) -> &'static str {
+    if unique {
+        "unique constraint"
+    } else {
+        "index"
+    }
+}
+
+fn render_field_list(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| format!("`{}`", field))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+type Tops = Vec<TopDiff>;
+
+fn push_top(tops: &mut Tops, kind: TopKind, name: &str, changes: Vec<Change>) {
+    if !changes.is_empty() {
+        tops.push(TopDiff {
+            kind,
+            name: name.to_owned(),
+            changes,
+        });
+    }
+}
+
+fn push_datasources(tops: &mut Tops, differ: &TopDiffer<'_>) {
+    for source in differ.created_datasources() {
+        push_top(tops, TopKind::Datasource, &source.name.name, vec![Change::Created]);
+    }
+
+    for source in differ.deleted_datasources() {
+        push_top(tops, TopKind::Datasource, &source.name.name, vec![Change::Deleted]);
+    }
+
+    for source in differ.updated_datasources() {
+        push_top(
+            tops,
+            TopKind::Datasource,
+            &source.previous.name.name,
+            datasource_changes(&source),
+        );
+    }
+}
+
+fn datasource_changes(source: &SourceArgumentsDiffer<'_>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for argument in source.created_arguments() {
+        changes.push(Change::PropertyCreated {
+            property: argument.name.name.clone(),
+            value: argument.value.render_to_string(),
+        });
+    }
+
+    for argument in source.deleted_arguments() {
+        changes.push(Change::PropertyDeleted {
+            property: argument.name.name.clone(),
+        });
+    }
+
+    for (previous, next) in source.argument_pairs() {
+        let (previous_value, next_value) = (previous.value.render_to_string(), next.value.render_to_string());
+
+        if previous_value != next_value {
+            changes.push(Change::PropertyChanged {
+                property: previous.name.name.clone(),
+                previous: previous_value,
+                next: next_value,
+            });
+        }
+    }
+
+    changes
+}
+
+fn push_type_aliases(tops: &mut Tops, differ: &TopDiffer<'_>) {
+    for type_alias in differ.created_type_aliases() {
+        push_top(tops, TopKind::TypeAlias, &type_alias.name.name, vec![Change::Created]);
+    }
+
+    for type_alias in differ.deleted_type_aliases() {
+        push_top(tops, TopKind::TypeAlias, &type_alias.name.name, vec![Change::Deleted]);
+    }
+
+    for type_alias in differ.type_alias_pairs() {
+        let mut changes = Vec::new();
+        let (previous_type, next_type) = (render_type(type_alias.previous), render_type(type_alias.next));
+
+        if previous_type != next_type {
+            changes.push(Change::TypeChanged {
+                previous: previous_type,
+                next: next_type,
+            });
+        }
+
+        push_field_attribute_changes(&mut changes, None, &type_alias);
+        push_top(tops, TopKind::TypeAlias, &type_alias.previous.name.name, changes);
+    }
+}
+
+fn push_enums(tops: &mut Tops, differ: &TopDiffer<'_>) {
+    for enm in differ.created_enums() {
+        push_top(tops, TopKind::Enum, &enm.name.name, vec![Change::Created]);
+    }
+
+    for enm in differ.deleted_enums() {
+        push_top(tops, TopKind::Enum, &enm.name.name, vec![Change::Deleted]);
+    }
+
+    for enm in differ.enum_pairs() {
+        push_top(tops, TopKind::Enum, &enm.previous.name.name, enum_changes(&enm));
+    }
+}
+
+fn enum_changes(enm: &EnumDiffer<'_>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for value in enm.created_values() {
+        changes.push(Change::ValueCreated {
+            value: value.name.name.clone(),
+        });
+    }
+
+    for value in enm.deleted_values() {
+        changes.push(Change::ValueDeleted {
+            value: value.name.name.clone(),
+        });
+    }
+
+    for value in enm.value_pairs() {
+        let target = Some(value.previous.name.name.clone());
+
+        push_created_attributes(&mut changes, &target, value.created_directives(), false);
+        push_deleted_attributes(&mut changes, &target, value.deleted_directives(), false);
+        push_changed_attributes(&mut changes, &target, value.directive_pairs(), false);
+    }
+
+    push_created_attributes(&mut changes, &None, enm.created_directives(), true);
+    push_deleted_attributes(&mut changes, &None, enm.deleted_directives(), true);
+    push_changed_attributes(&mut changes, &None, enm.directive_pairs(), true);
+
+    changes
+}
+
+fn push_models(tops: &mut Tops, differ: &TopDiffer<'_>) {
+    for model in differ.created_models() {
+        push_top(tops, model_kind(model), &model.name.name, vec![Change::Created]);
+    }
+
+    for model in differ.deleted_models() {
+        push_top(tops, model_kind(model), &model.name.name, vec![Change::Deleted]);
+    }
+
+    for model in differ.model_pairs() {
+        push_top(
+            tops,
+            model_kind(model.previous),
+            &model.previous.name.name,
+            model_changes(&model),
+        );
+    }
+}
+
+fn model_kind(model: &ast::Model) -> TopKind {
+    if model.is_composite_type {
+        TopKind::CompositeType
+    } else {
+        TopKind::Model
+    }
+}
+
+fn model_changes(model: &ModelDiffer<'_>) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    for field in model.created_fields() {
+        changes.push(Change::FieldCreated {
+            field: field.name.name.clone(),
+            r#type: render_type(field),
+        });
+    }
+
+    for field in model.deleted_fields() {
+        changes.push(Change::FieldDeleted {
+            field: field.name.name.clone(),
+        });
+    }
+
+    for field in model.field_pairs() {
+        let name = &field.previous.name.name;
+
+        if field.previous.field_type.name != field.next.field_type.name {
+            changes.push(Change::FieldTypeChanged {
+                field: name.clone(),
+                previous: render_type(field.previous),
+                next: render_type(field.next),
+            });
+        } else if field.previous.arity != field.next.arity {
+            changes.push(Change::FieldArityChanged {
+                field: name.clone(),
+                previous: field.previous.arity.into(),
+                next: field.next.arity.into(),
+            });
+        }
+
+        push_field_attribute_changes(&mut changes, Some(name.clone()), &field);
+    }
+
+    push_created_attributes(&mut changes, &None, model.created_regular_directives(), true);
+    push_deleted_attributes(&mut changes, &None, model.deleted_regular_directives(), true);
+    push_changed_attributes(&mut changes, &None, model.regular_directive_pairs(), true);
+
+    for directive in model.created_repeated_directives() {
+        changes.push(match index_fields(directive) {
+            Some(fields) => Change::IndexCreated {
+                fields,
+                unique: directive.name.name == "unique",
+            },
+            None => Change::AttributeCreated {
+                target: None,
+                attribute: render_attribute(directive, true),
+            },
+        });
+    }
+
+    for directive in model.deleted_repeated_directives() {
+        changes.push(match index_fields(directive) {
+            Some(fields) => Change::IndexDeleted {
+                fields,
+                unique: directive.name.name == "unique",
+            },
+            None => Change::AttributeDeleted {
+                target: None,
+                attribute: render_attribute(directive, true),
+            },
+        });
+    }
+
+    changes
+}
+
+fn push_field_attribute_changes(changes: &mut Vec<Change>, target: Option<String>, field: &FieldDiffer<'_>) {
+    push_created_attributes(changes, &target, field.created_directives(), false);
+    push_deleted_attributes(changes, &target, field.deleted_directives(), false);
+    push_changed_attributes(changes, &target, field.directive_pairs(), false);
+}
+
+fn push_created_attributes<'a>(
+    changes: &mut Vec<Change>,
+    target: &Option<String>,
+    directives: impl Iterator<Item = &'a ast::Directive>,
+    is_block: bool,
+) {
+    for directive in directives {
+        changes.push(Change::AttributeCreated {
+            target: target.clone(),
+            attribute: render_attribute(directive, is_block),
+        });
+    }
+}
+
+fn push_deleted_attributes<'a>(
+    changes: &mut Vec<Change>,
+    target: &Option<String>,
+    directives: impl Iterator<Item = &'a ast::Directive>,
+    is_block: bool,
+) {
+    for directive in directives {
+        changes.push(Change::AttributeDeleted {
+            target: target.clone(),
+            attribute: render_attribute(directive, is_block),
+        });
+    }
+}
+
+fn push_changed_attributes<'a>(
+    changes: &mut Vec<Change>,
+    target: &Option<String>,
+    directives: impl Iterator<Item = DirectiveDiffer<'a>>,
+    is_block: bool,
+) {
+    for directive in directives {
+        let previous = render_attribute(directive.previous, is_block);
+        let next = render_attribute(directive.next, is_block);
+
+        if previous != next {
+            changes.push(Change::AttributeChanged {
+                target: target.clone(),
+                previous,
+                next,
+            });
+        }
+    }
+}
+
+/// The fields of an `@@index` or `@@unique`, if they are given as a list of field names.
+fn index_fields(directive: &ast::Directive) -> Option<Vec<String>> {
+    let argument = directive
+        .arguments
+        .iter()
+        .find(|arg| arg.name.name == "" || arg.name.name == "fields")?;
+
+    match &argument.value {
+        ast::Expression::Array(values, _) => values
+            .iter()
+            .map(|value| match value {
+                ast::Expression::ConstantValue(name, _) => Some(name.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+fn render_type(field: &ast::Field) -> String {
+    match field.arity {
+        ast::FieldArity::Required => field.field_type.name.clone(),
+        ast::FieldArity::Optional => format!("{}?", field.field_type.name),
+        ast::FieldArity::List => format!("{}[]", field.field_type.name),
+    }
+}
+
+fn render_attribute(directive: &ast::Directive, is_block: bool) -> String {
+    let mut builder = StringBuilder::new();
+
+    if is_block {
+        builder.write("@");
+    }
+
+    Renderer::render_field_directive(&mut builder, directive);
+    builder.to_string()
+}
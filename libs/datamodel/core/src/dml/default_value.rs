@@ -44,7 +44,7 @@ pub struct ValueGenerator {
 
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<ScalarValue>) -> std::result::Result<Self, DatamodelError> {
-        let generator = ValueGeneratorFn::try_from(name.as_ref())?;
+        let generator = ValueGeneratorFn::new(name.as_ref(), &args)?;
 
         Ok(ValueGenerator {
             name,
@@ -57,8 +57,14 @@ impl ValueGenerator {
         ValueGenerator::new("autoincrement".to_owned(), vec![]).unwrap()
     }
 
-    pub fn new_dbgenerated() -> Self {
-        ValueGenerator::new("dbgenerated".to_owned(), vec![]).unwrap()
+    pub fn new_dbgenerated(expression: String) -> Self {
+        let args = if expression.is_empty() {
+            vec![]
+        } else {
+            vec![ScalarValue::String(expression)]
+        };
+
+        ValueGenerator::new("dbgenerated".to_owned(), args).unwrap()
     }
 
     pub fn new_now() -> Self {
@@ -69,6 +75,14 @@ impl ValueGenerator {
         self.generator.invoke()
     }
 
+    /// The SQL expression of a `dbgenerated("...")` default, if there is one.
+    pub fn db_generated_expression(&self) -> Option<&str> {
+        match &self.generator {
+            ValueGeneratorFn::DbGenerated(expression) if !expression.is_empty() => Some(expression.as_str()),
+            _ => None,
+        }
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -78,23 +92,36 @@ impl ValueGenerator {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum ValueGeneratorFn {
     UUID,
     CUID,
     Now,
     Autoincrement,
-    DbGenerated,
+    /// A default computed by the database. The expression is kept verbatim, an empty string means it is unknown.
+    DbGenerated(String),
 }
 
 impl ValueGeneratorFn {
+    fn new(name: &str, args: &[ScalarValue]) -> std::result::Result<Self, DatamodelError> {
+        match (name, args) {
+            ("dbgenerated", []) => Ok(Self::DbGenerated(String::new())),
+            ("dbgenerated", [ScalarValue::String(expression)]) => Ok(Self::DbGenerated(expression.clone())),
+            ("dbgenerated", _) => Err(DatamodelError::new_functional_evaluation_error(
+                "The dbgenerated function takes a single string argument containing the SQL expression.",
+                Span::empty(),
+            )),
+            _ => Self::try_from(name),
+        }
+    }
+
     pub fn invoke(&self) -> Option<ScalarValue> {
         match self {
             Self::UUID => Self::generate_uuid(),
             Self::CUID => Self::generate_cuid(),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
-            Self::DbGenerated => None,
+            Self::DbGenerated(_) => None,
         }
     }
 
@@ -120,7 +147,7 @@ impl TryFrom<&str> for ValueGeneratorFn {
             "uuid" => Ok(Self::UUID),
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
-            "dbgenerated" => Ok(Self::DbGenerated),
+            "dbgenerated" => Ok(Self::DbGenerated(String::new())),
             _ => Err(DatamodelError::new_functional_evaluation_error(
                 &format!("The function {} is not a known function.", s),
                 Span::empty(),
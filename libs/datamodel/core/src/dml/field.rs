@@ -23,6 +23,8 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field embedding a composite type of the given name.
+    Composite(String),
     /// Connector specific field type.
     ConnectorSpecific(ScalarFieldType),
     /// This is a field with an unsupported datatype.
@@ -38,6 +40,13 @@ impl FieldType {
             _ => false,
         }
     }
+
+    pub fn is_composite(&self) -> bool {
+        match self {
+            Self::Composite(_) => true,
+            _ => false,
+        }
+    }
 }
 
 /// Describes a singular field on a data source.
@@ -63,6 +72,15 @@ impl Hash for DataSourceField {
 
 impl Eq for DataSourceField {}
 
+/// The database expression of a computed (generated) field.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ComputedValue {
+    /// The SQL expression computing the value.
+    pub expression: String,
+    /// Whether the value is stored, or computed every time it is read.
+    pub stored: bool,
+}
+
 /// Represents a field in a model.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Field {
@@ -100,6 +118,10 @@ pub struct Field {
     /// automatically.
     pub is_updated_at: bool,
 
+    /// If set, the database computes this field's value from an expression
+    /// and the field is read-only.
+    pub computed: Option<ComputedValue>,
+
     /// The data source field specifics, like backing fields and defaults.
     pub data_source_fields: Vec<DataSourceField>,
 
@@ -108,6 +130,11 @@ pub struct Field {
 }
 
 impl Field {
+    /// Computed fields can only be read, never written to.
+    pub fn is_read_only(&self) -> bool {
+        self.computed.is_some()
+    }
+
     pub fn points_to_model(&self, name: &str) -> bool {
         match &self.field_type {
             FieldType::Relation(rel_info) if rel_info.to == name => true,
@@ -175,6 +202,7 @@ impl Field {
             is_updated_at: false,
             data_source_fields: vec![],
             is_commented_out: false,
+            computed: None,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -192,6 +220,7 @@ impl Field {
             is_updated_at: false,
             data_source_fields: vec![],
             is_commented_out: false,
+            computed: None,
         }
     }
 }
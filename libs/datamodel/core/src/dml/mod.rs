@@ -1,4 +1,5 @@
 mod comment;
+mod composite_type;
 mod datamodel;
 mod default_value;
 mod r#enum;
@@ -9,6 +10,7 @@ mod relation_info;
 mod traits;
 
 pub use self::datamodel::*;
+pub use composite_type::*;
 pub use default_value::*;
 pub use field::*;
 pub use functions::*;
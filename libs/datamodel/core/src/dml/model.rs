@@ -13,6 +13,8 @@ pub struct Model {
     pub database_name: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// The database schema this model lives in, if it is not the default one.
+    pub schema: Option<String>,
     /// Describes Composite Indexes
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
@@ -28,6 +30,10 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// Sort order of each of the fields, in the same order as `fields`. Empty if all fields are ascending.
+    pub sort_orders: Vec<SortOrder>,
+    /// The index method, if it is not the database default.
+    pub method: Option<IndexMethod>,
 }
 
 impl IndexDefinition {
@@ -37,6 +43,11 @@ impl IndexDefinition {
             _ => false,
         }
     }
+
+    /// The sort order of the field at the given position.
+    pub fn sort_order(&self, field_index: usize) -> SortOrder {
+        self.sort_orders.get(field_index).cloned().unwrap_or(SortOrder::Asc)
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -45,6 +56,84 @@ pub enum IndexType {
     Normal,
 }
 
+/// The sort order of a field in an index.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Parsable for SortOrder {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Asc" => Some(SortOrder::Asc),
+            "Desc" => Some(SortOrder::Desc),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "sort order"
+    }
+}
+
+impl ToString for SortOrder {
+    fn to_string(&self) -> String {
+        match self {
+            SortOrder::Asc => String::from("Asc"),
+            SortOrder::Desc => String::from("Desc"),
+        }
+    }
+}
+
+/// The method (data structure) backing an index.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexMethod {
+    BTree,
+    Hash,
+    Gist,
+    Gin,
+    Brin,
+    SpGist,
+    FullText,
+    Spatial,
+}
+
+impl Parsable for IndexMethod {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "BTree" => Some(IndexMethod::BTree),
+            "Hash" => Some(IndexMethod::Hash),
+            "Gist" => Some(IndexMethod::Gist),
+            "Gin" => Some(IndexMethod::Gin),
+            "Brin" => Some(IndexMethod::Brin),
+            "SpGist" => Some(IndexMethod::SpGist),
+            "FullText" => Some(IndexMethod::FullText),
+            "Spatial" => Some(IndexMethod::Spatial),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "index type"
+    }
+}
+
+impl ToString for IndexMethod {
+    fn to_string(&self) -> String {
+        match self {
+            IndexMethod::BTree => String::from("BTree"),
+            IndexMethod::Hash => String::from("Hash"),
+            IndexMethod::Gist => String::from("Gist"),
+            IndexMethod::Gin => String::from("Gin"),
+            IndexMethod::Brin => String::from("Brin"),
+            IndexMethod::SpGist => String::from("SpGist"),
+            IndexMethod::FullText => String::from("FullText"),
+            IndexMethod::Spatial => String::from("Spatial"),
+        }
+    }
+}
+
 impl Model {
     /// Creates a new model with the given name.
     pub fn new(name: String, database_name: Option<String>) -> Model {
@@ -56,6 +145,7 @@ impl Model {
             documentation: None,
             database_name,
             is_embedded: false,
+            schema: None,
             is_generated: false,
             is_commented_out: false,
         }
@@ -1,4 +1,5 @@
 use super::DatamodelError;
+use crate::ast::SourceFile;
 
 /// Represents a list of validation or parser errors.
 ///
@@ -53,6 +54,21 @@ impl ErrorCollection {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Like `to_pretty_string`, for a schema parsed from several files. Every error is printed
+    /// with the file its span points into.
+    pub fn to_pretty_string_in_files(&self, files: &[SourceFile]) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in self.to_iter() {
+            if let Some(file) = files.get(err.span().file_id.0) {
+                err.pretty_print(&mut message, &file.name, &file.contents)
+                    .expect("printing datamodel error");
+            }
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
 }
 
 impl std::fmt::Display for ErrorCollection {
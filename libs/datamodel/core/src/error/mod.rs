@@ -1,7 +1,11 @@
 mod collection;
+mod quick_fix;
+mod warning;
 pub use collection::*;
+pub use quick_fix::*;
+pub use warning::*;
 
-use crate::ast::Span;
+use crate::ast::{FileId, Span};
 use colored::Colorize;
 
 // No format for this file, on purpose.
@@ -337,21 +341,63 @@ impl DatamodelError {
             DatamodelError::ScalarListFieldsAreNotSupported {span, ..} => *span,
         }
     }
+
+    /// The same error, pointing into the given file.
+    pub fn in_file(mut self, file_id: FileId) -> DatamodelError {
+        let span = self.span_mut();
+        *span = span.in_file(file_id);
+
+        self
+    }
+
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            DatamodelError::ArgumentNotFound { span, .. } => span,
+            DatamodelError::DirectiveArgumentNotFound { span, .. } => span,
+            DatamodelError::ArgumentCountMissmatch { span, .. } => span,
+            DatamodelError::SourceArgumentNotFound { span, .. } => span,
+            DatamodelError::GeneratorArgumentNotFound { span, .. } => span,
+            DatamodelError::DirectiveValidationError { span, .. } => span,
+            DatamodelError::DirectiveNotKnownError { span, .. } => span,
+            DatamodelError::ReservedScalarTypeError { span, .. } => span,
+            DatamodelError::FunctionNotKnownError { span, .. } => span,
+            DatamodelError::SourceNotKnownError { span, .. } => span,
+            DatamodelError::LiteralParseError { span, .. } => span,
+            DatamodelError::TypeNotFoundError { span, .. } => span,
+            DatamodelError::ScalarTypeNotFoundError { span, .. } => span,
+            DatamodelError::ParserError { span, .. } => span,
+            DatamodelError::FunctionalEvaluationError { span, .. } => span,
+            DatamodelError::EnvironmentFunctionalEvaluationError { span, .. } => span,
+            DatamodelError::TypeMismatchError { span, .. } => span,
+            DatamodelError::ValueParserError { span, .. } => span,
+            DatamodelError::ValidationError { span, .. } => span,
+            DatamodelError::LegacyParserError { span, .. } => span,
+            DatamodelError::ModelValidationError { span, .. } => span,
+            DatamodelError::DuplicateDirectiveError { span, .. } => span,
+            DatamodelError::DuplicateConfigKeyError { span, .. } => span,
+            DatamodelError::DuplicateTopError { span, .. } => span,
+            DatamodelError::DuplicateFieldError { span, .. } => span,
+            DatamodelError::DuplicateEnumValueError { span, .. } => span,
+            DatamodelError::DuplicateArgumentError { span, .. } => span,
+            DatamodelError::DuplicateDefaultArgumentError { span, .. } => span,
+            DatamodelError::UnusedArgumentError { span, .. } => span,
+            DatamodelError::ScalarListFieldsAreNotSupported { span, .. } => span,
+        }
+    }
+
     pub fn description(&self) -> String {
         format!("{}", self)
     }
 
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
-        pretty_print_error(f, file_name, text, self)
+        pretty_print(f, file_name, text, self.span(), "error".bright_red().bold(), &self.description())
     }
 }
 
-/// Given the datamodel text representation, pretty prints an error, including
+/// Given the datamodel text representation, pretty prints an error or warning, including
 /// the offending portion of the source code, for human-friendly reading.
 #[rustfmt::skip]
-fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, error_obj: &DatamodelError) -> std::io::Result<()> {
-    let span = error_obj.span();
-    let error = error_obj.description();
+fn pretty_print(f: &mut dyn std::io::Write, file_name: &str, text: &str, span: Span, severity: colored::ColoredString, error: &str) -> std::io::Result<()> {
 
     let start_line_number = text[..span.start].matches("\n").count();
     let end_line_number = text[..span.end].matches("\n").count();
@@ -373,7 +419,7 @@ fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, e
     let arrow = "-->".bright_blue().bold();
     let file_path = format!("{}:{}", file_name, start_line_number + 1).underline();
 
-    writeln!(f, "{}: {}", "error".bright_red().bold(), error.bold())?;
+    writeln!(f, "{}: {}", severity, error.bold())?;
     writeln!(f, "  {}  {}", arrow, file_path)?;
     writeln!(f, "{}", format_line_number(0))?;
     
@@ -0,0 +1,56 @@
+use crate::ast::{FileId, SourceFile, Span};
+
+/// A machine-applicable change to the schema text that resolves a diagnostic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickFix {
+    /// A short description of the change, e.g. for an editor's code action menu.
+    pub title: String,
+    /// The span of the error or warning this fix resolves.
+    pub span: Span,
+    /// The message of the error or warning this fix resolves, to tell apart diagnostics with
+    /// the same span.
+    pub diagnostic: String,
+    /// The text edits to apply. They never overlap.
+    pub edits: Vec<TextEdit>,
+}
+
+/// Replaces the text in a span. An empty span inserts text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub span: Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn replace(span: Span, new_text: &str) -> TextEdit {
+        TextEdit {
+            span,
+            new_text: String::from(new_text),
+        }
+    }
+
+    pub fn insert(file_id: FileId, position: usize, new_text: &str) -> TextEdit {
+        TextEdit::replace(Span::new(position, position).in_file(file_id), new_text)
+    }
+
+    /// Does this edit touch the same text as another one, or insert at the same position.
+    pub fn overlaps(&self, other: &TextEdit) -> bool {
+        self.span.file_id == other.span.file_id
+            && ((self.span.start < other.span.end && other.span.start < self.span.end)
+                || self.span.start == other.span.start)
+    }
+
+    /// Applies edits to the files they point into. The edits must not overlap.
+    pub fn apply_all(edits: &[TextEdit], files: &mut [SourceFile]) {
+        let mut edits: Vec<&TextEdit> = edits.iter().collect();
+        // Editing back to front keeps the spans of the remaining edits valid.
+        edits.sort_by_key(|edit| (edit.span.file_id.0, std::cmp::Reverse(edit.span.start)));
+
+        for edit in edits {
+            if let Some(file) = files.get_mut(edit.span.file_id.0) {
+                file.contents
+                    .replace_range(edit.span.start..edit.span.end, &edit.new_text);
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::ast::{FileId, Span};
+use colored::Colorize;
+
+// No format for this file, on purpose.
+// Line breaks make the declarations very hard to read.
+#[rustfmt::skip]
+/// Enum for non-fatal diagnostics, which are reported alongside a successfully
+/// validated datamodel.
+///
+/// For fancy printing, please use the `pretty_print` function.
+#[derive(Debug, Fail, Clone, PartialEq)]
+pub enum DatamodelWarning {
+    #[fail(display = "Attribute \"@{}\" is deprecated. {}", directive_name, message)]
+    DeprecatedDirective { directive_name: String, message: String, span: Span },
+
+    #[fail(display = "{}", message)]
+    LegacySyntax { message: String, span: Span },
+
+    #[fail(display = "The relation field \"{}\" on model \"{}\" has no index on its foreign key. Lookups of related records will need to scan the whole table.", field_name, model_name)]
+    MissingIndexOnRelationField { model_name: String, field_name: String, span: Span },
+
+    #[fail(display = "The field \"{}\" on model \"{}\" looks like it stores amounts of money, but uses `Float`. Use `Decimal` to avoid rounding errors.", field_name, model_name)]
+    FloatForMoney { model_name: String, field_name: String, span: Span },
+
+    #[fail(display = "The relation field \"{}\" on model \"{}\" has no opposite relation field on model \"{}\". One is generated implicitly, add it to the schema to make it explicit.", field_name, model_name, related_model_name)]
+    MissingBackRelationField { model_name: String, field_name: String, related_model_name: String, span: Span },
+
+    #[fail(display = "The one-to-one relation field \"{}\" on model \"{}\" holds the foreign key only because of the order of the model names. Add `references` to its @relation directive to make this explicit.", field_name, model_name)]
+    AmbiguousOneToOneRelation { model_name: String, field_name: String, span: Span },
+}
+
+#[rustfmt::skip]
+impl DatamodelWarning {
+    pub fn new_deprecated_directive_warning(message: &str, directive_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::DeprecatedDirective {
+            message: String::from(message),
+            directive_name: String::from(directive_name),
+            span,
+        }
+    }
+
+    pub fn new_legacy_syntax_warning(message: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::LegacySyntax { message: String::from(message), span }
+    }
+
+    pub fn new_missing_index_on_relation_field_warning(model_name: &str, field_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::MissingIndexOnRelationField {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            span,
+        }
+    }
+
+    pub fn new_float_for_money_warning(model_name: &str, field_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::FloatForMoney {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            span,
+        }
+    }
+
+    pub fn new_missing_back_relation_field_warning(model_name: &str, field_name: &str, related_model_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::MissingBackRelationField {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            related_model_name: String::from(related_model_name),
+            span,
+        }
+    }
+
+    pub fn new_ambiguous_one_to_one_relation_warning(model_name: &str, field_name: &str, span: Span) -> DatamodelWarning {
+        DatamodelWarning::AmbiguousOneToOneRelation {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            span,
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            DatamodelWarning::DeprecatedDirective { span, .. } => *span,
+            DatamodelWarning::LegacySyntax { span, .. } => *span,
+            DatamodelWarning::MissingIndexOnRelationField { span, .. } => *span,
+            DatamodelWarning::FloatForMoney { span, .. } => *span,
+            DatamodelWarning::MissingBackRelationField { span, .. } => *span,
+            DatamodelWarning::AmbiguousOneToOneRelation { span, .. } => *span,
+        }
+    }
+
+    /// The same warning, pointing into the given file.
+    pub fn in_file(mut self, file_id: FileId) -> DatamodelWarning {
+        let span = match &mut self {
+            DatamodelWarning::DeprecatedDirective { span, .. } => span,
+            DatamodelWarning::LegacySyntax { span, .. } => span,
+            DatamodelWarning::MissingIndexOnRelationField { span, .. } => span,
+            DatamodelWarning::FloatForMoney { span, .. } => span,
+            DatamodelWarning::MissingBackRelationField { span, .. } => span,
+            DatamodelWarning::AmbiguousOneToOneRelation { span, .. } => span,
+        };
+        *span = span.in_file(file_id);
+
+        self
+    }
+
+    pub fn description(&self) -> String {
+        format!("{}", self)
+    }
+
+    pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
+        super::pretty_print(f, file_name, text, self.span(), "warning".bright_yellow().bold(), &self.description())
+    }
+}
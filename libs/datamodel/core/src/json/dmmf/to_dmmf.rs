@@ -17,6 +17,7 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
@@ -27,6 +28,10 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -75,6 +80,14 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type.fields().map(&field_to_dmmf).collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
 fn field_to_dmmf(field: &dml::Field) -> Field {
     Field {
         name: field.name.clone(),
@@ -91,13 +104,14 @@ fn field_to_dmmf(field: &dml::Field) -> Field {
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated),
         is_updated_at: Some(field.is_updated_at),
+        is_read_only: Some(true).filter(|_| field.is_read_only()),
         documentation: field.documentation.clone(),
     }
 }
 
 fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
-        dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::Relation(_) | dml::FieldType::Composite(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
@@ -108,6 +122,7 @@ fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::Composite(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
         dml::FieldType::ConnectorSpecific(sft) => type_to_string(&sft.prisma_type()),
@@ -0,0 +1,191 @@
+//! Renders a datamodel as [JSON Schema](https://json-schema.org/draft/2019-09/schema) documents,
+//! e.g. to validate REST payloads that mirror the models.
+//!
+//! Every model, composite type and enum gets a document of its own. Documents refer to each other
+//! by their `$id`, `<Name>.schema.json`. Optional fields may be `null` and may be left out, all
+//! other fields except relation fields are required.
+
+use crate::common::{ScalarType, ScalarValue};
+use crate::dml;
+use serde_json::{json, Map, Value};
+
+const DRAFT_2019_09: &str = "https://json-schema.org/draft/2019-09/schema";
+
+/// Where references to other models, composite types and enums point to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Target {
+    /// Stand-alone documents, referring to each other by their `$id`.
+    JsonSchema,
+    /// The `components.schemas` section of an OpenAPI 3.1 document.
+    OpenApi,
+}
+
+impl Target {
+    fn reference(self, name: &str) -> String {
+        match self {
+            Target::JsonSchema => format!("{}.schema.json", name),
+            Target::OpenApi => format!("#/components/schemas/{}", name),
+        }
+    }
+}
+
+/// Renders one JSON Schema document per model, composite type and enum, keyed by name.
+pub fn render_to_json_schema_value(schema: &dml::Datamodel) -> Value {
+    Value::Object(render_schemas(schema, Target::JsonSchema))
+}
+
+pub fn render_to_json_schema(schema: &dml::Datamodel) -> String {
+    serde_json::to_string_pretty(&render_to_json_schema_value(schema)).expect("Failed to render JSON")
+}
+
+/// Renders the schemas as the `components` section of an OpenAPI 3.1 document, whose schema
+/// objects are JSON Schema as well.
+pub fn render_to_openapi_components_value(schema: &dml::Datamodel) -> Value {
+    json!({ "components": { "schemas": render_schemas(schema, Target::OpenApi) } })
+}
+
+pub fn render_to_openapi_components(schema: &dml::Datamodel) -> String {
+    serde_json::to_string_pretty(&render_to_openapi_components_value(schema)).expect("Failed to render JSON")
+}
+
+fn render_schemas(schema: &dml::Datamodel, target: Target) -> Map<String, Value> {
+    let mut schemas = Map::new();
+
+    for model in schema.models().filter(|model| !model.is_generated) {
+        let document = object_schema(&model.name, model.fields(), &model.documentation, target);
+        schemas.insert(model.name.clone(), document);
+    }
+
+    for composite_type in schema.composite_types() {
+        let document = object_schema(
+            &composite_type.name,
+            composite_type.fields(),
+            &composite_type.documentation,
+            target,
+        );
+        schemas.insert(composite_type.name.clone(), document);
+    }
+
+    for enm in schema.enums() {
+        schemas.insert(enm.name.clone(), enum_schema(enm, target));
+    }
+
+    schemas
+}
+
+fn object_schema<'a>(
+    name: &str,
+    fields: impl Iterator<Item = &'a dml::Field>,
+    documentation: &Option<String>,
+    target: Target,
+) -> Value {
+    let mut document = document_header(name, documentation, target);
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in fields {
+        properties.insert(field.name.clone(), field_schema(field, target));
+
+        if !field.field_type.is_relation() && field.arity != dml::FieldArity::Optional {
+            required.push(Value::String(field.name.clone()));
+        }
+    }
+
+    document.insert("type".to_owned(), json!("object"));
+    document.insert("properties".to_owned(), Value::Object(properties));
+    document.insert("required".to_owned(), Value::Array(required));
+    document.insert("additionalProperties".to_owned(), json!(false));
+
+    Value::Object(document)
+}
+
+fn enum_schema(enm: &dml::Enum, target: Target) -> Value {
+    let mut document = document_header(&enm.name, &enm.documentation, target);
+    let values: Vec<Value> = enm.values().map(|value| json!(value.name)).collect();
+
+    document.insert("type".to_owned(), json!("string"));
+    document.insert("enum".to_owned(), Value::Array(values));
+
+    Value::Object(document)
+}
+
+fn document_header(name: &str, documentation: &Option<String>, target: Target) -> Map<String, Value> {
+    let mut document = Map::new();
+
+    if target == Target::JsonSchema {
+        document.insert("$schema".to_owned(), json!(DRAFT_2019_09));
+        document.insert("$id".to_owned(), json!(target.reference(name)));
+    }
+
+    document.insert("title".to_owned(), json!(name));
+
+    if let Some(documentation) = documentation {
+        document.insert("description".to_owned(), json!(documentation));
+    }
+
+    document
+}
+
+fn field_schema(field: &dml::Field, target: Target) -> Value {
+    let type_schema = match &field.field_type {
+        dml::FieldType::Base(scalar_type, _) => scalar_schema(*scalar_type),
+        dml::FieldType::ConnectorSpecific(sft) => scalar_schema(sft.prisma_type()),
+        dml::FieldType::Enum(name) | dml::FieldType::Composite(name) => json!({ "$ref": target.reference(name) }),
+        dml::FieldType::Relation(rel_info) => json!({ "$ref": target.reference(&rel_info.to) }),
+        // Anything goes for types Prisma does not know.
+        dml::FieldType::Unsupported(_) => json!({}),
+    };
+
+    let mut schema = match field.arity {
+        dml::FieldArity::Required => type_schema,
+        dml::FieldArity::List => json!({ "type": "array", "items": type_schema }),
+        dml::FieldArity::Optional => nullable(type_schema),
+    };
+
+    if let Value::Object(schema) = &mut schema {
+        if let Some(dml::DefaultValue::Single(value)) = &field.default_value {
+            schema.insert("default".to_owned(), default_value(value));
+        }
+
+        if let Some(documentation) = &field.documentation {
+            schema.insert("description".to_owned(), json!(documentation));
+        }
+    }
+
+    schema
+}
+
+fn scalar_schema(scalar_type: ScalarType) -> Value {
+    match scalar_type {
+        ScalarType::Int => json!({ "type": "integer" }),
+        ScalarType::Float | ScalarType::Decimal => json!({ "type": "number" }),
+        ScalarType::Boolean => json!({ "type": "boolean" }),
+        ScalarType::String => json!({ "type": "string" }),
+        ScalarType::DateTime => json!({ "type": "string", "format": "date-time" }),
+    }
+}
+
+/// Scalars list `null` as one of their types, references to other documents are wrapped.
+fn nullable(type_schema: Value) -> Value {
+    match type_schema {
+        Value::Object(mut schema) => match schema.remove("type") {
+            Some(tpe) => {
+                schema.insert("type".to_owned(), json!([tpe, "null"]));
+                Value::Object(schema)
+            }
+            None if schema.is_empty() => Value::Object(schema),
+            None => json!({ "anyOf": [Value::Object(schema), { "type": "null" }] }),
+        },
+        other => other,
+    }
+}
+
+fn default_value(value: &ScalarValue) -> Value {
+    match value {
+        ScalarValue::Int(val) => json!(val),
+        ScalarValue::Float(val) | ScalarValue::Decimal(val) => json!(val),
+        ScalarValue::Boolean(val) => json!(val),
+        ScalarValue::String(val) | ScalarValue::ConstantLiteral(val) => json!(val),
+        ScalarValue::DateTime(val) => json!(val.to_rfc3339()),
+    }
+}
@@ -7,6 +7,10 @@ pub struct SourceConfig {
     pub name: String,
     pub connector_type: String,
     pub url: StringFromEnvVar,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow_database_url: Option<StringFromEnvVar>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -36,6 +40,8 @@ fn source_to_json_struct(source: &dyn configuration::Source) -> SourceConfig {
         name: source.name().clone(),
         connector_type: String::from(source.connector_type()),
         url: source.url().clone(),
+        schemas: source.schemas().to_vec(),
+        shadow_database_url: source.shadow_database_url().cloned(),
         documentation: source.documentation().clone(),
     }
 }
@@ -75,6 +81,30 @@ fn source_from_json(
         }
     }
 
+    if !source.schemas.is_empty() {
+        let values = source
+            .schemas
+            .iter()
+            .map(|schema| ast::Expression::StringValue(schema.clone(), ast::Span::empty()))
+            .collect();
+        arguments.push(ast::Argument::new_array("schemas", values));
+    }
+
+    if let Some(shadow_database_url) = &source.shadow_database_url {
+        match shadow_database_url.from_env_var {
+            Some(ref env_var) => {
+                let values = vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())];
+                arguments.push(ast::Argument::new_function("shadowDatabaseUrl", "env", values));
+            }
+            None => {
+                arguments.push(ast::Argument::new_string(
+                    "shadowDatabaseUrl",
+                    &shadow_database_url.value,
+                ));
+            }
+        }
+    }
+
     let ast_source = ast::SourceConfig {
         name: ast::Identifier::new(&source.name),
         properties: arguments,
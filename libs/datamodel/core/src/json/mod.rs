@@ -1,2 +1,3 @@
 pub mod dmmf;
+pub mod jsonschema;
 pub mod mcf;
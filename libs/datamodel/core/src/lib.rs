@@ -50,12 +50,15 @@ extern crate failure; // Failure enum display derivation
 pub mod ast;
 pub mod common;
 pub mod configuration;
+pub mod diff;
 pub mod dml;
 pub mod error;
 pub mod json;
 pub mod validator;
 
+pub use ast::SourceFile;
 pub use configuration::*;
+pub use diff::diff;
 pub use dml::*;
 
 use crate::ast::SchemaAst;
@@ -96,10 +99,74 @@ fn parse_datamodel_internal(
     ignore_env_var_errors: bool,
 ) -> Result<Datamodel, error::ErrorCollection> {
     let ast = ast::parser::parse(datamodel_string)?;
-    let sources = load_sources(&ast, ignore_env_var_errors)?;
+
+    validate_schema_ast(&ast, ignore_env_var_errors)
+}
+
+/// Parses and validates a schema split across several files, using core attributes only. Models
+/// may refer to models in other files.
+pub fn parse_datamodel_from_files(files: &[SourceFile]) -> Result<Datamodel, error::ErrorCollection> {
+    validate_schema_ast(&ast::parser::parse_files(files)?, false)
+}
+
+pub fn parse_datamodel_from_files_and_ignore_env_errors(
+    files: &[SourceFile],
+) -> Result<Datamodel, error::ErrorCollection> {
+    validate_schema_ast(&ast::parser::parse_files(files)?, true)
+}
+
+fn validate_schema_ast(ast: &SchemaAst, ignore_env_var_errors: bool) -> Result<Datamodel, error::ErrorCollection> {
+    let sources = load_sources(ast, ignore_env_var_errors)?;
+    let validator = ValidationPipeline::with_sources(&sources);
+
+    validator.validate(ast)
+}
+
+/// Parses and validates a datamodel string, using core attributes only. Non-fatal diagnostics,
+/// like deprecations, are returned alongside the datamodel.
+pub fn parse_datamodel_with_warnings(
+    datamodel_string: &str,
+) -> Result<(Datamodel, Vec<error::DatamodelWarning>), error::ErrorCollection> {
+    let ast = ast::parser::parse(datamodel_string)?;
+
+    validate_schema_ast_with_warnings(&ast, false)
+}
+
+/// Like `parse_datamodel_with_warnings`, for a schema split across several files.
+pub fn parse_datamodel_from_files_with_warnings(
+    files: &[SourceFile],
+    ignore_env_var_errors: bool,
+) -> Result<(Datamodel, Vec<error::DatamodelWarning>), error::ErrorCollection> {
+    validate_schema_ast_with_warnings(&ast::parser::parse_files(files)?, ignore_env_var_errors)
+}
+
+fn validate_schema_ast_with_warnings(
+    ast: &SchemaAst,
+    ignore_env_var_errors: bool,
+) -> Result<(Datamodel, Vec<error::DatamodelWarning>), error::ErrorCollection> {
+    let sources = load_sources(ast, ignore_env_var_errors)?;
     let validator = ValidationPipeline::with_sources(&sources);
 
-    validator.validate(&ast)
+    validator.validate_with_warnings(ast)
+}
+
+/// Computes machine-applicable fixes for the errors and warnings of a schema split across several
+/// files. Each fix refers to the diagnostic it resolves by its span. Schemas that can not be
+/// parsed have no fixes.
+pub fn quick_fixes_for_files(files: &[SourceFile], ignore_env_var_errors: bool) -> Vec<error::QuickFix> {
+    let ast = match ast::parser::parse_files(files) {
+        Ok(ast) => ast,
+        Err(_) => return Vec::new(),
+    };
+    let fixer = validator::QuickFixer::new(files, &ast);
+
+    match validate_schema_ast_with_warnings(&ast, ignore_env_var_errors) {
+        Ok((schema, warnings)) => warnings
+            .iter()
+            .filter_map(|warning| fixer.for_warning(warning, &schema))
+            .collect(),
+        Err(errors) => errors.to_iter().filter_map(|error| fixer.for_error(error)).collect(),
+    }
 }
 
 /// Validates a [Schema AST](/ast/struct.SchemaAst.html) and returns its
@@ -122,24 +189,42 @@ pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, error::Erro
     ast::parser::parse(datamodel_string)
 }
 
+/// Parses a schema split across several files into a single [Schema AST](/ast/struct.SchemaAst.html).
+pub fn parse_schema_ast_from_files(files: &[SourceFile]) -> Result<SchemaAst, error::ErrorCollection> {
+    ast::parser::parse_files(files)
+}
+
+/// Parses a datamodel string into a [Schema AST](/ast/struct.SchemaAst.html), even if it has
+/// syntax errors. The AST contains all well-formed blocks, and the errors all others.
+pub fn parse_schema_ast_with_recovery(datamodel_string: &str) -> (SchemaAst, error::ErrorCollection) {
+    ast::parser::parse_with_recovery(datamodel_string)
+}
+
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, error::ErrorCollection> {
-    let ast = ast::parser::parse(datamodel_string)?;
-    let datasources = load_sources(&ast, false)?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
-
-    Ok(Configuration {
-        datasources,
-        generators,
-    })
+    load_configuration(&ast::parser::parse(datamodel_string)?, false)
 }
 
 pub fn parse_configuration_and_ignore_env_errors(
     datamodel_string: &str,
 ) -> Result<Configuration, error::ErrorCollection> {
-    let ast = ast::parser::parse(datamodel_string)?;
-    let datasources = load_sources(&ast, true)?;
-    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    load_configuration(&ast::parser::parse(datamodel_string)?, true)
+}
+
+/// Loads all configuration blocks from a schema split across several files.
+pub fn parse_configuration_from_files(files: &[SourceFile]) -> Result<Configuration, error::ErrorCollection> {
+    load_configuration(&ast::parser::parse_files(files)?, false)
+}
+
+pub fn parse_configuration_from_files_and_ignore_env_errors(
+    files: &[SourceFile],
+) -> Result<Configuration, error::ErrorCollection> {
+    load_configuration(&ast::parser::parse_files(files)?, true)
+}
+
+fn load_configuration(ast: &SchemaAst, ignore_env_var_errors: bool) -> Result<Configuration, error::ErrorCollection> {
+    let datasources = load_sources(ast, ignore_env_var_errors)?;
+    let generators = GeneratorLoader::load_generators_from_ast(ast)?;
 
     Ok(Configuration {
         datasources,
@@ -0,0 +1,64 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@computed` directive.
+pub struct ComputedDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for ComputedDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"computed"
+    }
+
+    fn documentation(&self) -> &str {
+        "Computes the field from an SQL expression in the database. Computed fields are read only."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["expression", "stored"]
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        if let dml::FieldType::Relation(_) = obj.field_type {
+            return self.new_directive_validation_error("Relation fields can not be computed.", args.span());
+        }
+
+        if obj.arity == dml::FieldArity::List {
+            return self.new_directive_validation_error(
+                "Fields that are marked with @computed can not be lists.",
+                args.span(),
+            );
+        }
+
+        let expression = args.default_arg("expression")?.as_str()?;
+        let stored = match args.optional_arg("stored") {
+            Some(stored) => stored.as_bool()?,
+            None => true,
+        };
+
+        obj.computed = Some(dml::ComputedValue { expression, stored });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let Some(computed) = &field.computed {
+            let mut args = vec![ast::Argument::new_string("", &computed.expression)];
+
+            if !computed.stored {
+                args.push(ast::Argument::new(
+                    "stored",
+                    ast::Expression::BooleanValue(String::from("false"), ast::Span::empty()),
+                ));
+            }
+
+            Ok(vec![ast::Directive::new(self.directive_name(), args)])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
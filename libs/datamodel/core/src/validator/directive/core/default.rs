@@ -11,6 +11,14 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
         &"default"
     }
 
+    fn documentation(&self) -> &str {
+        "Sets the value the field takes when none is given on creation."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["value"]
+    }
+
     fn validate_and_apply(&self, args: &mut Args, field: &mut dml::Field) -> Result<(), DatamodelError> {
         // If we allow list default values, we need to adjust the types below properly for that case.
         if field.arity == dml::FieldArity::List {
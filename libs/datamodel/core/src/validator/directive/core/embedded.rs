@@ -9,6 +9,11 @@ impl DirectiveValidator<dml::Model> for EmbeddedDirectiveValidator {
     fn directive_name(&self) -> &'static str {
         &"embedded"
     }
+
+    fn documentation(&self) -> &str {
+        "Marks the model as embedded into other models."
+    }
+
     fn validate_and_apply(&self, _args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
         obj.is_embedded = true;
         Ok(())
@@ -10,6 +10,10 @@ impl DirectiveValidator<dml::Field> for IdDirectiveValidator {
         &"id"
     }
 
+    fn documentation(&self) -> &str {
+        "Marks the field as the primary key of the model."
+    }
+
     // TODO In which form is this still required or needs to change? Default values are handling the id strategy now.
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
         if obj.arity != dml::FieldArity::Required {
@@ -41,6 +45,14 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
         "id"
     }
 
+    fn documentation(&self) -> &str {
+        "Defines a primary key made of multiple fields."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["fields"]
+    }
+
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
         match args.default_arg("fields")?.as_array() {
             Ok(fields) => {
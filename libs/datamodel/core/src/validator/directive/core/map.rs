@@ -13,6 +13,14 @@ impl<T: dml::WithDatabaseName> DirectiveValidator<T> for MapDirectiveValidator {
         DIRECTIVE_NAME
     }
 
+    fn documentation(&self) -> &str {
+        "Maps a model, field, enum or enum value to a different name in the database."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["name"]
+    }
+
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), DatamodelError> {
         match args.default_arg("name")?.as_array() {
             Ok(value) => {
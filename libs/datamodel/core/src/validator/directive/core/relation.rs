@@ -11,6 +11,15 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
     fn directive_name(&self) -> &'static str {
         &"relation"
     }
+
+    fn documentation(&self) -> &str {
+        "Configures a relation: its name and the fields of the related model it references."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["name", "references"]
+    }
+
     fn validate_and_apply(
         &self,
         args: &mut Args,
@@ -0,0 +1,40 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@schema` directive.
+pub struct SchemaDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for SchemaDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"schema"
+    }
+
+    fn documentation(&self) -> &str {
+        "Places the model in the given database schema."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["name"]
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.schema = Some(args.default_arg("name")?.as_str()?);
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if let Some(schema) = &model.schema {
+            return Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_string("", schema)],
+            )]);
+        }
+
+        Ok(vec![])
+    }
+}
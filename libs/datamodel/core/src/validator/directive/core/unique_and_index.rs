@@ -10,6 +10,10 @@ impl DirectiveValidator<dml::Field> for FieldLevelUniqueDirectiveValidator {
         &"unique"
     }
 
+    fn documentation(&self) -> &str {
+        "Adds a unique constraint on the field."
+    }
+
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
         if obj.is_id {
             return self.new_directive_validation_error(
@@ -45,6 +49,14 @@ impl DirectiveValidator<dml::Model> for ModelLevelUniqueDirectiveValidator {
         "unique"
     }
 
+    fn documentation(&self) -> &str {
+        "Adds a unique constraint on a combination of fields."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["fields", "name", "sort", "type"]
+    }
+
     fn is_duplicate_definition_allowed(&self) -> bool {
         true
     }
@@ -74,6 +86,14 @@ impl DirectiveValidator<dml::Model> for ModelLevelIndexDirectiveValidator {
         "index"
     }
 
+    fn documentation(&self) -> &str {
+        "Adds an index on one or more fields."
+    }
+
+    fn argument_names(&self) -> &[&'static str] {
+        &["fields", "name", "sort", "type"]
+    }
+
     fn is_duplicate_definition_allowed(&self) -> bool {
         true
     }
@@ -106,6 +126,8 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            sort_orders: vec![],
+            method: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -121,6 +143,30 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             Err(err) => return Err(self.wrap_in_directive_validation_error(&err)),
         }
 
+        if let Some(sort_arg) = args.optional_arg("sort") {
+            let sort_orders = sort_arg
+                .as_array()?
+                .iter()
+                .map(|sort_order| sort_order.parse_literal::<dml::SortOrder>())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if sort_orders.len() != index_def.fields.len() {
+                return Err(DatamodelError::new_directive_validation_error(
+                    "The `sort` argument must contain one sort order for each field of the index.",
+                    self.directive_name(),
+                    sort_arg.span(),
+                ));
+            }
+
+            if sort_orders.iter().any(|sort_order| *sort_order == dml::SortOrder::Desc) {
+                index_def.sort_orders = sort_orders;
+            }
+        }
+
+        if let Some(method_arg) = args.optional_arg("type") {
+            index_def.method = Some(method_arg.parse_literal::<dml::IndexMethod>()?);
+        }
+
         let undefined_fields: Vec<String> = index_def
             .fields
             .iter()
@@ -171,6 +217,19 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if !index_def.sort_orders.is_empty() {
+                    args.push(ast::Argument::new_array(
+                        "sort",
+                        index_def
+                            .sort_orders
+                            .iter()
+                            .map(|sort_order| ast::Expression::ConstantValue(sort_order.to_string(), ast::Span::empty()))
+                            .collect(),
+                    ));
+                }
+                if let Some(method) = &index_def.method {
+                    args.push(ast::Argument::new_constant("type", &method.to_string()));
+                }
 
                 ast::Directive::new(self.directive_name(), args)
             })
@@ -10,6 +10,10 @@ impl DirectiveValidator<dml::Field> for UpdatedAtDirectiveValidator {
         &"updatedAt"
     }
 
+    fn documentation(&self) -> &str {
+        "Sets the field to the current time whenever the record is updated."
+    }
+
     fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Field) -> Result<(), DatamodelError> {
         if let dml::FieldType::Base(dml::ScalarType::DateTime, _) = obj.field_type {
             // everything good
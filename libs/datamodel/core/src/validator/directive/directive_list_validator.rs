@@ -55,6 +55,11 @@ impl<T: 'static> DirectiveListValidator<T> {
         }
     }
 
+    /// All known directive validators, ordered by directive name.
+    pub fn directives(&self) -> impl Iterator<Item = &dyn DirectiveValidator<T>> + '_ {
+        self.known_directives.values().map(|validator| validator.as_ref())
+    }
+
     /// For each directive in the given object, picks the correct
     /// directive definition and uses it to validate and apply the directive.
     pub fn validate_and_apply(&self, ast: &dyn ast::WithDirectives, t: &mut T) -> Result<(), ErrorCollection> {
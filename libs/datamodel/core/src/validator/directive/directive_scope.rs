@@ -30,6 +30,12 @@ impl<T> DirectiveValidator<T> for DirectiveScope<T> {
     fn directive_name(&self) -> &str {
         &self.name
     }
+    fn documentation(&self) -> &str {
+        self.inner.documentation()
+    }
+    fn argument_names(&self) -> &[&'static str] {
+        self.inner.argument_names()
+    }
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), DatamodelError> {
         self.inner.validate_and_apply(args, obj)
     }
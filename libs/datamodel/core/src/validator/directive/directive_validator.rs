@@ -14,6 +14,17 @@ pub trait DirectiveValidator<T> {
         false
     }
 
+    /// A short description of the directive, shown by editor integrations.
+    fn documentation(&self) -> &str {
+        ""
+    }
+
+    /// The names of all arguments the directive accepts, the default argument first.
+    /// Used by editor integrations for completion.
+    fn argument_names(&self) -> &[&'static str] {
+        &[]
+    }
+
     /// Validates a directive and applies the directive
     /// to the given object.
     fn validate_and_apply(&self, args: &mut Args, obj: &mut T) -> Result<(), DatamodelError>;
@@ -40,6 +40,10 @@ impl LiftAstToDml {
                     Ok(en) => schema.add_enum(en),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::Model(ty) if ty.is_composite_type => match self.lift_composite_type(&ty, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Model(ty) => match self.lift_model(&ty, ast_schema) {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
@@ -83,6 +87,59 @@ impl LiftAstToDml {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    /// Composite types only hold plain data, so directives and relation fields are rejected.
+    fn lift_composite_type(
+        &self,
+        ast_type: &ast::Model,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, ErrorCollection> {
+        let mut composite_type = dml::CompositeType::new(&ast_type.name.name);
+        composite_type.documentation = ast_type.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = ErrorCollection::new();
+
+        for directive in &ast_type.directives {
+            errors.push(DatamodelError::new_directive_validation_error(
+                "Directives are not supported on composite types.",
+                &directive.name.name,
+                directive.span,
+            ));
+        }
+
+        for ast_field in &ast_type.fields {
+            if let Some(directive) = ast_field.directives.first() {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    "Directives are not supported on fields of composite types.",
+                    &directive.name.name,
+                    directive.span,
+                ));
+                continue;
+            }
+
+            match self.lift_field(ast_field, ast_schema) {
+                Ok(field) if field.field_type.is_relation() => {
+                    errors.push(DatamodelError::new_model_validation_error(
+                        &format!(
+                            "The field `{}` refers to a model. Composite types can not have relation fields.",
+                            ast_field.name.name
+                        ),
+                        &ast_type.name.name,
+                        ast_field.field_type.span,
+                    ));
+                }
+                Ok(field) => composite_type.add_field(field),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(composite_type)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, ErrorCollection> {
         let mut errors = ErrorCollection::new();
@@ -198,8 +255,12 @@ impl LiftAstToDml {
             } else {
                 Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
             }
-        } else if ast_schema.find_model(type_name).is_some() {
-            Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
+        } else if let Some(model) = ast_schema.find_model(type_name) {
+            if model.is_composite_type {
+                Ok((dml::FieldType::Composite(type_name.clone()), vec![]))
+            } else {
+                Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
+            }
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
         } else {
@@ -232,7 +293,7 @@ impl LiftAstToDml {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if let dml::FieldType::Relation(_) | dml::FieldType::Composite(_) = field_type {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
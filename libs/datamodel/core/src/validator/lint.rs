@@ -0,0 +1,205 @@
+use crate::{ast, common::ScalarType, dml, error::DatamodelWarning, FieldArity};
+
+/// Words in field names that hint at monetary amounts.
+const MONEY_WORDS: &[&str] = &[
+    "amount", "balance", "cost", "fee", "money", "payment", "price", "revenue", "salary", "total",
+];
+
+/// Collects non-fatal diagnostics for a datamodel that passed validation.
+pub struct Linter {}
+
+impl Linter {
+    pub fn lint(ast_schema: &ast::SchemaAst, schema: &dml::Datamodel) -> Vec<DatamodelWarning> {
+        let mut warnings = Vec::new();
+
+        for ast_model in ast_schema.models() {
+            Self::lint_deprecated_directives(ast_model, &mut warnings);
+            Self::lint_legacy_type_syntax(ast_model, &mut warnings);
+        }
+
+        for model in schema.models() {
+            let ast_model = match ast_schema.find_model(&model.name) {
+                Some(ast_model) => ast_model,
+                // Generated models, like relation tables, have no source to point to.
+                None => continue,
+            };
+
+            Self::lint_relation_fields_without_index(ast_model, model, &mut warnings);
+            Self::lint_implicit_relation_fields(ast_schema, ast_model, schema, model, &mut warnings);
+            Self::lint_float_for_money(ast_model, model, &mut warnings);
+        }
+
+        warnings.sort_by_key(|warning| (warning.span().file_id.0, warning.span().start));
+        warnings
+    }
+
+    fn lint_deprecated_directives(ast_model: &ast::Model, warnings: &mut Vec<DatamodelWarning>) {
+        for directive in ast_model.directives.iter().filter(|d| d.name.name == "embedded") {
+            warnings.push(DatamodelWarning::new_deprecated_directive_warning(
+                "Use a composite type, declared with the `type` keyword, instead.",
+                &directive.name.name,
+                directive.span,
+            ));
+        }
+    }
+
+    fn lint_legacy_type_syntax(ast_model: &ast::Model, warnings: &mut Vec<DatamodelWarning>) {
+        for field in ast_model.fields.iter().filter(|f| f.is_legacy_type_syntax) {
+            let message = match field.arity {
+                ast::FieldArity::List => "To specify a list, please use `Type[]` instead of `[Type]`.",
+                _ => "Fields are required by default, `!` is no longer required.",
+            };
+
+            warnings.push(DatamodelWarning::new_legacy_syntax_warning(
+                message,
+                field.field_type.span,
+            ));
+        }
+    }
+
+    /// Relation fields holding a foreign key should be the first field of an id, unique
+    /// constraint or index, so that related records can be found without a full table scan.
+    fn lint_relation_fields_without_index(
+        ast_model: &ast::Model,
+        model: &dml::Model,
+        warnings: &mut Vec<DatamodelWarning>,
+    ) {
+        if model.is_embedded {
+            return;
+        }
+
+        for field in model.fields() {
+            let holds_foreign_key = match &field.field_type {
+                dml::FieldType::Relation(rel_info) => field.arity != FieldArity::List && !rel_info.to_fields.is_empty(),
+                _ => false,
+            };
+
+            let is_indexed = field.is_id
+                || field.is_unique
+                || model.id_fields.first() == Some(&field.name)
+                || model
+                    .indices
+                    .iter()
+                    .any(|index| index.fields.first() == Some(&field.name));
+
+            if !holds_foreign_key || is_indexed {
+                continue;
+            }
+
+            if let Some(ast_field) = ast_model.fields.iter().find(|f| f.name.name == field.name) {
+                warnings.push(DatamodelWarning::new_missing_index_on_relation_field_warning(
+                    &model.name,
+                    &field.name,
+                    ast_field.span,
+                ));
+            }
+        }
+    }
+
+    /// Back relation fields and the side of a one-to-one relation holding the foreign key are
+    /// inferred if the schema leaves them out. Both are easy to miss when reading the schema.
+    fn lint_implicit_relation_fields(
+        ast_schema: &ast::SchemaAst,
+        ast_model: &ast::Model,
+        schema: &dml::Datamodel,
+        model: &dml::Model,
+        warnings: &mut Vec<DatamodelWarning>,
+    ) {
+        for field in model.fields() {
+            let rel_info = match &field.field_type {
+                dml::FieldType::Relation(rel_info) => rel_info,
+                _ => continue,
+            };
+
+            let ast_field = match ast_model.fields.iter().find(|f| f.name.name == field.name) {
+                Some(ast_field) => ast_field,
+                // Generated back relation fields are reported on the field they belong to.
+                None => continue,
+            };
+
+            let related_field = schema
+                .find_model(&rel_info.to)
+                .and_then(|related_model| related_model.related_field(&model.name, &rel_info.name, &field.name));
+
+            let (related_field, ast_related_model) = match (related_field, ast_schema.find_model(&rel_info.to)) {
+                (Some(related_field), Some(ast_related_model)) => (related_field, ast_related_model),
+                _ => continue,
+            };
+
+            let ast_related_field = ast_related_model
+                .fields
+                .iter()
+                .find(|f| f.name.name == related_field.name);
+
+            match ast_related_field {
+                None => warnings.push(DatamodelWarning::new_missing_back_relation_field_warning(
+                    &model.name,
+                    &field.name,
+                    &rel_info.to,
+                    ast_field.span,
+                )),
+                Some(ast_related_field) => {
+                    let is_one_to_one = field.arity != FieldArity::List && related_field.arity != FieldArity::List;
+
+                    if is_one_to_one
+                        && !rel_info.to_fields.is_empty()
+                        && !has_references_argument(ast_field)
+                        && !has_references_argument(ast_related_field)
+                    {
+                        warnings.push(DatamodelWarning::new_ambiguous_one_to_one_relation_warning(
+                            &model.name,
+                            &field.name,
+                            ast_field.span,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    fn lint_float_for_money(ast_model: &ast::Model, model: &dml::Model, warnings: &mut Vec<DatamodelWarning>) {
+        for field in model.fields() {
+            let is_float = match field.field_type {
+                dml::FieldType::Base(ScalarType::Float, _) => true,
+                _ => false,
+            };
+
+            if !is_float || !name_words(&field.name).any(|word| MONEY_WORDS.contains(&word.as_str())) {
+                continue;
+            }
+
+            if let Some(ast_field) = ast_model.fields.iter().find(|f| f.name.name == field.name) {
+                warnings.push(DatamodelWarning::new_float_for_money_warning(
+                    &model.name,
+                    &field.name,
+                    ast_field.field_type.span,
+                ));
+            }
+        }
+    }
+}
+
+fn has_references_argument(field: &ast::Field) -> bool {
+    field
+        .directives
+        .iter()
+        .filter(|directive| directive.name.name == "relation")
+        .any(|directive| directive.arguments.iter().any(|arg| arg.name.name == "references"))
+}
+
+/// Splits a camelCase or snake_case name into lowercase words.
+fn name_words(name: &str) -> impl Iterator<Item = String> {
+    let mut words: Vec<String> = vec![String::new()];
+
+    for c in name.chars() {
+        if c == '_' || (c.is_uppercase() && !words.last().unwrap().is_empty()) {
+            words.push(String::new());
+        }
+
+        if c != '_' {
+            words.last_mut().unwrap().extend(c.to_lowercase());
+        }
+    }
+
+    words.into_iter().filter(|word| !word.is_empty())
+}
@@ -39,6 +39,13 @@ impl LowerDmlToAst {
             }
         }
 
+        for composite_type in datamodel.composite_types() {
+            match self.lower_composite_type(composite_type, datamodel) {
+                Ok(res) => tops.push(ast::Top::Model(res)),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
         for enm in datamodel.enums() {
             match self.lower_enum(enm, datamodel) {
                 Ok(res) => tops.push(ast::Top::Enum(res)),
@@ -80,6 +87,40 @@ impl LowerDmlToAst {
                 .map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_composite_type: false,
+        })
+    }
+
+    fn lower_composite_type(
+        &self,
+        composite_type: &dml::CompositeType,
+        datamodel: &dml::Datamodel,
+    ) -> Result<ast::Model, ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+        let mut fields: Vec<ast::Field> = Vec::new();
+
+        for field in composite_type.fields() {
+            match self.lower_field(field, datamodel) {
+                Ok(ast_field) => fields.push(ast_field),
+                Err(mut err) => errors.append(&mut err),
+            };
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(ast::Model {
+            name: ast::Identifier::new(&composite_type.name),
+            fields,
+            directives: vec![],
+            documentation: composite_type
+                .documentation
+                .clone()
+                .map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
+            commented_out: false,
+            is_composite_type: true,
         })
     }
 
@@ -125,6 +166,7 @@ impl LowerDmlToAst {
                 .map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             is_commented_out: field.is_commented_out,
+            is_legacy_type_syntax: false,
         })
     }
 
@@ -185,6 +227,7 @@ impl LowerDmlToAst {
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
+            dml::FieldType::Composite(name) => ast::Identifier::new(name),
             _ => unimplemented!("Connector specific types are not supported atm."),
         }
     }
@@ -1,7 +1,9 @@
 mod directive_box;
 mod lift;
+mod lint;
 mod lower;
 mod precheck;
+mod quick_fix;
 mod standardise;
 mod validate;
 mod validation_pipeline;
@@ -12,7 +14,9 @@ pub mod directive;
 use directive_box::*;
 
 use lift::*;
+use lint::*;
 pub use lower::*;
+pub use quick_fix::*;
 use standardise::*;
 use validate::*;
 pub use validation_pipeline::*;
@@ -0,0 +1,221 @@
+use crate::{
+    ast::{self, SourceFile},
+    dml,
+    error::{DatamodelError, DatamodelWarning, QuickFix, TextEdit},
+    FieldArity,
+};
+
+/// Computes machine-applicable fixes for errors and warnings.
+///
+/// Fixes are text edits on the original source files, so that editors can offer them without
+/// reformatting the whole schema.
+pub struct QuickFixer<'a> {
+    files: &'a [SourceFile],
+    ast_schema: &'a ast::SchemaAst,
+}
+
+impl<'a> QuickFixer<'a> {
+    pub fn new(files: &'a [SourceFile], ast_schema: &'a ast::SchemaAst) -> QuickFixer<'a> {
+        QuickFixer { files, ast_schema }
+    }
+
+    pub fn for_error(&self, error: &DatamodelError) -> Option<QuickFix> {
+        let (title, edits) = match error {
+            DatamodelError::ReservedScalarTypeError { type_name, span } => {
+                self.map_reserved_model_name(type_name, *span)?
+            }
+            _ => return None,
+        };
+
+        Some(QuickFix {
+            title,
+            span: error.span(),
+            diagnostic: error.description(),
+            edits,
+        })
+    }
+
+    pub fn for_warning(&self, warning: &DatamodelWarning, schema: &dml::Datamodel) -> Option<QuickFix> {
+        let (title, edits) = match warning {
+            DatamodelWarning::LegacySyntax { span, .. } => self.replace_legacy_type_syntax(*span)?,
+            DatamodelWarning::MissingBackRelationField {
+                model_name, field_name, ..
+            } => self.add_back_relation_field(schema, model_name, field_name)?,
+            DatamodelWarning::AmbiguousOneToOneRelation {
+                model_name, field_name, ..
+            } => self.add_references_argument(schema, model_name, field_name)?,
+            _ => return None,
+        };
+
+        Some(QuickFix {
+            title,
+            span: warning.span(),
+            diagnostic: warning.description(),
+            edits,
+        })
+    }
+
+    fn replace_legacy_type_syntax(&self, span: ast::Span) -> Option<(String, Vec<TextEdit>)> {
+        let field = self
+            .ast_schema
+            .models()
+            .into_iter()
+            .flat_map(|model| model.fields.iter())
+            .find(|field| field.field_type.span == span)?;
+
+        let new_type = match field.arity {
+            ast::FieldArity::List => format!("{}[]", field.field_type.name),
+            _ => field.field_type.name.clone(),
+        };
+
+        Some((
+            format!("Replace with `{}`", new_type),
+            vec![TextEdit::replace(span, &new_type)],
+        ))
+    }
+
+    /// Renames a model that uses the name of a scalar type, and maps it to its old table name.
+    /// Fields of that type keep referring to the scalar type, which is how they are parsed anyway.
+    fn map_reserved_model_name(&self, type_name: &str, span: ast::Span) -> Option<(String, Vec<TextEdit>)> {
+        let model = self
+            .ast_schema
+            .models()
+            .into_iter()
+            .find(|model| model.name.span == span && !model.is_composite_type)?;
+
+        let mut new_name = format!("{}Model", type_name);
+        let mut suffix = 1;
+        while self.ast_schema.tops.iter().any(|top| top.name() == new_name) {
+            suffix += 1;
+            new_name = format!("{}Model{}", type_name, suffix);
+        }
+
+        let mut edits = vec![TextEdit::replace(span, &new_name)];
+        if !model.directives.iter().any(|directive| directive.name.name == "map") {
+            edits.push(self.append_to_model(model, &format!("@@map(\"{}\")", type_name), true));
+        }
+
+        Some((
+            format!("Rename to `{}` and map it to \"{}\"", new_name, type_name),
+            edits,
+        ))
+    }
+
+    fn add_back_relation_field(
+        &self,
+        schema: &dml::Datamodel,
+        model_name: &str,
+        field_name: &str,
+    ) -> Option<(String, Vec<TextEdit>)> {
+        let field = schema.find_model(model_name)?.find_field(field_name)?;
+        let rel_info = match &field.field_type {
+            dml::FieldType::Relation(rel_info) => rel_info,
+            _ => return None,
+        };
+
+        let back_field = schema
+            .find_model(&rel_info.to)?
+            .related_field(model_name, &rel_info.name, field_name)?;
+        let ast_field = self.ast_schema.find_field(model_name, field_name)?;
+        let ast_related_model = self.ast_schema.find_model(&rel_info.to)?;
+
+        let arity = match back_field.arity {
+            FieldArity::List => "[]",
+            FieldArity::Optional => "?",
+            FieldArity::Required => "",
+        };
+        let mut declaration = format!("{} {}{}", back_field.name, model_name, arity);
+
+        // Relations named in the schema need the same name on both sides.
+        if has_relation_name_argument(ast_field) {
+            declaration.push_str(&format!(" @relation(\"{}\")", rel_info.name));
+        }
+
+        Some((
+            format!("Add `{}` to model `{}`", declaration, rel_info.to),
+            vec![self.append_to_model(ast_related_model, &declaration, false)],
+        ))
+    }
+
+    fn add_references_argument(
+        &self,
+        schema: &dml::Datamodel,
+        model_name: &str,
+        field_name: &str,
+    ) -> Option<(String, Vec<TextEdit>)> {
+        let to_fields = match &schema.find_model(model_name)?.find_field(field_name)?.field_type {
+            dml::FieldType::Relation(rel_info) if !rel_info.to_fields.is_empty() => rel_info.to_fields.clone(),
+            _ => return None,
+        };
+        let ast_field = self.ast_schema.find_field(model_name, field_name)?;
+        let references = format!("references: [{}]", to_fields.join(", "));
+        let file_id = ast_field.span.file_id;
+
+        let edit = match ast_field.directives.iter().find(|d| d.name.name == "relation") {
+            None => TextEdit::insert(
+                file_id,
+                ast_field.field_type.span.end,
+                &format!(" @relation({})", references),
+            ),
+            Some(directive) if directive.arguments.is_empty() => {
+                TextEdit::insert(file_id, directive.span.end, &format!("({})", references))
+            }
+            // The span of a directive with arguments ends with the closing parenthesis.
+            Some(directive) => TextEdit::insert(file_id, directive.span.end - 1, &format!(", {}", references)),
+        };
+
+        Some((format!("Add `{}`", references), vec![edit]))
+    }
+
+    /// Adds a line to the end of a model, after its last field, or its last block directive if
+    /// `after_directives` is set. The line is indented like the line it follows.
+    fn append_to_model(&self, model: &ast::Model, line: &str, after_directives: bool) -> TextEdit {
+        let file_id = model.span.file_id;
+        let text = self
+            .files
+            .get(file_id.0)
+            .map(|file| file.contents.as_str())
+            .unwrap_or("");
+
+        let mut anchors: Vec<ast::Span> = model.fields.iter().map(|field| field.span).collect();
+        if after_directives {
+            anchors.extend(model.directives.iter().map(|directive| directive.span));
+        }
+
+        match anchors.into_iter().max_by_key(|span| span.end) {
+            Some(anchor) => {
+                let line_start = text[..anchor.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+                let indentation: String = text[line_start..anchor.start]
+                    .chars()
+                    .take_while(|c| *c == ' ' || *c == '\t')
+                    .collect();
+                // Spans ending in a trailing comment include its line break.
+                let anchor_end = anchor.end - text[..anchor.end].ends_with('\n') as usize;
+                let line_end = text[anchor_end..]
+                    .find('\n')
+                    .map(|i| anchor_end + i)
+                    .unwrap_or_else(|| text.len());
+
+                TextEdit::insert(file_id, line_end, &format!("\n{}{}", indentation, line))
+            }
+            None => {
+                let block_close = text[..model.span.end].rfind('}').unwrap_or(model.span.end);
+
+                TextEdit::insert(file_id, block_close, &format!("  {}\n", line))
+            }
+        }
+    }
+}
+
+fn has_relation_name_argument(field: &ast::Field) -> bool {
+    field
+        .directives
+        .iter()
+        .filter(|directive| directive.name.name == "relation")
+        .any(|directive| {
+            directive
+                .arguments
+                .iter()
+                .any(|arg| arg.name.name == "" || arg.name.name == "name")
+        })
+}
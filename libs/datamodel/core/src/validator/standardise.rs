@@ -160,6 +160,7 @@ impl Standardiser {
             name: relation_name,
             database_name: None,
             is_embedded: false,
+            schema: None,
             fields: vec![a_related_field, b_related_field],
             indices: vec![],
             id_fields: vec![],
@@ -425,6 +426,7 @@ impl Standardiser {
                     dml::FieldType::Relation(rel_info) => {
                         self.get_datasource_fields_for_relation_field(&field, &rel_info, &datamodel)
                     }
+                    dml::FieldType::Composite(_) => self.get_datasource_fields_for_composite_field(&field),
                     dml::FieldType::ConnectorSpecific(_) => unimplemented!(
                         "ConnectorSpecific is not supported here as it will be removed soon."
                     ),
@@ -483,6 +485,17 @@ impl Standardiser {
         vec![datasource_field]
     }
 
+    /// Composite values are stored as a single serialized document.
+    fn get_datasource_fields_for_composite_field(&self, field: &dml::Field) -> Vec<DataSourceField> {
+        let datasource_field = dml::DataSourceField {
+            name: field.final_single_database_name().to_owned(),
+            field_type: dml::ScalarType::String,
+            arity: field.arity,
+            default_value: None,
+        };
+        vec![datasource_field]
+    }
+
     fn get_datasource_fields_for_relation_field(
         &self,
         field: &dml::Field,
@@ -35,12 +35,107 @@ impl<'a> Validator<'a> {
             if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
                 errors.push(err);
             }
+            if let Err(err) = self.validate_model_schema(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.push(err);
+            }
+            if let Err(err) = self.validate_index_methods(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.push(err);
+            }
 
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
             {
                 errors.append(the_errors);
             }
+
+            if let Err(ref mut the_errors) =
+                self.validate_computed_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_composite_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.append(the_errors);
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Validations that need to know which side of a relation holds the foreign key, which is only
+    /// decided by the standardiser.
+    pub fn post_standardisation_validate(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        schema: &dml::Datamodel,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for model in schema.models() {
+            if let Err(ref mut the_errors) =
+                self.validate_relation_fields_in_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors.append(the_errors);
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Relation fields in `@@unique` and `@@index` stand for their foreign key columns, so only
+    /// the side of the relation holding the foreign key can be indexed.
+    fn validate_relation_fields_in_indexes(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for index in &model.indices {
+            for field_name in &index.fields {
+                let field = model.find_field(field_name).expect(STATE_ERROR);
+
+                if !field.field_type.is_relation()
+                    || (field.arity != FieldArity::List && !field.data_source_fields.is_empty())
+                {
+                    continue;
+                }
+
+                let directive_name = if index.tpe == IndexType::Unique {
+                    "unique"
+                } else {
+                    "index"
+                };
+                let span = ast_model
+                    .directives
+                    .iter()
+                    .filter(|directive| directive.name.name == directive_name)
+                    .find(|directive| directive_refers_to_field(directive, field_name))
+                    .map(|directive| directive.span)
+                    .unwrap_or(ast_model.span);
+
+                errors.push(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The {}index definition refers to the relation field `{}`, which does not hold a foreign key. Only relation fields holding a foreign key can be part of an index.",
+                        if index.tpe == IndexType::Unique { "unique " } else { "" },
+                        field_name
+                    ),
+                    &model.name,
+                    span,
+                ));
+            }
         }
 
         if errors.has_errors() {
@@ -60,7 +155,11 @@ impl<'a> Validator<'a> {
         };
 
         for field in model.fields() {
-            if field.arity == FieldArity::List && !scalar_lists_are_supported && !field.field_type.is_relation() {
+            if field.arity == FieldArity::List
+                && !scalar_lists_are_supported
+                && !field.field_type.is_relation()
+                && !field.field_type.is_composite()
+            {
                 let ast_field = ast_model
                     .fields
                     .iter()
@@ -82,6 +181,150 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// Composite values are stored as one document per record, so they can not be lists or identify records.
+    fn validate_composite_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for field in model.fields().filter(|field| field.field_type.is_composite()) {
+            let in_index = model.indices.iter().any(|index| index.fields.contains(&field.name))
+                || model.id_fields.contains(&field.name);
+
+            let message = if field.arity == FieldArity::List {
+                "Lists of composite types are not supported yet."
+            } else if field.is_id || field.is_unique || in_index {
+                "Fields of a composite type can not be used in ids, unique constraints or indexes."
+            } else {
+                continue;
+            };
+
+            let ast_field = ast_model
+                .fields
+                .iter()
+                .find(|ast_field| ast_field.name.name == field.name)
+                .expect(STATE_ERROR);
+
+            errors.push(DatamodelError::new_model_validation_error(
+                &format!("The field `{}` is invalid. {}", field.name, message),
+                &model.name,
+                ast_field.span,
+            ));
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_computed_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let virtual_columns_are_supported = match self.source {
+            Some(source) => source.connector_type() != configuration::POSTGRES_SOURCE_NAME,
+            None => true,
+        };
+
+        for field in model.fields() {
+            let computed = match &field.computed {
+                Some(computed) => computed,
+                None => continue,
+            };
+
+            let ast_field = ast_model
+                .fields
+                .iter()
+                .find(|ast_field| ast_field.name.name == field.name)
+                .expect(STATE_ERROR);
+
+            let message = if field.default_value.is_some() {
+                "Computed fields can not have a default value."
+            } else if field.is_id {
+                "Computed fields can not be used as id."
+            } else if field.is_updated_at {
+                "Computed fields can not be marked with @updatedAt."
+            } else if !computed.stored && !virtual_columns_are_supported {
+                "Virtual computed fields are not supported by the postgresql provider. Use `stored: true`."
+            } else {
+                continue;
+            };
+
+            errors.push(DatamodelError::new_directive_validation_error(
+                message,
+                "computed",
+                ast_field.span,
+            ));
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_model_schema(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let schema = match &model.schema {
+            Some(schema) => schema,
+            None => return Ok(()),
+        };
+
+        let schema_is_declared = self
+            .source
+            .map(|source| source.schemas().iter().any(|declared| declared == schema))
+            .unwrap_or(false);
+
+        if schema_is_declared {
+            Ok(())
+        } else {
+            Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The schema `{}` is not declared in the `schemas` property of the datasource.",
+                    schema
+                ),
+                &model.name,
+                ast_model.span,
+            ))
+        }
+    }
+
+    /// Databases either reject the index methods they do not know, or silently build a B-tree index instead.
+    fn validate_index_methods(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let connector_type = match self.source {
+            Some(source) => source.connector_type(),
+            None => return Ok(()),
+        };
+
+        for method in model.indices.iter().filter_map(|index| index.method) {
+            let is_supported = match (connector_type, method) {
+                (_, dml::IndexMethod::BTree) => true,
+                // InnoDB, the default storage engine, only builds B-tree indexes.
+                (configuration::MYSQL_SOURCE_NAME, dml::IndexMethod::FullText)
+                | (configuration::MYSQL_SOURCE_NAME, dml::IndexMethod::Spatial) => true,
+                (configuration::POSTGRES_SOURCE_NAME, dml::IndexMethod::Hash)
+                | (configuration::POSTGRES_SOURCE_NAME, dml::IndexMethod::Gist)
+                | (configuration::POSTGRES_SOURCE_NAME, dml::IndexMethod::Gin)
+                | (configuration::POSTGRES_SOURCE_NAME, dml::IndexMethod::Brin)
+                | (configuration::POSTGRES_SOURCE_NAME, dml::IndexMethod::SpGist) => true,
+                _ => false,
+            };
+
+            if !is_supported {
+                return Err(DatamodelError::new_model_validation_error(
+                    &format!(
+                        "The index type `{}` is not supported by the {} provider.",
+                        method.to_string(),
+                        connector_type
+                    ),
+                    &model.name,
+                    ast_model.span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     fn validate_model_has_id(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
         let multiple_single_field_id_error = Err(DatamodelError::new_model_validation_error(
             "At most one field must be marked as the id field with the `@id` directive.",
@@ -278,3 +521,15 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 }
+
+fn directive_refers_to_field(directive: &ast::Directive, field_name: &str) -> bool {
+    directive.arguments.iter().any(|arg| match &arg.value {
+        ast::Expression::Array(values, _) if arg.name.name == "" || arg.name.name == "fields" => {
+            values.iter().any(|value| match value {
+                ast::Expression::ConstantValue(name, _) => name == field_name,
+                _ => false,
+            })
+        }
+        _ => false,
+    })
+}
@@ -1,5 +1,8 @@
 use super::*;
-use crate::{ast, configuration, dml, error::ErrorCollection};
+use crate::{
+    ast, configuration, dml,
+    error::{DatamodelWarning, ErrorCollection},
+};
 
 /// Wrapper for all lift and validation steps
 pub struct ValidationPipeline<'a> {
@@ -67,10 +70,29 @@ impl<'a> ValidationPipeline<'a> {
             all_errors.append(&mut err);
         }
 
+        // Phase 6: Validations that need the consistent datamodel.
+        if !all_errors.has_errors() {
+            if let Err(mut err) = self.validator.post_standardisation_validate(ast_schema, &schema) {
+                all_errors.append(&mut err);
+            }
+        }
+
         if all_errors.has_errors() {
             Err(all_errors)
         } else {
             Ok(schema)
         }
     }
+
+    /// Like `validate`, but also returns the warnings for the valid datamodel, e.g. for
+    /// deprecated directives or legacy syntax.
+    pub fn validate_with_warnings(
+        &self,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<(dml::Datamodel, Vec<DatamodelWarning>), ErrorCollection> {
+        let schema = self.validate(ast_schema)?;
+        let warnings = Linter::lint(ast_schema, &schema);
+
+        Ok((schema, warnings))
+    }
 }
@@ -0,0 +1,152 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::ScalarType, dml, error::DatamodelError};
+
+#[test]
+fn parse_composite_type() {
+    let dml = r#"
+    model User {
+        id      Int @id
+        address Address?
+    }
+
+    /// A postal address.
+    type Address {
+        street String
+        zip    String?
+    }
+    "#;
+
+    let schema = parse(dml);
+    let address = schema.find_composite_type("Address").expect("Composite type not found");
+    assert_eq!(address.documentation, Some("A postal address.".to_owned()));
+    address
+        .find_field("street")
+        .unwrap()
+        .assert_base_type(&ScalarType::String)
+        .assert_arity(&dml::FieldArity::Required);
+    address
+        .find_field("zip")
+        .unwrap()
+        .assert_base_type(&ScalarType::String)
+        .assert_arity(&dml::FieldArity::Optional);
+
+    assert!(schema.find_model("Address").is_none());
+
+    let user_model = schema.assert_has_model("User");
+    let address_field = user_model.assert_has_field("address");
+    assert_eq!(
+        address_field.field_type,
+        dml::FieldType::Composite("Address".to_owned())
+    );
+    address_field.assert_arity(&dml::FieldArity::Optional);
+
+    let dsf = address_field.assert_has_one_datasource_field();
+    assert_eq!(dsf.name, "address");
+    assert_eq!(dsf.field_type, ScalarType::String);
+}
+
+#[test]
+fn composite_types_can_be_nested() {
+    let dml = r#"
+    model User {
+        id      Int @id
+        address Address
+    }
+
+    type Address {
+        street String
+        geo    Coordinates
+    }
+
+    type Coordinates {
+        lat Float
+        lng Float
+    }
+    "#;
+
+    let schema = parse(dml);
+    let address = schema.find_composite_type("Address").unwrap();
+
+    assert_eq!(
+        address.find_field("geo").unwrap().field_type,
+        dml::FieldType::Composite("Coordinates".to_owned())
+    );
+}
+
+#[test]
+fn composite_types_are_rendered_with_the_type_keyword() {
+    let dml = r#"model User {
+  id      Int     @id
+  address Address
+}
+
+type Address {
+  street String
+}"#;
+
+    let schema = parse(dml);
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn fail_on_directives_on_composite_type_fields() {
+    let dml = r#"
+    type Address {
+        street String @default("")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Directives are not supported on fields of composite types.",
+        "default",
+        Span::new(43, 54),
+    ));
+}
+
+#[test]
+fn fail_on_relation_fields_in_composite_types() {
+    let dml = r#"
+    model User {
+        id      Int @id
+        address Address
+    }
+
+    type Address {
+        owner User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The field `owner` refers to a model. Composite types can not have relation fields.",
+        "Address",
+        Span::new(106, 110),
+    ));
+}
+
+#[test]
+fn fail_on_lists_of_composite_types() {
+    let dml = r#"
+    model User {
+        id        Int @id
+        addresses Address[]
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The field `addresses` is invalid. Lists of composite types are not supported yet.",
+        "User",
+        Span::new(52, 71),
+    ));
+}
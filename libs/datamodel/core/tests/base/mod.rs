@@ -2,4 +2,6 @@ pub mod array_sugar;
 pub mod base_types;
 pub mod basic;
 pub mod comments;
+pub mod composite_types;
 pub mod duplicates;
+pub mod multiple_files;
@@ -0,0 +1,81 @@
+use crate::common::*;
+use datamodel::{
+    ast::{FileId, Span},
+    error::DatamodelError,
+    SourceFile,
+};
+
+#[test]
+fn models_can_relate_to_models_in_other_files() {
+    let files = vec![
+        SourceFile::new(
+            "user.prisma",
+            r#"
+            model User {
+                id    Int    @id
+                posts Post[]
+            }
+            "#,
+        ),
+        SourceFile::new(
+            "post.prisma",
+            r#"
+            model Post {
+                id     Int  @id
+                author User
+            }
+            "#,
+        ),
+    ];
+
+    let datamodel = datamodel::parse_datamodel_from_files(&files).unwrap();
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_field("posts")
+        .assert_relation_to("Post");
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_field("author")
+        .assert_relation_to("User");
+}
+
+#[test]
+fn errors_point_into_the_file_they_occur_in() {
+    let post = r#"
+    model Post {
+        id     Int    @id
+        author Author
+    }
+    "#;
+
+    let files = vec![
+        SourceFile::new("user.prisma", "model User {\n  id Int @id\n}\n"),
+        SourceFile::new("post.prisma", post),
+    ];
+
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+    let start = post.find("Author").unwrap();
+
+    errors.assert_is(DatamodelError::new_type_not_found_error(
+        "Author",
+        Span::new(start, start + "Author".len()).in_file(FileId(1)),
+    ));
+
+    assert!(errors.to_pretty_string_in_files(&files).contains("post.prisma:4"));
+}
+
+#[test]
+fn syntax_errors_of_all_files_are_reported() {
+    let files = vec![
+        SourceFile::new("a.prisma", "model A {\n  id Int @\n}\n"),
+        SourceFile::new("b.prisma", "model B {\n  id Int @id\n}\n"),
+        SourceFile::new("c.prisma", "enum C {\n  A-B\n}\n"),
+    ];
+
+    let errors = datamodel::parse_schema_ast_from_files(&files).unwrap_err();
+
+    errors.assert_length(2);
+    assert_eq!(errors.errors[0].span().file_id, FileId(0));
+    assert_eq!(errors.errors[1].span().file_id, FileId(2));
+}
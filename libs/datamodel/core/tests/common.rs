@@ -311,6 +311,17 @@ pub fn parse(datamodel_string: &str) -> datamodel::Datamodel {
     }
 }
 
+#[allow(dead_code)] // Not sure why the compiler thinks this is never used.
+pub fn parse_warnings(datamodel_string: &str) -> Vec<DatamodelWarning> {
+    match datamodel::parse_datamodel_with_warnings(datamodel_string) {
+        Ok((_, warnings)) => warnings,
+        Err(errs) => panic!(
+            "Datamodel parsing failed: {}",
+            errs.to_pretty_string("", datamodel_string)
+        ),
+    }
+}
+
 #[allow(dead_code)] // Not sure why the compiler thinks this is never used.
 pub fn parse_error(datamodel_string: &str) -> ErrorCollection {
     match datamodel::parse_datamodel(datamodel_string) {
@@ -89,6 +89,107 @@ fn fail_to_load_sources_for_invalid_source() {
     }
 }
 
+#[test]
+fn serialize_postgres_source_with_schemas_to_dmmf() {
+    const DATAMODEL: &str = r#"
+        datasource pg1 {
+            provider = "postgresql"
+            url = "https://localhost/postgres1"
+            schemas = ["public", "billing"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(DATAMODEL).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+  {
+    "name": "pg1",
+    "connectorType": "postgresql",
+    "url": {
+      "fromEnvVar": null,
+      "value": "https://localhost/postgres1"
+    },
+    "schemas": ["public", "billing"]
+  }
+]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn serialize_source_with_shadow_database_url_to_dmmf() {
+    std::env::set_var("SHADOW_DATABASE_URL", "mysql://localhost/shadow");
+    const DATAMODEL: &str = r#"
+        datasource pg1 {
+            provider = "postgresql"
+            url = "postgresql://localhost/postgres1"
+            shadowDatabaseUrl = "postgresql://localhost/shadow"
+        }
+
+        datasource mysql1 {
+            provider = "mysql"
+            url = "mysql://localhost"
+            shadowDatabaseUrl = env("SHADOW_DATABASE_URL")
+        }
+    "#;
+    let config = datamodel::parse_configuration(DATAMODEL).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+  {
+    "name": "pg1",
+    "connectorType": "postgresql",
+    "url": {
+      "fromEnvVar": null,
+      "value": "postgresql://localhost/postgres1"
+    },
+    "shadowDatabaseUrl": {
+      "fromEnvVar": null,
+      "value": "postgresql://localhost/shadow"
+    }
+  },
+  {
+    "name": "mysql1",
+    "connectorType": "mysql",
+    "url": {
+      "fromEnvVar": null,
+      "value": "mysql://localhost"
+    },
+    "shadowDatabaseUrl": {
+      "fromEnvVar": "SHADOW_DATABASE_URL",
+      "value": "mysql://localhost/shadow"
+    }
+  }
+]"#;
+
+    assert_eq_json(&rendered, expected);
+
+    let sources = datamodel::json::mcf::sources_from_json_value(serde_json::from_str(&rendered).unwrap());
+
+    assert_eq!(
+        sources[1].shadow_database_url().unwrap().from_env_var.as_deref(),
+        Some("SHADOW_DATABASE_URL")
+    );
+}
+
+#[test]
+fn fail_to_load_sources_with_the_same_url_and_shadow_database_url() {
+    const DATAMODEL: &str = r#"
+        datasource pg1 {
+            provider = "postgresql"
+            url = "postgresql://localhost/postgres1"
+            shadowDatabaseUrl = "postgresql://localhost/postgres1"
+        }
+    "#;
+
+    let error = datamodel::parse_configuration(DATAMODEL).unwrap_err();
+
+    error.assert_is(DatamodelError::new_validation_error(
+        "The `shadowDatabaseUrl` must point to a different database than the `url`.",
+        datamodel::ast::Span::new(147, 181),
+    ));
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
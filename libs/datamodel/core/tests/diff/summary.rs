@@ -0,0 +1,162 @@
+use datamodel::diff::{Arity, Change, TopKind};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+fn diff(previous: &str, next: &str) -> datamodel::diff::DatamodelDiff {
+    let previous = datamodel::parse_schema_ast(previous).unwrap();
+    let next = datamodel::parse_schema_ast(next).unwrap();
+
+    datamodel::diff(&previous, &next)
+}
+
+#[test]
+fn field_and_index_changes_are_summarized_per_model() {
+    let previous = r#"
+        model User {
+            id        Int      @id
+            tenantId  Int
+            createdAt DateTime
+            email     String?
+            nickname  String
+        }
+    "#;
+    let next = r#"
+        model User {
+            id        Int      @id
+            tenantId  Int
+            createdAt DateTime @default(now())
+            email     String
+            age       Int?
+
+            @@index([tenantId, createdAt])
+        }
+    "#;
+
+    let diff = diff(previous, next);
+
+    assert_eq!(
+        diff.to_string(),
+        "Model `User`: field `age` (`Int?`) added; field `nickname` removed; attribute `@default(now())` added to \
+         `createdAt`; field `email` made required; index added on (`tenantId`, `createdAt`)\n"
+    );
+    assert_eq!(diff.tops[0].kind, TopKind::Model);
+    assert!(diff.tops[0].changes.contains(&Change::FieldArityChanged {
+        field: "email".to_owned(),
+        previous: Arity::Optional,
+        next: Arity::Required,
+    }));
+}
+
+#[test]
+fn unchanged_schemas_have_an_empty_diff() {
+    let schema = r#"
+        model User {
+            id Int @id
+            @@unique([id])
+        }
+    "#;
+
+    let diff = diff(schema, schema);
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn created_and_deleted_tops_are_summarized() {
+    let previous = r#"
+        model Post {
+            id Int @id
+        }
+
+        enum Role {
+            USER
+            ADMIN
+        }
+    "#;
+    let next = r#"
+        type Address {
+            street String
+        }
+
+        enum Role {
+            USER
+            EDITOR
+        }
+    "#;
+
+    assert_eq!(
+        diff(previous, next).to_string(),
+        "Enum `Role`: value `EDITOR` added; value `ADMIN` removed\n\
+         Type `Address`: created\n\
+         Model `Post`: deleted\n"
+    );
+}
+
+#[test]
+fn attribute_and_datasource_changes_are_summarized() {
+    let previous = r#"
+        datasource db {
+            provider = "postgresql"
+            url = "postgresql://localhost/a"
+        }
+
+        model User {
+            id   Int    @id
+            name String @map("user_name")
+
+            @@map("users")
+            @@unique([name])
+        }
+    "#;
+    let next = r#"
+        datasource db {
+            provider = "postgresql"
+            url = env("DATABASE_URL")
+        }
+
+        model User {
+            id   Int    @id
+            name String
+
+            @@map("accounts")
+        }
+    "#;
+
+    assert_eq!(
+        diff(previous, next).to_string(),
+        "Datasource `db`: property `url` changed from `\"postgresql://localhost/a\"` to `env(\"DATABASE_URL\")`\n\
+         Model `User`: attribute `@map(\"user_name\")` removed from `name`; attribute `@@map(\"users\")` changed to \
+         `@@map(\"accounts\")`; unique constraint removed on (`name`)\n"
+    );
+}
+
+#[test]
+fn diffs_render_to_json() {
+    let previous = r#"
+        model User {
+            id Int @id
+        }
+    "#;
+    let next = r#"
+        model User {
+            id    Int    @id
+            email String @unique
+        }
+    "#;
+
+    let json: serde_json::Value = serde_json::from_str(&diff(previous, next).render_to_json()).unwrap();
+
+    assert_eq!(
+        json,
+        json!({
+            "tops": [{
+                "kind": "model",
+                "name": "User",
+                "changes": [
+                    { "change": "fieldCreated", "field": "email", "type": "String" }
+                ]
+            }]
+        })
+    );
+}
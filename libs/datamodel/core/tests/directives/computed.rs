@@ -0,0 +1,103 @@
+use crate::common::*;
+use datamodel::{ast::Span, common::ScalarType, error::DatamodelError, render_datamodel_to_string, ComputedValue};
+
+#[test]
+fn should_apply_computed_directive() {
+    let dml = r#"
+    model Order {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @computed("price * quantity")
+        label    String? @computed("'order'", stored: false)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let order_model = schema.assert_has_model("Order");
+    let total = order_model
+        .assert_has_field("total")
+        .assert_base_type(&ScalarType::Int);
+
+    assert_eq!(
+        total.computed,
+        Some(ComputedValue {
+            expression: "price * quantity".to_owned(),
+            stored: true,
+        })
+    );
+    assert!(total.is_read_only());
+
+    let label = order_model.assert_has_field("label");
+    assert_eq!(
+        label.computed,
+        Some(ComputedValue {
+            expression: "'order'".to_owned(),
+            stored: false,
+        })
+    );
+
+    assert!(!order_model.assert_has_field("price").is_read_only());
+}
+
+#[test]
+fn computed_directive_must_round_trip() {
+    let dml = r#"
+    model Order {
+        id    Int @id
+        price Int
+        total Int @computed("price * 2")
+        half  Int @computed("price / 2", stored: false)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains(r#"@computed("price * 2")"#));
+    assert!(rendered.contains(r#"@computed("price / 2", stored: false)"#));
+    assert_eq!(parse(&rendered), schema);
+}
+
+#[test]
+fn should_fail_if_computed_field_has_a_default() {
+    let dml = r#"
+    model Order {
+        id    Int @id
+        price Int
+        total Int @computed("price * 2") @default(0)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Computed fields can not have a default value.",
+        "computed",
+        Span::new(67, 111),
+    ));
+}
+
+#[test]
+fn should_fail_on_virtual_computed_fields_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432"
+    }
+
+    model Order {
+        id    Int @id
+        price Int
+        total Int @computed("price * 2", stored: false)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Virtual computed fields are not supported by the postgresql provider. Use `stored: true`.",
+        "computed",
+        Span::new(170, 217),
+    ));
+}
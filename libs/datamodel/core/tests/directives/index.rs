@@ -1,4 +1,4 @@
-use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexMethod, IndexType, SortOrder};
 
 use crate::common::*;
 
@@ -20,6 +20,8 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
@@ -44,9 +46,70 @@ fn indexes_on_relation_fields_must_work() {
         name: None,
         fields: vec!["identification".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
+#[test]
+fn indexes_on_compound_relation_fields_must_work() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@unique([firstName, lastName])
+    }
+
+    model Post {
+        id        Int      @id
+        createdAt DateTime
+        author    User     @relation(references: [firstName, lastName])
+
+        @@index([author, createdAt])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["author".to_string(), "createdAt".to_string()],
+        tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: None,
+    });
+
+    // The relation field stands for both of its foreign key columns.
+    assert_eq!(post_model.assert_has_field("author").data_source_fields.len(), 2);
+}
+
+#[test]
+fn must_error_when_indexing_a_relation_field_without_foreign_key() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+
+        @@index([posts])
+    }
+
+    model Post {
+        id     Int  @id
+        author User @relation(references: [id])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The index definition refers to the relation field `posts`, which does not hold a foreign key. Only relation fields holding a foreign key can be part of an index.",
+        "User",
+        Span::new(75, 89),
+    ));
+}
+
 #[test]
 fn the_name_argument_must_work() {
     let dml = r#"
@@ -65,9 +128,55 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: None,
+    });
+}
+
+#[test]
+fn the_sort_and_type_arguments_must_work() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@index([firstName, lastName], sort: [Asc, Desc], type: Hash)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Normal,
+        sort_orders: vec![SortOrder::Asc, SortOrder::Desc],
+        method: Some(IndexMethod::Hash),
     });
 }
 
+#[test]
+fn must_error_when_the_sort_argument_does_not_match_the_fields() {
+    let dml = r#"
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@index([firstName, lastName], sort: [Desc])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `sort` argument must contain one sort order for each field of the index.",
+        "index",
+        Span::new(143, 149),
+    ));
+}
+
 #[test]
 fn multiple_index_must_work() {
     let dml = r#"
@@ -88,12 +197,16 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
@@ -131,3 +244,122 @@ fn index_directives_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn index_sort_and_type_arguments_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model User {
+            id        Int    @id
+            firstName String
+            lastName  String
+
+            @@index([firstName,lastName], sort: [Desc, Asc], type: Gin)
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(parse(&rendered), schema);
+}
+
+#[test]
+fn postgres_specific_index_types_must_work_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432/prisma"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+
+        @@index([firstName], type: Gin)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string()],
+        tpe: IndexType::Normal,
+        sort_orders: vec![],
+        method: Some(IndexMethod::Gin),
+    });
+}
+
+#[test]
+fn must_error_on_postgres_specific_index_types_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306/prisma"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+
+        @@index([firstName], type: Gin)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The index type `Gin` is not supported by the mysql provider.",
+        "User",
+        Span::new(105, 218),
+    ));
+}
+
+#[test]
+fn must_error_on_hash_indexes_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306/prisma"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+
+        @@index([firstName], type: Hash)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The index type `Hash` is not supported by the mysql provider.",
+        "User",
+        Span::new(105, 219),
+    ));
+}
+
+#[test]
+fn must_error_on_mysql_specific_index_types_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432/prisma"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+
+        @@index([firstName], type: FullText)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The index type `FullText` is not supported by the postgresql provider.",
+        "User",
+        Span::new(115, 233),
+    ));
+}
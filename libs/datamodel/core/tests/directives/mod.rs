@@ -1,4 +1,5 @@
 pub mod builtin_directives;
+pub mod computed;
 pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
@@ -10,6 +11,7 @@ pub mod relations_consistency;
 pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_positive;
+pub mod schema;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
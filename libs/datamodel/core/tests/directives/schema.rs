@@ -0,0 +1,74 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError};
+
+#[test]
+fn schema_directive() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432/prisma"
+        schemas = ["billing"]
+    }
+
+    model User {
+        id Int @id
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert_eq!(schema.assert_has_model("User").schema, None);
+    assert_eq!(
+        schema.assert_has_model("Invoice").schema,
+        Some("billing".to_owned())
+    );
+}
+
+#[test]
+fn schema_directive_must_reference_a_declared_schema() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432/prisma"
+        schemas = ["public"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("billing")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The schema `billing` is not declared in the `schemas` property of the datasource.",
+        "Invoice",
+        Span::new(144, 213),
+    ));
+}
+
+#[test]
+fn schemas_property_is_only_supported_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306/prisma"
+        schemas = ["billing"]
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_validation_error(
+        "The `schemas` property is only supported by the postgresql provider.",
+        Span::new(112, 123),
+    ));
+}
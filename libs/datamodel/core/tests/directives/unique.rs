@@ -20,6 +20,8 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
@@ -44,6 +46,8 @@ fn unique_indexes_on_relation_fields_must_work() {
         name: None,
         fields: vec!["identification".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
@@ -65,6 +69,8 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
@@ -88,12 +94,16 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        method: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        method: None,
     });
 }
 
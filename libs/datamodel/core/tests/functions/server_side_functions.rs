@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::{common::ScalarType, DefaultValue, ValueGenerator};
+use datamodel::{ast::Span, common::ScalarType, error::DatamodelError, DefaultValue, ValueGenerator};
 
 #[test]
 fn correctly_handle_server_side_now_function() {
@@ -66,3 +66,49 @@ fn correctly_handle_server_side_uuid_function() {
             ValueGenerator::new("uuid".to_owned(), Vec::new()).unwrap(),
         ));
 }
+
+#[test]
+fn correctly_handle_server_side_dbgenerated_function() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(dbgenerated("gen_random_uuid()"))
+        legacy String @default(dbgenerated())
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_field("someId")
+        .assert_base_type(&ScalarType::String)
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated(
+            "gen_random_uuid()".to_owned(),
+        )));
+    user_model
+        .assert_has_field("legacy")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated(String::new())));
+
+    let rendered = datamodel::render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains(r#"@default(dbgenerated("gen_random_uuid()"))"#));
+    assert!(rendered.contains("@default(dbgenerated())"));
+}
+
+#[test]
+fn should_error_if_dbgenerated_gets_more_than_one_argument() {
+    let dml = r#"
+    model User {
+        id Int @id
+        someId String @default(dbgenerated("a", "b"))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The dbgenerated function takes a single string argument containing the SQL expression.",
+        "default",
+        Span::new(68, 89),
+    ));
+}
@@ -2,10 +2,13 @@ pub mod base;
 pub mod common;
 pub mod config;
 pub mod datasource_fields;
+pub mod diff;
 pub mod directives;
 pub mod functions;
 pub mod parsing;
 pub mod reformat;
 pub mod render_to_dmmf;
+pub mod render_to_json_schema;
 pub mod renderer;
 pub mod types;
+pub mod warnings;
@@ -1 +1,2 @@
 pub mod nice_errors;
+pub mod recovery;
@@ -123,21 +123,6 @@ fn nice_error_missing_braces() {
     ));
 }
 
-#[test]
-fn nice_error_broken_field_type_legacy_list() {
-    let dml = r#"
-    model User {
-        id [Int] @id
-    }"#;
-
-    let error = parse_error(dml);
-
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "To specify a list, please use `Type[]` instead of `[Type]`.",
-        Span::new(29, 34),
-    ));
-}
-
 #[test]
 fn nice_error_broken_field_type_legacy_colon() {
     let dml = r#"
@@ -153,36 +138,6 @@ fn nice_error_broken_field_type_legacy_colon() {
     ));
 }
 
-#[test]
-fn nice_error_broken_field_type_legacy_required() {
-    let dml = r#"
-    model User {
-        id Int! @id
-    }"#;
-
-    let error = parse_error(dml);
-
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Fields are required by default, `!` is no longer required.",
-        Span::new(29, 33),
-    ));
-}
-
-#[test]
-fn nice_error_legacy_model_decl() {
-    let dml = r#"
-    type User {
-        id Int @id
-    }"#;
-
-    let error = parse_error(dml);
-
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Model declarations have to be indicated with the `model` keyword.",
-        Span::new(5, 9),
-    ));
-}
-
 #[test]
 fn optional_list_fields_must_error() {
     let dml = r#"
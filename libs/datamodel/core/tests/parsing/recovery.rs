@@ -0,0 +1,79 @@
+use crate::common::*;
+use datamodel::ast::Span;
+
+#[test]
+fn all_syntax_errors_are_reported() {
+    let dml = r#"
+model User {
+  id   Int @id
+  name String @
+}
+
+model Post {
+  id Int @id
+}
+
+enum Role {
+  A-dmin
+}
+"#;
+
+    let errors = parse_error(dml);
+    errors.assert_length(2);
+
+    let first = errors.errors[0].span();
+    assert!(first.start > dml.find("name String").unwrap());
+    assert!(first.end < dml.find("model Post").unwrap());
+
+    let dash = dml.find("-dmin").unwrap();
+    assert_eq!(errors.errors[1].span(), Span::new(dash, dash));
+}
+
+#[test]
+fn well_formed_blocks_are_kept() {
+    let dml = r#"
+datasource db {
+  provider = "postgresql"
+  url      = "postgresql://localhost"
+}
+
+model User {
+  id   Int @id
+  name String @
+}
+
+// A post.
+model Post {
+  id Int @id
+}
+"#;
+
+    let (ast, errors) = datamodel::parse_schema_ast_with_recovery(dml);
+
+    assert_eq!(errors.errors.len(), 1);
+    assert!(ast.find_model("User").is_none());
+
+    let post = ast.find_model("Post").unwrap();
+    assert_eq!(post.documentation.as_ref().unwrap().text, "A post.");
+    assert_eq!(&dml[post.name.span.start..post.name.span.end], "Post");
+    assert!(ast.find_source("db").is_some());
+}
+
+#[test]
+fn a_missing_closing_brace_does_not_swallow_the_next_block() {
+    let dml = r#"
+model User {
+  id Int @id
+
+model Post {
+  id Int @id
+}
+"#;
+
+    let (ast, errors) = datamodel::parse_schema_ast_with_recovery(dml);
+    let end_of_user = dml.find("@id\n\nmodel Post").unwrap() + "@id".len();
+
+    assert_eq!(errors.errors.len(), 1);
+    assert_eq!(errors.errors[0].span(), Span::new(end_of_user, end_of_user));
+    assert!(ast.find_model("Post").is_some());
+}
@@ -15,7 +15,7 @@ fn test_reformat_model() {
 }"#;
 
     let mut buf = Vec::new();
-    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2);
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
     let actual = str::from_utf8(&buf).expect("unable to convert to string");
     assert_eq!(expected, actual);
 }
@@ -35,7 +35,7 @@ fn test_reformat_config() {
 }"#;
 
     let mut buf = Vec::new();
-    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2);
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
     let actual = str::from_utf8(&buf).expect("unable to convert to string");
     assert_eq!(expected, actual);
 }
@@ -56,7 +56,7 @@ fn test_reformat_tabs() {
 
     let mut buf = Vec::new();
     // replaces \t placeholder with a real tab
-    datamodel::ast::reformat::Reformatter::reformat_to(&input.replace("\\t", "\t"), &mut buf, 2);
+    datamodel::ast::reformat::Reformatter::reformat_to(&input.replace("\\t", "\t"), &mut buf, 2).unwrap();
     let actual = str::from_utf8(&buf).expect("unable to convert to string");
     assert_eq!(expected, actual);
 }
@@ -74,8 +74,7 @@ model a {
 /// ajlsdkfkjasflk
 // model ok {}"#;
 
-    let _expected = r#"
-model a {
+    let expected = r#"model a {
   one Int
   two Int
   // bs  b[] @relation(references: [a])
@@ -86,11 +85,9 @@ model a {
 // model ok {}"#;
 
     let mut buf = Vec::new();
-    // replaces \t placeholder with a real tab
-    datamodel::ast::reformat::Reformatter::reformat_to(&input.replace("\\t", "\t"), &mut buf, 2);
-    // FIXME: This is ignored. See explanation in following test for details on why.
-    //    let actual = str::from_utf8(&buf).expect("unable to convert to string");
-    //    assert_eq!(expected, actual);
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
 }
 
 #[test]
@@ -107,8 +104,7 @@ model a {
 // ajlsdkfkjasflk
 "#;
 
-    let _expected = r#"
-model a {
+    let expected = r#"model a {
   one Int
   two Int
   // bs  b[] @relation(references: [a])
@@ -119,15 +115,9 @@ model a {
 // ajlsdkfkjasflk"#;
 
     let mut buf = Vec::new();
-    // replaces \t placeholder with a real tab
-    datamodel::ast::reformat::Reformatter::reformat_to(&input.replace("\\t", "\t"), &mut buf, 2);
-    let _actual = str::from_utf8(&buf).expect("unable to convert to string");
-    // FIXME: the assertion is ignored for now. We just make sure that the reformatting at least does not crash.
-    // FIXME: It's hard to implement this because the reformatting does not operate purely on the AST anymore and goes through dml layer and back.
-    // FIXME: This means that the following information gets lost:
-    // FIXME: 1. The commented field gets simply assigned to the model. It is not known where it was originally placed.
-    // FIXME: 2. The floating comments are not present in the dml representation at all. They get lost.
-    //    assert_eq!(expected, actual);
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
 }
 
 #[test]
@@ -143,18 +133,156 @@ enum Colors {
 }
 "#;
 
-    // moving the comment to the top is not ideal. Just want to capture the current behavior in a test.
-    let expected = r#"// comment
-enum Colors {
+    let expected = r#"enum Colors {
   RED
   BLUE
   GREEN
+
+  // comment
   ORANGE
 }"#;
 
     let mut buf = Vec::new();
-    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2);
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
     let actual = str::from_utf8(&buf).expect("unable to convert to string");
-    println!("{}", actual);
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn reformatting_must_keep_comments_and_blank_lines_in_place() {
+    let input = r#"
+// This is the user.
+model User {
+  id Int @id // the id
+
+  // contact data
+  email String @unique
+  name String?
+
+
+
+  @@map("users") // table name
+}
+
+
+
+enum Role {
+  USER // default
+  ADMIN
+}
+"#;
+
+    let expected = r#"// This is the user.
+model User {
+  id    Int     @id     // the id
+
+  // contact data
+  email String  @unique
+  name  String?
+
+  @@map("users") // table name
+}
+
+enum Role {
+  USER // default
+  ADMIN
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn reformatting_an_invalid_schema_must_work() {
+    let input = r#"
+model Post {
+  id Int @id
+  author   Usr   @relation(fields: [authorId])
+}
+"#;
+
+    let expected = r#"model Post {
+  id     Int @id
+  author Usr @relation(fields: [authorId])
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn reformatting_a_schema_that_does_not_parse_must_return_an_error() {
+    let input = r#"
+model Post {
+  id Int @id
+  title String @default(
+}
+"#;
+
+    let mut buf = Vec::new();
+    let result = datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2);
+
+    assert!(result.is_err());
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn reformatting_a_valid_schema_must_add_back_relation_fields() {
+    let input = r#"
+model User {
+  id Int @id
+}
+
+model Post {
+  id Int @id
+  user User
+}
+"#;
+
+    let expected = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id   Int  @id
+  user User @relation(references: [id])
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_to(&input, &mut buf, 2).unwrap();
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn reformatting_only_the_ast_must_not_add_back_relation_fields() {
+    let input = r#"
+model User {
+  id Int @id
+}
+
+model Post {
+  id Int @id
+  user User
+}
+"#;
+
+    let expected = r#"model User {
+  id Int @id
+}
+
+model Post {
+  id   Int  @id
+  user User
+}"#;
+
+    let mut buf = Vec::new();
+    datamodel::ast::reformat::Reformatter::reformat_ast_to(&input, &mut buf, 2).unwrap();
+    let actual = str::from_utf8(&buf).expect("unable to convert to string");
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,106 @@
+use crate::common::*;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+const DATAMODEL: &str = r#"
+/// A registered user.
+model User {
+  id      Int      @id @default(autoincrement())
+  email   String   @unique
+  name    String?
+  role    Role     @default(USER)
+  score   Float    @default(1.5)
+  tags    String[]
+  address Address?
+  posts   Post[]
+}
+
+model Post {
+  id        Int      @id
+  /// When the post went online.
+  published DateTime?
+  author    User
+}
+
+type Address {
+  street String
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+#[test]
+fn models_are_rendered_as_object_schemas() {
+    let schema = datamodel::json::jsonschema::render_to_json_schema_value(&parse(DATAMODEL));
+
+    assert_eq!(
+        schema["User"],
+        json!({
+            "$schema": "https://json-schema.org/draft/2019-09/schema",
+            "$id": "User.schema.json",
+            "title": "User",
+            "description": "A registered user.",
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "email": { "type": "string" },
+                "name": { "type": ["string", "null"] },
+                "role": { "$ref": "Role.schema.json", "default": "USER" },
+                "score": { "type": "number", "default": 1.5 },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "address": { "anyOf": [{ "$ref": "Address.schema.json" }, { "type": "null" }] },
+                "posts": { "type": "array", "items": { "$ref": "Post.schema.json" } },
+            },
+            "required": ["id", "email", "role", "score", "tags"],
+            "additionalProperties": false,
+        })
+    );
+}
+
+#[test]
+fn field_documentation_and_date_times_are_rendered() {
+    let schema = datamodel::json::jsonschema::render_to_json_schema_value(&parse(DATAMODEL));
+
+    assert_eq!(
+        schema["Post"]["properties"]["published"],
+        json!({
+            "type": ["string", "null"],
+            "format": "date-time",
+            "description": "When the post went online.",
+        })
+    );
+    assert_eq!(schema["Post"]["required"], json!(["id"]));
+}
+
+#[test]
+fn enums_and_composite_types_get_documents_of_their_own() {
+    let schema = datamodel::json::jsonschema::render_to_json_schema_value(&parse(DATAMODEL));
+
+    assert_eq!(
+        schema["Role"],
+        json!({
+            "$schema": "https://json-schema.org/draft/2019-09/schema",
+            "$id": "Role.schema.json",
+            "title": "Role",
+            "type": "string",
+            "enum": ["USER", "ADMIN"],
+        })
+    );
+    assert_eq!(schema["Address"]["required"], json!(["street"]));
+}
+
+#[test]
+fn openapi_components_refer_to_each_other_within_the_document() {
+    let openapi = datamodel::json::jsonschema::render_to_openapi_components_value(&parse(DATAMODEL));
+    let user = &openapi["components"]["schemas"]["User"];
+
+    assert_eq!(user["$schema"], serde_json::Value::Null);
+    assert_eq!(user["$id"], serde_json::Value::Null);
+    assert_eq!(
+        user["properties"]["posts"],
+        json!({ "type": "array", "items": { "$ref": "#/components/schemas/Post" } })
+    );
+}
@@ -0,0 +1,177 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelWarning};
+
+#[test]
+fn legacy_type_syntax_is_accepted_with_a_warning() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id    Int @id
+        names [String]
+        name  String!
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+
+    assert_eq!(
+        warnings,
+        vec![
+            DatamodelWarning::new_legacy_syntax_warning(
+                "To specify a list, please use `Type[]` instead of `[Type]`.",
+                Span::new(162, 170),
+            ),
+            DatamodelWarning::new_legacy_syntax_warning(
+                "Fields are required by default, `!` is no longer required.",
+                Span::new(185, 192),
+            ),
+        ]
+    );
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_field("names")
+        .assert_arity(&datamodel::dml::FieldArity::List);
+    user_model
+        .assert_has_field("name")
+        .assert_arity(&datamodel::dml::FieldArity::Required);
+}
+
+#[test]
+fn embedded_models_are_deprecated() {
+    let dml = r#"
+    model Address {
+        id Int @id
+
+        @@embedded
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+
+    assert_eq!(
+        warnings,
+        vec![DatamodelWarning::new_deprecated_directive_warning(
+            "Use a composite type, declared with the `type` keyword, instead.",
+            "embedded",
+            Span::new(51, 59),
+        )]
+    );
+}
+
+#[test]
+fn relation_fields_without_index_are_flagged() {
+    let dml = r#"
+    model User {
+        id    Int @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        author   User
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+
+    assert_eq!(
+        warnings,
+        vec![DatamodelWarning::new_missing_index_on_relation_field_warning(
+            "Post",
+            "author",
+            Span::new(119, 132),
+        )]
+    );
+}
+
+#[test]
+fn implicit_back_relation_fields_are_flagged() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id Int @id
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+
+    assert_eq!(
+        warnings,
+        vec![DatamodelWarning::new_missing_back_relation_field_warning(
+            "User",
+            "posts",
+            "Post",
+            Span::new(51, 63),
+        )]
+    );
+}
+
+#[test]
+fn one_to_one_relations_without_references_are_flagged() {
+    let dml = r#"
+    model User {
+        id      Int      @id
+        profile Profile?
+    }
+
+    model Profile {
+        id   Int  @id
+        user User
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+
+    assert_eq!(
+        warnings,
+        vec![
+            DatamodelWarning::new_missing_index_on_relation_field_warning("Profile", "user", Span::new(129, 138)),
+            DatamodelWarning::new_ambiguous_one_to_one_relation_warning("Profile", "user", Span::new(129, 138)),
+        ]
+    );
+}
+
+#[test]
+fn float_fields_for_money_are_flagged() {
+    let dml = r#"
+    model Order {
+        id         Int   @id
+        totalPrice Float
+        weight     Float
+    }
+    "#;
+
+    let warnings = parse_warnings(dml);
+
+    assert_eq!(
+        warnings,
+        vec![DatamodelWarning::new_float_for_money_warning(
+            "Order",
+            "totalPrice",
+            Span::new(67, 72),
+        )]
+    );
+}
+
+#[test]
+fn valid_schemas_have_no_warnings() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        name  String
+        price Decimal
+    }
+    "#;
+
+    assert!(parse_warnings(dml).is_empty());
+}
@@ -0,0 +1,2 @@
+pub mod lints;
+pub mod quick_fixes;
@@ -0,0 +1,120 @@
+use datamodel::{error::TextEdit, SourceFile};
+
+/// Applies all fixes for the schema at once.
+fn fix(dml: &str) -> String {
+    let mut files = vec![SourceFile::new("schema.prisma", dml)];
+    let edits: Vec<TextEdit> = datamodel::quick_fixes_for_files(&files, false)
+        .into_iter()
+        .flat_map(|fix| fix.edits)
+        .collect();
+
+    TextEdit::apply_all(&edits, &mut files);
+    files.remove(0).contents
+}
+
+#[test]
+fn legacy_type_syntax_is_replaced() {
+    let dml = "model User {\n  id   Int @id\n  name String!\n  tags [String]\n}";
+
+    assert_eq!(
+        fix(dml),
+        "model User {\n  id   Int @id\n  name String\n  tags String[]\n}"
+    );
+}
+
+#[test]
+fn missing_back_relation_fields_are_added() {
+    let dml = r#"model User {
+  id Int @id
+}
+
+model Post {
+  id     Int  @id
+  author User
+}"#;
+
+    let expected = r#"model User {
+  id Int @id
+  posts Post[]
+}
+
+model Post {
+  id     Int  @id
+  author User
+}"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn named_relations_keep_their_name_on_the_added_field() {
+    let dml = r#"model User {
+  id Int @id
+}
+
+model Post {
+  id     Int  @id
+  author User @relation("Authorship")
+}"#;
+
+    let expected = r#"model User {
+  id Int @id
+  posts Post[] @relation("Authorship")
+}
+
+model Post {
+  id     Int  @id
+  author User @relation("Authorship")
+}"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn references_are_added_to_ambiguous_one_to_one_relations() {
+    let dml = r#"model User {
+  id      Int      @id
+  profile Profile?
+}
+
+model Profile {
+  id   Int  @id
+  user User
+}"#;
+
+    let expected = r#"model User {
+  id      Int      @id
+  profile Profile?
+}
+
+model Profile {
+  id   Int  @id
+  user User @relation(references: [id])
+}"#;
+
+    assert_eq!(fix(dml), expected);
+}
+
+#[test]
+fn models_with_reserved_names_are_renamed_and_mapped() {
+    let dml = "model String {\n  id Int @id\n}";
+
+    assert_eq!(fix(dml), "model StringModel {\n  id Int @id\n  @@map(\"String\")\n}");
+}
+
+#[test]
+fn valid_schemas_have_no_fixes() {
+    let dml = r#"model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id     Int  @id
+  author User
+}"#;
+
+    let files = vec![SourceFile::new("schema.prisma", dml)];
+
+    assert!(datamodel::quick_fixes_for_files(&files, false).is_empty());
+}
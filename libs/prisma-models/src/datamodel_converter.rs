@@ -29,6 +29,7 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
         }
     }
@@ -52,6 +53,25 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<InternalCompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|composite_type| InternalCompositeType {
+                name: composite_type.name.clone(),
+                fields: composite_type
+                    .fields()
+                    .map(|field| InternalCompositeField {
+                        name: field.name.clone(),
+                        type_identifier: field.type_identifier(),
+                        is_required: field.is_required(),
+                        is_list: field.is_list(),
+                        composite_type: field.composite_type(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
@@ -61,6 +81,7 @@ impl<'a> DatamodelConverter<'a> {
                 ModelTemplate {
                     name: model.name.clone(),
                     is_embedded: model.is_embedded,
+                    schema: model.schema.clone(),
                     fields: self.convert_fields(&model),
                     manifestation: model.single_database_name().map(|s| s.to_owned()),
                     id_field_names: model.id_fields.clone(),
@@ -113,6 +134,7 @@ impl<'a> DatamodelConverter<'a> {
                         ),
                         behaviour: field.behaviour(),
                         internal_enum: field.internal_enum(self.datamodel),
+                        composite_type: field.composite_type(),
                     })
                 }
             })
@@ -424,6 +446,7 @@ trait DatamodelFieldExtensions {
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn final_db_name(&self) -> String;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
+    fn composite_type(&self) -> Option<String>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
@@ -433,6 +456,7 @@ impl DatamodelFieldExtensions for dml::Field {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+            dml::FieldType::Composite(_) => TypeIdentifier::Json,
             dml::FieldType::Base(scalar, _) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
@@ -474,6 +498,8 @@ impl DatamodelFieldExtensions for dml::Field {
     fn behaviour(&self) -> Option<FieldBehaviour> {
         if self.is_updated_at {
             Some(FieldBehaviour::UpdatedAt)
+        } else if self.is_read_only() {
+            Some(FieldBehaviour::Computed)
         } else {
             None
         }
@@ -499,6 +525,13 @@ impl DatamodelFieldExtensions for dml::Field {
         }
     }
 
+    fn composite_type(&self) -> Option<String> {
+        match self.field_type {
+            dml::FieldType::Composite(ref name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue {
         InternalEnumValue {
             name: enum_value.name.clone(),
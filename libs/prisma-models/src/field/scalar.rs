@@ -26,6 +26,7 @@ pub struct ScalarFieldTemplate {
     pub is_auto_generated_int_id: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
     pub data_source_field: dml::DataSourceField,
 }
 
@@ -39,6 +40,8 @@ pub struct ScalarField {
     pub is_auto_generated_int_id: bool,
     pub internal_enum: Option<InternalEnum>,
     pub behaviour: Option<FieldBehaviour>,
+    /// The name of the composite type this field embeds, if any. Such fields hold JSON documents.
+    pub composite_type: Option<String>,
 
     #[debug_stub = "#ModelWeakRef#"]
     pub model: ModelWeakRef,
@@ -58,6 +61,7 @@ impl Hash for ScalarField {
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
         self.behaviour.hash(state);
+        self.composite_type.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
     }
@@ -73,6 +77,7 @@ impl PartialEq for ScalarField {
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
             && self.behaviour == other.behaviour
+            && self.composite_type == other.composite_type
             && self.default_value() == other.default_value()
             && self.is_unique == other.is_unique
             && self.model() == other.model()
@@ -83,6 +88,8 @@ impl PartialEq for ScalarField {
 pub enum FieldBehaviour {
     CreatedAt,
     UpdatedAt,
+    /// The value is computed by the database and can't be written.
+    Computed,
     ScalarList { strategy: ScalarListStrategy },
 }
 
@@ -104,6 +111,7 @@ impl ScalarFieldTemplate {
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
             behaviour: self.behaviour,
+            composite_type: self.composite_type,
             model,
             data_source_field: OnceCell::new(),
         };
@@ -163,6 +171,20 @@ impl ScalarField {
         }
     }
 
+    /// Computed fields are excluded from create and update inputs.
+    pub fn is_read_only(&self) -> bool {
+        match self.behaviour {
+            Some(FieldBehaviour::Computed) => true,
+            _ => false,
+        }
+    }
+
+    pub fn internal_composite_type(&self) -> Option<InternalCompositeType> {
+        let name = self.composite_type.as_ref()?;
+
+        self.internal_data_model().find_composite_type(name).cloned()
+    }
+
     pub fn unique(&self) -> bool {
         self.is_unique || self.is_id()
     }
@@ -10,12 +10,14 @@ pub struct InternalDataModelTemplate {
     pub models: Vec<ModelTemplate>,
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     pub version: Option<String>,
 }
 
 #[derive(DebugStub)]
 pub struct InternalDataModel {
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     version: Option<String>,
 
     /// Todo clarify / rename.
@@ -90,12 +92,30 @@ impl InternalEnumValue {
     }
 }
 
+/// A composite type. Fields using it store its values inline, as a JSON document.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeType {
+    pub name: String,
+    pub fields: Vec<InternalCompositeField>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeField {
+    pub name: String,
+    pub type_identifier: TypeIdentifier,
+    pub is_required: bool,
+    pub is_list: bool,
+    /// The name of the composite type this field embeds, if any.
+    pub composite_type: Option<String>,
+}
+
 impl InternalDataModelTemplate {
     pub fn build(self, db_name: String) -> InternalDataModelRef {
         let internal_data_model = Arc::new(InternalDataModel {
             models: OnceCell::new(),
             relations: OnceCell::new(),
             enums: self.enums,
+            composite_types: self.composite_types,
             version: self.version,
             db_name,
             relation_fields: OnceCell::new(),
@@ -137,6 +157,12 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::ModelNotFound { name: name.to_string() })
     }
 
+    pub fn find_composite_type(&self, name: &str) -> Option<&InternalCompositeType> {
+        self.composite_types
+            .iter()
+            .find(|composite_type| composite_type.name == name)
+    }
+
     pub fn find_relation(&self, name: &str) -> crate::Result<RelationWeakRef> {
         self.relations
             .get()
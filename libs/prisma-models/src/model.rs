@@ -12,6 +12,7 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub schema: Option<String>,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
@@ -22,6 +23,7 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    pub schema: Option<String>,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -36,6 +38,7 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            schema: self.schema,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
             manifestation: self.manifestation,
@@ -7,6 +7,11 @@ pub trait AsTable {
 
 impl AsTable for Model {
     fn as_table(&self) -> Table<'static> {
-        (self.internal_data_model().db_name.clone(), self.db_name().to_string()).into()
+        let schema = self
+            .schema
+            .clone()
+            .unwrap_or_else(|| self.internal_data_model().db_name.clone());
+
+        (schema, self.db_name().to_string()).into()
     }
 }
@@ -16,6 +16,9 @@ pub enum SqlSchemaDescriberError {
     /// An unknown error occurred.
     #[fail(display = "unknown")]
     UnknownError,
+    /// The backend can only describe one schema at a time.
+    #[fail(display = "Describing multiple schemas is not supported on this database.")]
+    MultipleSchemasNotSupported,
 }
 
 /// The result type.
@@ -30,6 +33,19 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata>;
     /// Describe a database schema.
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
+    /// Describe the default schema and a list of additional schemas into a single `SqlSchema`.
+    /// Tables and foreign keys pointing outside of `default_schema` carry their schema name.
+    async fn describe_with_schemas(
+        &self,
+        default_schema: &str,
+        additional_schemas: &[String],
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
+        if additional_schemas.is_empty() {
+            self.describe(default_schema).await
+        } else {
+            Err(SqlSchemaDescriberError::MultipleSchemasNotSupported)
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -60,6 +76,13 @@ impl SqlSchema {
         self.tables.iter().find(|x| x.name == name)
     }
 
+    /// Get a table by its schema and name. A `None` schema designates the default schema.
+    pub fn get_table_in_schema(&self, schema: Option<&str>, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|x| x.name == name && x.schema.as_ref().map(String::as_str) == schema)
+    }
+
     /// Get an enum.
     pub fn get_enum(&self, name: &str) -> Option<&Enum> {
         self.enums.iter().find(|x| x.name == name)
@@ -96,6 +119,9 @@ impl SqlSchema {
 pub struct Table {
     /// The table's name.
     pub name: String,
+    /// The schema the table lives in, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// The table's columns.
     pub columns: Vec<Column>,
     /// The table's indices.
@@ -147,6 +173,7 @@ impl Table {
     pub fn is_column_unique(&self, column_name: &str) -> bool {
         self.indices.iter().any(|index| {
             index.tpe == IndexType::Unique
+                && index.is_plain()
                 && index.columns.len() == 1
                 && index.columns.contains(&column_name.to_owned())
         })
@@ -171,6 +198,55 @@ impl IndexType {
     }
 }
 
+/// The sort order of an index column.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    /// Ascending.
+    Asc,
+    /// Descending.
+    Desc,
+}
+
+/// The method of an index, i.e. the data structure backing it.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexMethod {
+    /// B-tree, the default on every database.
+    BTree,
+    /// Hash index.
+    Hash,
+    /// Postgres GiST index.
+    Gist,
+    /// Postgres GIN index.
+    Gin,
+    /// Postgres BRIN index.
+    Brin,
+    /// Postgres SP-GiST index.
+    SpGist,
+    /// MySQL FULLTEXT index.
+    FullText,
+    /// MySQL SPATIAL index.
+    Spatial,
+}
+
+impl IndexMethod {
+    /// Parses an index method as reported by the database. Returns `None` for the default
+    /// B-tree method and for unknown methods.
+    pub fn from_db_name(name: &str) -> Option<IndexMethod> {
+        match name.to_lowercase().as_str() {
+            "hash" => Some(IndexMethod::Hash),
+            "gist" => Some(IndexMethod::Gist),
+            "gin" => Some(IndexMethod::Gin),
+            "brin" => Some(IndexMethod::Brin),
+            "spgist" => Some(IndexMethod::SpGist),
+            "fulltext" => Some(IndexMethod::FullText),
+            "spatial" => Some(IndexMethod::Spatial),
+            _ => None,
+        }
+    }
+}
+
 /// An index of a table.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -181,12 +257,48 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Sort order of each of the columns, in the same order as `columns`. Empty if all columns are
+    /// ascending.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sort_orders: Vec<SortOrder>,
+    /// The SQL expressions indexed by an expression index, in addition to `columns`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expressions: Vec<String>,
+    /// The predicate of a partial index.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
+    /// The index method, when it is not the default B-tree.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<IndexMethod>,
 }
 
 impl Index {
     pub fn is_unique(&self) -> bool {
         self.tpe == IndexType::Unique
     }
+
+    /// The sort order of the column at the given position.
+    pub fn sort_order(&self, column_index: usize) -> SortOrder {
+        self.sort_orders
+            .get(column_index)
+            .cloned()
+            .unwrap_or(SortOrder::Asc)
+    }
+
+    /// Appends a column to the index, keeping `sort_orders` aligned with `columns`.
+    pub fn push_column(&mut self, column: String, sort_order: SortOrder) {
+        if sort_order == SortOrder::Desc || !self.sort_orders.is_empty() {
+            self.sort_orders.resize(self.columns.len(), SortOrder::Asc);
+            self.sort_orders.push(sort_order);
+        }
+
+        self.columns.push(column);
+    }
+
+    /// Whether the index is a plain index over columns, without expressions or predicate.
+    pub fn is_plain(&self) -> bool {
+        self.expressions.is_empty() && self.predicate.is_none()
+    }
 }
 
 /// The primary key of a table.
@@ -217,12 +329,40 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// The generation expression, if the column is a generated column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated: Option<GeneratedColumn>,
 }
 
 impl Column {
     pub fn is_required(&self) -> bool {
         self.tpe.arity == ColumnArity::Required
     }
+
+    /// Generated columns are computed by the database and can't be written to.
+    pub fn is_generated(&self) -> bool {
+        self.generated.is_some()
+    }
+}
+
+/// The definition of a generated (computed) column.
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedColumn {
+    /// The SQL expression computing the column's value.
+    pub expression: String,
+    /// Whether the computed value is stored or computed when read.
+    pub kind: GeneratedColumnKind,
+}
+
+/// How the value of a generated column is materialized.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GeneratedColumnKind {
+    /// The value is computed on write and stored like a regular column.
+    Stored,
+    /// The value is computed when the column is read. Not supported on Postgres.
+    Virtual,
 }
 
 /// The type of a column.
@@ -357,6 +497,9 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The schema of the referenced table, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -369,6 +512,9 @@ pub struct ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
+    /// The schema the enum lives in, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// Possible enum values.
     pub values: Vec<String>,
 }
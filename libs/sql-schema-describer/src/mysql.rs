@@ -144,6 +144,7 @@ impl SqlSchemaDescriber {
         (
             Table {
                 name: name.to_string(),
+                schema: None,
                 columns,
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
@@ -169,6 +170,7 @@ async fn get_all_columns(
                 column_default column_default,
                 is_nullable is_nullable,
                 extra extra,
+                generation_expression generation_expression,
                 table_name table_name
             FROM information_schema.columns
             WHERE table_schema = ?
@@ -290,11 +292,26 @@ async fn get_all_columns(
             },
         };
 
+        // MySQL 8 also reports `DEFAULT_GENERATED` for expression defaults, which are not generated columns.
+        let generated_kind = if extra.contains("stored generated") || extra.contains("persistent generated") {
+            Some(GeneratedColumnKind::Stored)
+        } else if extra.contains("virtual generated") {
+            Some(GeneratedColumnKind::Virtual)
+        } else {
+            None
+        };
+        let generated = generated_kind.and_then(|kind| {
+            col.get("generation_expression")
+                .and_then(|x| x.to_string())
+                .map(|expression| GeneratedColumn { expression, kind })
+        });
+
         let col = Column {
             name,
             tpe,
             default,
             auto_increment,
+            generated,
         };
 
         entry.0.push(col);
@@ -312,14 +329,20 @@ async fn get_all_indexes(
     // We alias all the columns because MySQL column names are case-insensitive in queries, but the
     // information schema column names became upper-case in MySQL 8, causing the code fetching
     // the result values by column name below to fail.
+    //
+    // The `EXPRESSION` column of functional key parts only exists from MySQL 8.0.13 on, so we
+    // select it through `statistics.*` and treat it as optional.
     let sql = "
             SELECT DISTINCT
                 index_name AS index_name,
                 non_unique AS non_unique,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
-                table_name AS table_name
-            FROM INFORMATION_SCHEMA.STATISTICS
+                table_name AS table_name,
+                statistics.collation AS sort_order,
+                statistics.index_type AS index_type,
+                statistics.*
+            FROM INFORMATION_SCHEMA.STATISTICS statistics
             WHERE table_schema = ?
             ORDER BY index_name, seq_in_index
             ";
@@ -348,10 +371,15 @@ async fn get_all_indexes(
             .get("non_unique")
             .and_then(|x| x.as_bool())
             .expect("non_unique");
-        let column_name = row
-            .get("column_name")
+        let column_name = row.get("column_name").and_then(|x| x.to_string());
+        let sort_order = match row.get("sort_order").and_then(|x| x.to_string()) {
+            Some(ref collation) if collation == "D" => SortOrder::Desc,
+            _ => SortOrder::Asc,
+        };
+        let index_type = row
+            .get("index_type")
             .and_then(|x| x.to_string())
-            .expect("column_name");
+            .expect("index_type");
 
         // Multi-column indices will return more than one row (with different column_name values).
         // We cannot assume that one row corresponds to one index.
@@ -360,6 +388,7 @@ async fn get_all_indexes(
 
         let is_pk = index_name.to_lowercase() == "primary";
         if is_pk {
+            let column_name = column_name.expect("primary key column_name");
             debug!("Column '{}' is part of the primary key", column_name);
             match primary_key {
                 Some(pk) => {
@@ -384,22 +413,28 @@ async fn get_all_indexes(
                 }
             };
         } else {
-            if indexes_map.contains_key(&index_name) {
-                indexes_map.get_mut(&index_name).map(|index: &mut Index| {
-                    index.columns.push(column_name);
-                });
-            } else {
-                indexes_map.insert(
-                    index_name.clone(),
-                    Index {
-                        name: index_name,
-                        columns: vec![column_name],
-                        tpe: match is_unique {
-                            true => IndexType::Unique,
-                            false => IndexType::Normal,
-                        },
-                    },
-                );
+            let index = indexes_map.entry(index_name.clone()).or_insert_with(|| Index {
+                name: index_name,
+                columns: Vec::new(),
+                tpe: match is_unique {
+                    true => IndexType::Unique,
+                    false => IndexType::Normal,
+                },
+                sort_orders: Vec::new(),
+                expressions: Vec::new(),
+                predicate: None,
+                method: IndexMethod::from_db_name(&index_type),
+            });
+
+            match column_name {
+                Some(column_name) => index.push_column(column_name, sort_order),
+                None => {
+                    let expression = row
+                        .get("EXPRESSION")
+                        .and_then(|x| x.to_string())
+                        .expect("functional key part expression");
+                    index.expressions.push(expression);
+                }
             }
         }
     }
@@ -509,6 +544,7 @@ async fn get_foreign_keys(
                     constraint_name: Some(constraint_name.clone()),
                     columns: vec![column],
                     referenced_table,
+                    referenced_schema: None,
                     referenced_columns: vec![referenced_column],
                     on_delete_action,
                 };
@@ -590,6 +626,7 @@ fn get_column_type_and_enum(
             tpe,
             Some(Enum {
                 name: name.clone(),
+                schema: None,
                 values: extract_enum_values(&full_data_type),
             }),
         ),
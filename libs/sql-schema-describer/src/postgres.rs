@@ -30,18 +30,64 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     }
 
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
+        self.describe_schema(schema, schema).await
+    }
+
+    async fn describe_with_schemas(
+        &self,
+        default_schema: &str,
+        additional_schemas: &[String],
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
+        let mut sql_schema = self.describe_schema(default_schema, default_schema).await?;
+
+        for schema in additional_schemas
+            .iter()
+            .filter(|schema| schema.as_str() != default_schema)
+        {
+            let described = self.describe_schema(schema, default_schema).await?;
+
+            sql_schema.tables.extend(described.tables);
+            sql_schema.sequences.extend(described.sequences);
+            sql_schema.enums.extend(described.enums);
+        }
+
+        Ok(sql_schema)
+    }
+}
+
+impl SqlSchemaDescriber {
+    /// Constructor.
+    pub fn new(conn: Arc<dyn Queryable + Send + Sync + 'static>) -> SqlSchemaDescriber {
+        SqlSchemaDescriber { conn }
+    }
+
+    /// Describe `schema`. Tables and enums outside of `default_schema` are qualified with their
+    /// schema name.
+    async fn describe_schema(
+        &self,
+        schema: &str,
+        default_schema: &str,
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
         let sequences = self.get_sequences(schema).await?;
-        let enums = self.get_enums(schema).await?;
+        let mut enums = self.get_enums(schema).await?;
         let mut columns = self.get_columns(schema, &enums).await;
-        let mut foreign_keys = self.get_foreign_keys(schema).await;
+        let mut foreign_keys = self.get_foreign_keys(schema, default_schema).await;
         let mut indexes = self.get_indices(schema, &sequences).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
+        let qualified_schema = qualify_schema(schema, default_schema);
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            let mut table =
+                self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes);
+            table.schema = qualified_schema.clone();
+            tables.push(table);
+        }
+
+        for r#enum in enums.iter_mut() {
+            r#enum.schema = qualified_schema.clone();
         }
 
         Ok(SqlSchema {
@@ -50,13 +96,6 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables,
         })
     }
-}
-
-impl SqlSchemaDescriber {
-    /// Constructor.
-    pub fn new(conn: Arc<dyn Queryable + Send + Sync + 'static>) -> SqlSchemaDescriber {
-        SqlSchemaDescriber { conn }
-    }
 
     async fn get_databases(&self) -> Vec<String> {
         debug!("Getting databases");
@@ -137,6 +176,7 @@ impl SqlSchemaDescriber {
         let columns = columns.remove(name).expect("could not get columns");
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices,
@@ -156,6 +196,8 @@ impl SqlSchemaDescriber {
                 column_default,
                 is_nullable,
                 is_identity,
+                is_generated,
+                generation_expression,
                 data_type
             FROM information_schema.columns
             WHERE table_schema = $1
@@ -307,11 +349,24 @@ impl SqlSchemaDescriber {
                     _ => false,
                 };
 
+            // Postgres only supports stored generated columns.
+            let generated = match col.get("is_generated").and_then(|x| x.to_string()) {
+                Some(ref is_generated) if is_generated.eq_ignore_ascii_case("always") => col
+                    .get("generation_expression")
+                    .and_then(|x| x.to_string())
+                    .map(|expression| GeneratedColumn {
+                        expression,
+                        kind: GeneratedColumnKind::Stored,
+                    }),
+                _ => None,
+            };
+
             let col = Column {
                 name: col_name,
                 tpe,
                 default,
                 auto_increment: is_auto_increment,
+                generated,
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -323,13 +378,18 @@ impl SqlSchemaDescriber {
     }
 
     /// Returns a map from table name to foreign keys.
-    async fn get_foreign_keys(&self, schema: &str) -> HashMap<String, Vec<ForeignKey>> {
+    async fn get_foreign_keys(
+        &self,
+        schema: &str,
+        default_schema: &str,
+    ) -> HashMap<String, Vec<ForeignKey>> {
         // The `generate_subscripts` in the inner select is needed because the optimizer is free to reorganize the unnested rows if not explicitly ordered.
         let sql = r#"
             SELECT
                 con.oid as "con_id",
                 att2.attname as "child_column",
                 cl.relname as "parent_table",
+                parent_ns.nspname as "parent_schema",
                 att.attname as "parent_column",
                 con.confdeltype,
                 conname as constraint_name,
@@ -360,6 +420,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id, con.colidx"#;
@@ -388,6 +450,10 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .expect("get parent_schema");
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -422,6 +488,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: qualify_schema(&referenced_schema, default_schema),
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                     };
@@ -462,15 +529,16 @@ impl SqlSchemaDescriber {
         SELECT
             indexInfos.relname as name,
             columnInfos.attname AS column_name,
+            pg_get_indexdef(rawIndex.indexrelid, rawIndex.indkeyidx + 1, true) AS expression,
             rawIndex.indisunique AS is_unique,
             rawIndex.indisprimary AS is_primary_key,
+            rawIndex.indoption[rawIndex.indkeyidx] & 1 = 1 AS is_descending,
+            pg_get_expr(rawIndex.indpred, rawIndex.indrelid, true) AS predicate,
+            accessMethod.amname AS index_method,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
-            -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
-            pg_class tableInfos,
-            pg_class indexInfos,
             -- pg_index stores indices: https://www.postgresql.org/docs/current/catalog-pg-index.html
             (
                 SELECT
@@ -478,31 +546,29 @@ impl SqlSchemaDescriber {
                     indexrelid,
                     indisunique,
                     indisprimary,
+                    indpred,
                     pg_index.indkey AS indkey,
+                    pg_index.indoption AS indoption,
                     generate_subscripts(pg_index.indkey, 1) AS indkeyidx
                 FROM pg_index
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
-                ORDER BY indrelid, indexrelid, indkeyidx
-            ) rawIndex,
-            -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
-            pg_attribute columnInfos,
+            ) rawIndex
+            -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
+            JOIN pg_class tableInfos ON tableInfos.oid = rawIndex.indrelid
+            JOIN pg_class indexInfos ON indexInfos.oid = rawIndex.indexrelid
+            -- pg_am stores the index methods (btree, gin, gist...)
+            JOIN pg_am accessMethod ON accessMethod.oid = indexInfos.relam
             -- pg_namespace stores info about the schema
-            pg_namespace schemaInfo
+            JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+            -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
+            -- expression index entries have no column, hence the left join
+            LEFT JOIN pg_attribute columnInfos ON columnInfos.attrelid = tableInfos.oid
+                AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
         WHERE
-            -- find table info for index
-            tableInfos.oid = rawIndex.indrelid
-            -- find index info
-            AND indexInfos.oid = rawIndex.indexrelid
-            -- find table columns
-            AND columnInfos.attrelid = tableInfos.oid
-            AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
             -- we only consider ordinary tables
-            AND tableInfos.relkind = 'r'
+            tableInfos.relkind = 'r'
             -- we only consider stuff out of one specific schema
-            AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
-        ORDER BY rawIndex.indkeyidx
+        ORDER BY rawIndex.indexrelid, rawIndex.indkeyidx
         "#;
         debug!("Getting indices: {}", sql);
         let rows = self
@@ -515,14 +581,19 @@ impl SqlSchemaDescriber {
             debug!("Got index: {:?}", index);
             let IndexRow {
                 column_name,
+                expression,
+                is_descending,
                 is_primary_key,
                 is_unique,
                 name,
+                predicate,
+                index_method,
                 sequence_name,
                 table_name,
             } = quaint::serde::from_row::<IndexRow>(index).unwrap();
 
             if is_primary_key {
+                let column_name = column_name.expect("primary key column name");
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) = indexes_map
                     .entry(table_name)
                     .or_insert_with(|| (Vec::new(), None));
@@ -558,17 +629,37 @@ impl SqlSchemaDescriber {
                     .entry(table_name)
                     .or_insert_with(|| (Vec::new(), None));
 
-                if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
-                    existing_index.columns.push(column_name);
+                let position = match entry.0.iter().position(|idx| idx.name == name) {
+                    Some(position) => position,
+                    None => {
+                        entry.0.push(Index {
+                            name: name,
+                            columns: Vec::new(),
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
+                            },
+                            sort_orders: Vec::new(),
+                            expressions: Vec::new(),
+                            predicate,
+                            method: IndexMethod::from_db_name(&index_method),
+                        });
+                        entry.0.len() - 1
+                    }
+                };
+                let index = &mut entry.0[position];
+
+                let sort_order = if is_descending {
+                    SortOrder::Desc
                 } else {
-                    entry.0.push(Index {
-                        name: name,
-                        columns: vec![column_name],
-                        tpe: match is_unique {
-                            true => IndexType::Unique,
-                            false => IndexType::Normal,
-                        },
-                    })
+                    SortOrder::Asc
+                };
+
+                match column_name {
+                    Some(column_name) => index.push_column(column_name, sort_order),
+                    None => index
+                        .expressions
+                        .push(expression.expect("index expression")),
                 }
             }
         }
@@ -633,7 +724,11 @@ impl SqlSchemaDescriber {
 
         let mut enums: Vec<Enum> = enum_values
             .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
+            .map(|(k, v)| Enum {
+                name: k,
+                schema: None,
+                values: v,
+            })
             .collect();
 
         enums.sort_by(|a, b| Ord::cmp(&a.name, &b.name));
@@ -646,9 +741,13 @@ impl SqlSchemaDescriber {
 #[derive(Deserialize)]
 struct IndexRow {
     name: String,
-    column_name: String,
+    column_name: Option<String>,
+    expression: Option<String>,
     is_unique: bool,
     is_primary_key: bool,
+    is_descending: bool,
+    predicate: Option<String>,
+    index_method: String,
     table_name: String,
     sequence_name: Option<String>,
 }
@@ -714,6 +813,15 @@ fn get_column_type<'a>(
     }
 }
 
+/// Tables in the default schema are left unqualified.
+fn qualify_schema(schema: &str, default_schema: &str) -> Option<String> {
+    if schema == default_schema {
+        None
+    } else {
+        Some(schema.to_owned())
+    }
+}
+
 static RE_SEQ: Lazy<Regex> =
     Lazy::new(|| Regex::new("^(?:.+\\.)?\"?([^.\"]+)\"?").expect("compile regex"));
 
@@ -115,6 +115,7 @@ impl SqlSchemaDescriber {
         let indices = self.get_indices(schema, name).await;
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             indices,
             primary_key,
@@ -123,12 +124,37 @@ impl SqlSchemaDescriber {
     }
 
     async fn get_columns(&self, schema: &str, table: &str) -> (Vec<Column>, Option<PrimaryKey>) {
-        let sql = format!(r#"PRAGMA "{}".table_info ("{}")"#, schema, table);
+        // `table_xinfo` also lists generated columns, through its `hidden` column: 2 is a virtual and
+        // 3 a stored generated column. 1 marks the hidden columns of virtual tables.
+        let sql = format!(r#"PRAGMA "{}".table_xinfo ("{}")"#, schema, table);
         debug!("describing table columns, query: '{}'", sql);
-        let result_set = self.conn.query_raw(&sql, &[]).await.unwrap();
+        let rows: Vec<_> = self.conn.query_raw(&sql, &[]).await.unwrap().into_iter().collect();
+        let has_generated_columns = rows
+            .iter()
+            .any(|row| row.get("hidden").and_then(|x| x.as_i64()).unwrap_or(0) > 1);
+
+        // Generation expressions are only available in the SQL the table was created with.
+        let table_sql = if has_generated_columns {
+            let sql = format!(
+                r#"SELECT sql FROM "{}".sqlite_master WHERE type = 'table' AND name = ?;"#,
+                schema
+            );
+
+            self.conn
+                .query_raw(&sql, &[table.into()])
+                .await
+                .expect("querying for table sql")
+                .into_iter()
+                .next()
+                .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+        } else {
+            None
+        };
+
         let mut pk_cols: HashMap<i64, String> = HashMap::new();
-        let mut cols: Vec<Column> = result_set
+        let mut cols: Vec<Column> = rows
             .into_iter()
+            .filter(|row| row.get("hidden").and_then(|x| x.as_i64()).unwrap_or(0) != 1)
             .map(|row| {
                 debug!("Got column row {:?}", row);
                 let is_required = row
@@ -216,12 +242,28 @@ impl SqlSchemaDescriber {
                     Some(_) => None,
                 };
 
+                let name = row.get("name").and_then(|x| x.to_string()).expect("name");
+                let generated = match row.get("hidden").and_then(|x| x.as_i64()) {
+                    Some(hidden) if hidden > 1 => table_sql
+                        .as_ref()
+                        .and_then(|table_sql| parse_generated_column_expression(table_sql, &name))
+                        .map(|expression| GeneratedColumn {
+                            expression,
+                            kind: match hidden {
+                                3 => GeneratedColumnKind::Stored,
+                                _ => GeneratedColumnKind::Virtual,
+                            },
+                        }),
+                    _ => None,
+                };
+
                 let pk_col = row.get("pk").and_then(|x| x.as_i64()).expect("primary key");
                 let col = Column {
-                    name: row.get("name").and_then(|x| x.to_string()).expect("name"),
+                    name,
                     tpe,
                     default,
                     auto_increment: false,
+                    generated,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -367,6 +409,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
 
@@ -421,9 +464,32 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             };
 
-            let sql = format!(r#"PRAGMA "{}".index_info("{}");"#, schema, name);
+            // Expressions and predicates are only available in the SQL the index was created with.
+            let sql = format!(
+                r#"SELECT sql FROM "{}".sqlite_master WHERE type = 'index' AND name = ?;"#,
+                schema
+            );
+            let index_sql = self
+                .conn
+                .query_raw(&sql, &[name.as_str().into()])
+                .await
+                .expect("querying for index sql")
+                .into_iter()
+                .next()
+                .and_then(|row| row.get("sql").and_then(|x| x.to_string()));
+            let (key_parts, predicate) = index_sql
+                .as_ref()
+                .map(|index_sql| parse_index_sql(index_sql))
+                .unwrap_or_default();
+            index.predicate = predicate;
+
+            let sql = format!(r#"PRAGMA "{}".index_xinfo("{}");"#, schema, name);
             debug!("describing table index '{}', SQL: '{}'", name, sql);
             let result_set = self
                 .conn
@@ -431,19 +497,31 @@ impl SqlSchemaDescriber {
                 .await
                 .expect("querying for index info");
             debug!("Got index description results: {:?}", result_set);
-            for row in result_set.into_iter() {
+
+            let mut rows: Vec<_> = result_set
+                .into_iter()
+                // Auxiliary columns (the rowid) are not part of the index key.
+                .filter(|row| row.get("key").and_then(|x| x.as_bool()).unwrap_or(false))
+                .collect();
+            rows.sort_by_key(|row| row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno"));
+
+            for row in rows {
                 let pos = row
                     .get("seqno")
                     .and_then(|x| x.as_i64())
                     .expect("get seqno") as usize;
-                let col_name = row
-                    .get("name")
-                    .and_then(|x| x.to_string())
-                    .expect("get name");
-                if index.columns.len() <= pos {
-                    index.columns.resize(pos + 1, "".to_string());
+                let sort_order = match row.get("desc").and_then(|x| x.as_bool()) {
+                    Some(true) => SortOrder::Desc,
+                    _ => SortOrder::Asc,
+                };
+
+                match row.get("name").and_then(|x| x.to_string()) {
+                    Some(col_name) => index.push_column(col_name, sort_order),
+                    None => {
+                        let expression = key_parts.get(pos).cloned().unwrap_or_default();
+                        index.expressions.push(expression);
+                    }
                 }
-                index.columns[pos] = col_name;
             }
 
             indices.push(index)
@@ -453,6 +531,140 @@ impl SqlSchemaDescriber {
     }
 }
 
+/// Splits the SQL of a `CREATE INDEX` statement into its key parts (without sort order) and
+/// its `WHERE` predicate.
+fn parse_index_sql(sql: &str) -> (Vec<String>, Option<String>) {
+    let open = match sql.find('(') {
+        Some(open) => open,
+        None => return (Vec::new(), None),
+    };
+
+    let mut depth = 0;
+    let mut end = sql.len();
+    let mut key_parts = Vec::new();
+    let mut current = String::new();
+
+    for (i, c) in sql[open..].char_indices() {
+        match c {
+            '(' => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    end = open + i + 1;
+                    break;
+                }
+            }
+            ',' if depth == 1 => {
+                key_parts.push(strip_sort_order(&current));
+                current.clear();
+                continue;
+            }
+            _ => (),
+        }
+
+        current.push(c);
+    }
+
+    key_parts.push(strip_sort_order(&current));
+
+    let rest = sql[end..].trim();
+    let predicate = if rest.len() > 5 && rest[..5].eq_ignore_ascii_case("where") {
+        Some(rest[5..].trim().to_owned())
+    } else {
+        None
+    };
+
+    (key_parts, predicate)
+}
+
+/// Finds the `AS (<expr>)` clause of a generated column in a `CREATE TABLE` statement.
+fn parse_generated_column_expression(table_sql: &str, column_name: &str) -> Option<String> {
+    let open = table_sql.find('(')?;
+    let mut depth = 0;
+    let mut definitions = Vec::new();
+    let mut current = String::new();
+
+    for c in table_sql[open..].chars() {
+        match c {
+            '(' => {
+                depth += 1;
+                if depth == 1 {
+                    continue;
+                }
+            }
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            ',' if depth == 1 => {
+                definitions.push(std::mem::replace(&mut current, String::new()));
+                continue;
+            }
+            _ => (),
+        }
+
+        current.push(c);
+    }
+
+    definitions.push(current);
+
+    let definition = definitions.into_iter().find(|definition| {
+        let definition = definition.trim_start();
+        let name = match definition.chars().next() {
+            Some(quote @ '"') | Some(quote @ '`') => definition[1..].split(quote).next(),
+            Some('[') => definition[1..].split(']').next(),
+            _ => definition.split_whitespace().next(),
+        };
+
+        name.map(|name| name.eq_ignore_ascii_case(column_name))
+            .unwrap_or(false)
+    })?;
+
+    let upper = definition.to_ascii_uppercase();
+    let as_keyword = upper
+        .match_indices("AS")
+        .map(|(i, _)| i)
+        .filter(|i| !upper[..*i].ends_with(|c: char| c.is_alphanumeric() || c == '_'))
+        .find(|i| upper[i + 2..].trim_start().starts_with('('))?;
+    let expression_start = as_keyword + 2 + upper[as_keyword + 2..].find('(')?;
+
+    let mut depth = 0;
+    for (i, c) in definition[expression_start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(definition[expression_start + 1..expression_start + i].trim().to_owned());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+fn strip_sort_order(key_part: &str) -> String {
+    let key_part = key_part.trim();
+    let upper = key_part.to_uppercase();
+
+    if upper.ends_with(" DESC") {
+        key_part[..key_part.len() - 5].trim().to_owned()
+    } else if upper.ends_with(" ASC") {
+        key_part[..key_part.len() - 4].trim().to_owned()
+    } else {
+        key_part.to_owned()
+    }
+}
+
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
     let tpe_lower = tpe.to_lowercase();
 
@@ -466,6 +678,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "serial" => ColumnTypeFamily::Int,
         "boolean" => ColumnTypeFamily::Boolean,
         "text" => ColumnTypeFamily::String,
+        "json" => ColumnTypeFamily::Json,
         s if s.contains("char") => ColumnTypeFamily::String,
         s if s.contains("numeric") => ColumnTypeFamily::Float,
         "date" => ColumnTypeFamily::DateTime,
@@ -52,6 +52,7 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "column2".to_string(),
@@ -62,6 +63,7 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
     ];
 
@@ -101,6 +103,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        generated: None,
     }];
 
     let on_delete_action = match api.sql_family() {
@@ -112,6 +115,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            sort_orders: vec![],
+            expressions: vec![],
+            predicate: None,
+            method: None,
         }]
     } else {
         vec![]
@@ -121,6 +128,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -133,6 +141,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
             }],
         }
@@ -183,6 +192,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "city_name".to_string(),
@@ -193,6 +203,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
     ];
 
@@ -201,6 +212,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            sort_orders: vec![],
+            expressions: vec![],
+            predicate: None,
+            method: None,
         }]
     } else {
         vec![]
@@ -215,6 +230,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -228,6 +244,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
             },],
         }
@@ -254,6 +271,7 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        generated: None,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
@@ -298,6 +316,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "name".to_string(),
@@ -308,6 +327,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -316,6 +336,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -354,6 +375,7 @@ async fn indices_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "id".to_string(),
@@ -365,6 +387,7 @@ async fn indices_must_work(api: &TestApi) {
 
             default,
             auto_increment: true,
+            generated: None,
         },
     ];
     let pk_sequence = match api.sql_family() {
@@ -379,11 +402,16 @@ async fn indices_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "count".to_string(),
                 columns: vec!["count".to_string()],
                 tpe: IndexType::Normal,
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
@@ -418,6 +446,7 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "uniq2".to_string(),
@@ -429,18 +458,27 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
     ];
     let mut expected_indices = vec![Index {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expressions: vec![],
+        predicate: None,
+        method: None,
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            sort_orders: vec![],
+            expressions: vec![],
+            predicate: None,
+            method: None,
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -448,18 +486,27 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            sort_orders: vec![],
+            expressions: vec![],
+            predicate: None,
+            method: None,
         }),
     };
     assert_eq!(
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indices,
             primary_key: None,
@@ -499,11 +546,13 @@ async fn defaults_must_work(api: &TestApi) {
 
         default: Some(default),
         auto_increment: false,
+        generated: None,
     }];
     assert_eq!(
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: None,
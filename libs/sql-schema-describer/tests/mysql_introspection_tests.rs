@@ -70,6 +70,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            generated: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -81,6 +82,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -92,6 +94,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -102,6 +105,7 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -113,6 +117,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -124,6 +129,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -135,6 +141,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -146,6 +153,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -157,6 +165,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -168,6 +177,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -179,6 +189,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -190,6 +201,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -201,6 +213,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -212,6 +225,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -223,6 +237,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some(DefaultValue::NOW),
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -234,6 +249,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -245,6 +261,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -256,6 +273,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -267,6 +285,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -278,6 +297,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -289,6 +309,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -300,6 +321,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -311,6 +333,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -322,6 +345,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -333,6 +357,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -344,6 +369,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -355,6 +381,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -366,6 +393,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -377,6 +405,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -388,6 +417,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -399,6 +429,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -410,6 +441,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -421,6 +453,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -432,6 +465,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -443,6 +477,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -454,6 +489,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -465,6 +501,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -476,6 +513,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -487,6 +525,7 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -495,6 +534,7 @@ async fn all_mysql_column_types_must_work() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -533,6 +573,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".to_string(),
@@ -543,6 +584,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -553,6 +595,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -563,6 +606,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -573,6 +617,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -584,6 +629,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 },
             ],
             indices: vec![
@@ -591,21 +637,37 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal
+                    sort_orders: vec![],
+                    expressions: vec![],
+                    predicate: None,
+                    method: None,
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal
+                    sort_orders: vec![],
+                    expressions: vec![],
+                    predicate: None,
+                    method: None,
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal
+                    sort_orders: vec![],
+                    expressions: vec![],
+                    predicate: None,
+                    method: None,
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal
+                    sort_orders: vec![],
+                    expressions: vec![],
+                    predicate: None,
+                    method: None,
                 }
             ],
             primary_key: Some(PrimaryKey {
@@ -618,6 +680,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -625,6 +688,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -632,6 +696,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -639,6 +704,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
@@ -669,6 +735,10 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique
+            sort_orders: vec![],
+            expressions: vec![],
+            predicate: None,
+            method: None,
         }]
     );
 }
@@ -707,6 +777,10 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique
+            sort_orders: vec![],
+            expressions: vec![],
+            predicate: None,
+            method: None,
         }]
     );
 }
@@ -744,6 +818,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -775,8 +850,43 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
     );
 }
+
+#[tokio::test]
+async fn mysql_generated_columns_must_be_inferred() {
+    let db_name = "mysql_generated_columns_must_be_inferred";
+
+    let sql = format!(
+        "CREATE TABLE `{db_name}`.`Order` (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL,
+            quantity INTEGER NOT NULL,
+            total INTEGER AS (price * quantity) VIRTUAL,
+            total_stored INTEGER AS (price * quantity) STORED
+        )",
+        db_name = db_name
+    );
+
+    let inspector = get_mysql_describer_for_schema(&sql, db_name).await;
+    let result = inspector.describe(db_name).await.expect("describing");
+    let table = result.table_bang("Order");
+
+    assert!(!table.column_bang("price").is_generated());
+
+    let total = table.column_bang("total").generated.as_ref().expect("total is generated");
+    assert_eq!(total.kind, GeneratedColumnKind::Virtual);
+    assert!(total.expression.contains("price"));
+
+    let total_stored = table
+        .column_bang("total_stored")
+        .generated
+        .as_ref()
+        .expect("total_stored is generated");
+    assert_eq!(total_stored.kind, GeneratedColumnKind::Stored);
+    assert!(total_stored.expression.contains("quantity"));
+}
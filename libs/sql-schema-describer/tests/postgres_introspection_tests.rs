@@ -81,6 +81,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -91,6 +92,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -101,6 +103,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -111,6 +114,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -121,6 +125,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -131,6 +136,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -141,6 +147,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -151,6 +158,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "binary_col".into(),
@@ -161,6 +169,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -171,6 +180,7 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -182,6 +192,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "double_col".into(),
@@ -193,6 +204,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "float_col".into(),
@@ -204,6 +216,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "int_col".into(),
@@ -215,6 +228,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "primary_col".into(),
@@ -229,6 +243,7 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            generated: None,
         },
         Column {
             name: "string1_col".into(),
@@ -240,6 +255,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "string2_col".into(),
@@ -251,6 +267,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -262,6 +279,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -276,6 +294,7 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            generated: None,
         },
         Column {
             name: "bit_col".into(),
@@ -287,6 +306,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -298,6 +318,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "box_col".into(),
@@ -309,6 +330,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "char_col".into(),
@@ -320,6 +342,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "circle_col".into(),
@@ -331,6 +354,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "interval_col".into(),
@@ -342,6 +366,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "line_col".into(),
@@ -353,6 +378,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -364,6 +390,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -375,6 +402,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "path_col".into(),
@@ -386,6 +414,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -397,6 +426,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -408,6 +438,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -419,6 +450,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -433,6 +465,7 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            generated: None,
         },
         Column {
             name: "serial_col".into(),
@@ -447,6 +480,7 @@ async fn all_postgres_column_types_must_work() {
                 SCHEMA
             ))),
             auto_increment: true,
+            generated: None,
         },
         Column {
             name: "time_col".into(),
@@ -458,6 +492,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "time_with_zone_col".into(),
@@ -469,6 +504,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -480,6 +516,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "timestamp_with_zone_col".into(),
@@ -491,6 +528,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -502,6 +540,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -513,6 +552,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "txid_col".into(),
@@ -524,6 +564,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "json_col".into(),
@@ -535,6 +576,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -546,6 +588,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -557,6 +600,7 @@ async fn all_postgres_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -565,11 +609,16 @@ async fn all_postgres_column_types_must_work() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                sort_orders: vec![],
+                expressions: vec![],
+                predicate: None,
+                method: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -612,6 +661,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".into(),
@@ -622,6 +672,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -632,6 +683,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -642,6 +694,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -652,6 +705,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -662,6 +716,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "id".into(),
@@ -673,6 +728,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -686,6 +742,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -693,6 +750,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -700,6 +758,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -707,6 +766,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -714,6 +774,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
@@ -740,6 +801,7 @@ async fn postgres_enums_must_work() {
         got_enum,
         &Enum {
             name: "mood".into(),
+            schema: None,
             values,
         }
     );
@@ -796,3 +858,99 @@ async fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order() {
     assert!(!index.tpe.is_unique());
     assert_eq!(&index.columns, &["age", "name"]);
 }
+
+#[tokio::test]
+async fn postgres_additional_schemas_must_be_described_with_qualified_names() {
+    let billing_schema = format!("{}_billing", SCHEMA);
+    let sql = format!(
+        r##"
+            DROP SCHEMA IF EXISTS "{billing_schema}" CASCADE;
+            CREATE SCHEMA "{billing_schema}";
+
+            CREATE TABLE "{schema_name}"."User" (
+                id SERIAL PRIMARY KEY
+            );
+
+            CREATE TABLE "{billing_schema}"."Invoice" (
+                id SERIAL PRIMARY KEY,
+                user_id INTEGER NOT NULL REFERENCES "{schema_name}"."User"("id")
+            );
+        "##,
+        schema_name = SCHEMA,
+        billing_schema = billing_schema,
+    );
+
+    let inspector = get_postgres_describer(&sql, "postgres_additional_schemas").await;
+    let schema = inspector
+        .describe_with_schemas(SCHEMA, &[billing_schema.clone()])
+        .await
+        .expect("describing");
+
+    let user_table = schema.get_table_in_schema(None, "User").expect("User table");
+    assert_eq!(user_table.schema, None);
+
+    let invoice_table = schema
+        .get_table_in_schema(Some(&billing_schema), "Invoice")
+        .expect("Invoice table");
+    assert_eq!(invoice_table.schema, Some(billing_schema.clone()));
+    assert_eq!(
+        invoice_table.foreign_keys,
+        vec![ForeignKey {
+            constraint_name: Some("Invoice_user_id_fkey".into()),
+            columns: vec!["user_id".into()],
+            referenced_table: "User".into(),
+            referenced_schema: None,
+            referenced_columns: vec!["id".into()],
+            on_delete_action: ForeignKeyAction::NoAction,
+        }],
+    );
+}
+
+#[tokio::test]
+async fn postgres_index_sort_orders_predicates_expressions_and_methods_must_be_inferred() {
+    let sql = format!(
+        r##"
+            CREATE TABLE "{schema_name}"."User" (
+                id SERIAL PRIMARY KEY,
+                name TEXT NOT NULL,
+                age INTEGER NOT NULL,
+                tags TEXT[] NOT NULL
+            );
+
+            CREATE INDEX "name_age_idx" ON "{schema_name}"."User" (name, age DESC) WHERE age > 18;
+            CREATE INDEX "lower_name_idx" ON "{schema_name}"."User" (lower(name));
+            CREATE INDEX "tags_idx" ON "{schema_name}"."User" USING GIN (tags);
+        "##,
+        schema_name = SCHEMA
+    );
+
+    let inspector = get_postgres_describer(&sql, "postgres_index_metadata").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+    let table = schema.table_bang("User");
+
+    let index = table
+        .indices
+        .iter()
+        .find(|index| index.name == "name_age_idx")
+        .expect("name_age_idx");
+    assert_eq!(index.columns, &["name", "age"]);
+    assert_eq!(index.sort_orders, &[SortOrder::Asc, SortOrder::Desc]);
+    assert_eq!(index.predicate.as_ref().map(String::as_str), Some("age > 18"));
+    assert_eq!(index.method, None);
+
+    let index = table
+        .indices
+        .iter()
+        .find(|index| index.name == "lower_name_idx")
+        .expect("lower_name_idx");
+    assert!(index.columns.is_empty());
+    assert_eq!(index.expressions, &["lower(name)"]);
+
+    let index = table
+        .indices
+        .iter()
+        .find(|index| index.name == "tags_idx")
+        .expect("tags_idx");
+    assert_eq!(index.columns, &["tags"]);
+    assert_eq!(index.method, Some(IndexMethod::Gin));
+}
@@ -22,6 +22,7 @@ fn database_schema_is_serializable() {
         tables: vec![
             Table {
                 name: "table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "column1".to_string(),
@@ -32,6 +33,7 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -42,6 +44,7 @@ fn database_schema_is_serializable() {
                         },
                         default: Some(DefaultValue::VALUE("default value".to_string())),
                         auto_increment: false,
+                        generated: None,
                     },
                     Column {
                         name: "column3".to_string(),
@@ -52,12 +55,17 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    sort_orders: vec![],
+                    expressions: vec![],
+                    predicate: None,
+                    method: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
@@ -67,12 +75,14 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
             },
             Table {
                 name: "table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
@@ -82,6 +92,7 @@ fn database_schema_is_serializable() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -93,6 +104,7 @@ fn database_schema_is_serializable() {
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![Sequence {
@@ -119,6 +131,7 @@ fn database_schema_without_primary_key_is_serializable() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![Column {
                 name: "column1".to_string(),
                 tpe: ColumnType {
@@ -128,6 +141,7 @@ fn database_schema_without_primary_key_is_serializable() {
                 },
                 default: None,
                 auto_increment: false,
+                generated: None,
             }],
             indices: vec![],
             primary_key: None,
@@ -177,11 +191,13 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         },
         default: None,
         auto_increment: false,
+        generated: None,
     })
     .collect();
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
@@ -222,11 +238,13 @@ fn database_schema_is_serializable_for_every_column_arity() {
         },
         default: None,
         auto_increment: false,
+        generated: None,
     })
     .collect();
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
@@ -254,6 +272,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "column1".to_string(),
@@ -264,6 +283,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    generated: None,
                 },
                 Column {
                     name: "column2".to_string(),
@@ -274,6 +294,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    generated: None,
                 },
                 Column {
                     name: "column3".to_string(),
@@ -284,6 +305,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    generated: None,
                 },
                 Column {
                     name: "column4".to_string(),
@@ -294,6 +316,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    generated: None,
                 },
                 Column {
                     name: "column5".to_string(),
@@ -304,6 +327,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     },
                     auto_increment: false,
                     default: None,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -313,6 +337,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -320,6 +345,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -327,6 +353,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -334,6 +361,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
@@ -341,6 +369,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
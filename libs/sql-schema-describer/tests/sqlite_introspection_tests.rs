@@ -33,6 +33,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -43,6 +44,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -53,6 +55,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -63,6 +66,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -73,6 +77,7 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            generated: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -81,6 +86,7 @@ async fn sqlite_column_types_must_work() {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -116,6 +122,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".to_string(),
@@ -126,6 +133,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -136,6 +144,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -146,6 +155,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -156,6 +166,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -166,6 +177,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
                 },
                 Column {
                     name: "id".to_string(),
@@ -176,6 +188,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
                 },
             ],
             indices: vec![],
@@ -189,6 +202,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -196,6 +210,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -203,6 +218,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -210,6 +226,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -217,6 +234,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
@@ -257,3 +275,32 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
         }
     );
 }
+
+#[tokio::test]
+async fn sqlite_index_sort_orders_predicates_and_expressions_must_be_inferred() {
+    let sql = format!(
+        r#"CREATE TABLE "{schema}"."User" (id INTEGER PRIMARY KEY, name TEXT NOT NULL, age INTEGER NOT NULL);CREATE INDEX "{schema}"."name_age_idx" ON "User" (name, age DESC) WHERE age > 18;CREATE INDEX "{schema}"."lower_name_idx" ON "User" (lower(name))"#,
+        schema = SCHEMA
+    );
+
+    let inspector = get_sqlite_describer(&sql, "sqlite_index_metadata_must_be_inferred").await;
+    let result = inspector.describe(SCHEMA).await.expect("describing");
+    let table = result.get_table("User").expect("couldn't get User table");
+
+    let index = table
+        .indices
+        .iter()
+        .find(|index| index.name == "name_age_idx")
+        .expect("name_age_idx");
+    assert_eq!(index.columns, &["name", "age"]);
+    assert_eq!(index.sort_orders, &[SortOrder::Asc, SortOrder::Desc]);
+    assert_eq!(index.predicate.as_ref().map(String::as_str), Some("age > 18"));
+
+    let index = table
+        .indices
+        .iter()
+        .find(|index| index.name == "lower_name_idx")
+        .expect("lower_name_idx");
+    assert!(index.columns.is_empty());
+    assert_eq!(index.expressions, &["lower(name)"]);
+}
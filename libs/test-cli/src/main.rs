@@ -16,6 +16,10 @@ enum Command {
         /// Whether to ignore warnings from the migration engine regarding data loss. Default: false.
         #[structopt(long)]
         force: Option<bool>,
+        /// Print the SQL and the warnings for migrating the database in its current state to the
+        /// schema, without resetting or migrating it.
+        #[structopt(long)]
+        dry_run: bool,
     },
     /// Introspect a database
     Introspect {
@@ -76,6 +80,7 @@ async fn main() -> anyhow::Result<()> {
             file_path,
             force,
             stdin,
+            dry_run,
         } => {
             let datamodel_string: String = match (file_path, stdin) {
                 (Some(path), false) => {
@@ -96,16 +101,25 @@ async fn main() -> anyhow::Result<()> {
 
             let api = migration_core::migration_api(&datamodel_string).await?;
 
-            let migration_id = "test-cli-migration".to_owned();
+            // Dry runs don't reset the database, so they must not reuse the id of a migration
+            // that may already be applied.
+            let migration_id = if dry_run {
+                "test-cli-dry-run".to_owned()
+            } else {
+                "test-cli-migration".to_owned()
+            };
 
             let infer_input = migration_core::InferMigrationStepsInput {
                 assume_applied_migrations: Some(Vec::new()),
                 assume_to_be_applied: Some(Vec::new()),
                 datamodel: datamodel_string.clone(),
                 migration_id: migration_id.clone(),
+                confirmed_renames: None,
             };
 
-            api.reset(&serde_json::Value::Null).await?;
+            if !dry_run {
+                api.reset(&serde_json::Value::Null).await?;
+            }
 
             let result = api.infer_migration_steps(&infer_input).await?;
 
@@ -113,16 +127,36 @@ async fn main() -> anyhow::Result<()> {
                 force,
                 migration_id,
                 steps: result.datamodel_steps,
+                dry_run: Some(dry_run),
             };
 
             let result = api.apply_migration(&apply_input).await?;
+
+            if dry_run {
+                for step in result.database_steps.as_array().into_iter().flatten() {
+                    if let Some(raw) = step.get("raw").and_then(|raw| raw.as_str()) {
+                        println!("{};\n", raw);
+                    }
+                }
+
+                for unexecutable in &result.unexecutable_migrations {
+                    eprintln!(
+                        "{} - {}",
+                        "❌ UNEXECUTABLE MIGRATION ❌ ".bold().red(),
+                        unexecutable.description
+                    );
+                }
+            }
+
             let warnings: Vec<_> = result
                 .warnings
                 .into_iter()
                 .map(|warning| warning.description)
                 .collect();
 
-            if warnings.is_empty() {
+            if warnings.is_empty() && dry_run {
+                eprintln!("{}", "✔️  dry run without warning".bold().green());
+            } else if warnings.is_empty() {
                 eprintln!("{}", "✔️  migrated without warning".bold().green());
             } else {
                 for warning in warnings {
@@ -27,6 +27,15 @@ struct MigrationRollback {
     pub database_error: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3003",
+    message = "Timed out after ${timeout_seconds} seconds waiting for the migration lock on the database. Another migration engine is probably migrating the same database. Please try again once it is done."
+)]
+pub struct MigrationLockTimeout {
+    pub timeout_seconds: u64,
+}
+
 // Tests
 
 #[cfg(test)]
@@ -26,7 +26,11 @@ impl Cli {
     pub(crate) async fn run(&self) -> ! {
         match std::panic::AssertUnwindSafe(self.run_inner()).catch_unwind().await {
             Ok(Ok(msg)) => {
-                tracing::info!("{}", msg);
+                match self.command {
+                    // The script is the output of the command, not a log message.
+                    CliCommand::RenderDdl { .. } => print!("{}", msg),
+                    _ => tracing::info!("{}", msg),
+                }
                 std::process::exit(0);
             }
             Ok(Err(error)) => {
@@ -56,6 +60,7 @@ impl Cli {
                 create_conn(&self.datasource, false).await?;
                 Ok("Connection successful".to_owned())
             }
+            CliCommand::RenderDdl { ref datamodel } => render_ddl(&self.datasource, datamodel),
         }
     }
 }
@@ -66,6 +71,13 @@ enum CliCommand {
     CreateDatabase,
     /// Does the database connection string work?
     CanConnectToDatabase,
+    /// Print the SQL script creating the schema for a datamodel in an empty database. The
+    /// connection string only determines the SQL family and schema name, it is not connected to.
+    RenderDdl {
+        /// Path to the datamodel, or to a directory of `.prisma` files forming the datamodel
+        #[structopt(long, short = "m")]
+        datamodel: String,
+    },
 }
 
 async fn create_conn(datasource: &str, admin_mode: bool) -> CoreResult<(String, Box<SqlMigrationConnector>)> {
@@ -146,6 +158,16 @@ async fn create_database(datasource: &str) -> Result<String, CliError> {
     }
 }
 
+fn render_ddl(datasource: &str, datamodel_location: &str) -> Result<String, CliError> {
+    let files = datamodel::SourceFile::load_all(std::path::Path::new(datamodel_location))
+        .context("Reading the datamodel.")
+        .map_err(CliError::Other)?;
+    let datamodel = datamodel::parse_datamodel_from_files_and_ignore_env_errors(&files)
+        .map_err(|errors| CliError::Other(anyhow::anyhow!(errors.to_pretty_string_in_files(&files))))?;
+
+    sql_migration_connector::render_ddl_script(&datamodel, datasource).map_err(CliError::from)
+}
+
 /// Try to connect as an admin to a postgres database. We try to pick a default database from which
 /// we can create another database.
 async fn create_postgres_admin_conn(mut url: Url) -> CoreResult<SqlMigrationConnector> {
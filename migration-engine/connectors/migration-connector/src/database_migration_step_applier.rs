@@ -12,6 +12,28 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
     /// Returns true to signal to the caller that there are more steps to unapply.
     async fn unapply_step(&self, database_migration: &T, step: usize) -> ConnectorResult<bool>;
 
+    /// The number of steps `apply_step` goes through for the migration.
+    fn step_count(&self, database_migration: &T) -> usize;
+
+    /// The number of steps `unapply_step` goes through for the migration.
+    fn rollback_step_count(&self, database_migration: &T) -> usize;
+
+    /// Whether the database can run all the steps of the migration in one transaction. The
+    /// migration is then applied and recorded atomically, with the transaction methods below.
+    fn supports_transactional_ddl(&self, database_migration: &T) -> bool;
+
+    async fn begin_transaction(&self) -> ConnectorResult<()>;
+
+    async fn commit_transaction(&self) -> ConnectorResult<()>;
+
+    async fn rollback_transaction(&self) -> ConnectorResult<()>;
+
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<serde_json::Value>>;
+
+    /// Render the steps `unapply_step` goes through for the CLI, like `render_steps_pretty`.
+    fn render_rollback_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<serde_json::Value>>;
+
+    /// Render the whole migration as one script, as written to a migrations directory.
+    fn render_script(&self, database_migration: &T) -> ConnectorResult<String>;
 }
@@ -0,0 +1,128 @@
+use serde::Serialize;
+
+/// How the database differs from the schema the applied migrations lead to, e.g. because it was
+/// altered by hand. Added items are in the database but not in the migrations, removed items are
+/// in the migrations but not in the database.
+#[derive(Debug, Default, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseDrift {
+    pub added_tables: Vec<String>,
+    pub removed_tables: Vec<String>,
+    pub changed_tables: Vec<TableDrift>,
+    pub added_enums: Vec<String>,
+    pub removed_enums: Vec<String>,
+    pub changed_enums: Vec<EnumDrift>,
+}
+
+impl DatabaseDrift {
+    pub fn is_empty(&self) -> bool {
+        self.added_tables.is_empty()
+            && self.removed_tables.is_empty()
+            && self.changed_tables.is_empty()
+            && self.added_enums.is_empty()
+            && self.removed_enums.is_empty()
+            && self.changed_enums.is_empty()
+    }
+
+    /// A one line description of the drift, for error messages.
+    pub fn summary(&self) -> String {
+        let changed_tables: Vec<String> = self.changed_tables.iter().map(|table| table.table.clone()).collect();
+        let changed_enums: Vec<String> = self.changed_enums.iter().map(|r#enum| r#enum.r#enum.clone()).collect();
+        let parts = [
+            ("Added tables", &self.added_tables),
+            ("Removed tables", &self.removed_tables),
+            ("Changed tables", &changed_tables),
+            ("Added enums", &self.added_enums),
+            ("Removed enums", &self.removed_enums),
+            ("Changed enums", &changed_enums),
+        ];
+
+        parts
+            .iter()
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(label, names)| format!("{}: {}.", label, names.join(", ")))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The drift in a table that is both in the database and in the migrations.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TableDrift {
+    pub table: String,
+    pub added_columns: Vec<String>,
+    pub removed_columns: Vec<String>,
+    pub changed_columns: Vec<ColumnDrift>,
+    pub added_indexes: Vec<IndexDrift>,
+    pub removed_indexes: Vec<IndexDrift>,
+    pub added_foreign_keys: Vec<ForeignKeyDrift>,
+    pub removed_foreign_keys: Vec<ForeignKeyDrift>,
+}
+
+impl TableDrift {
+    pub fn new(table: String) -> TableDrift {
+        TableDrift {
+            table,
+            added_columns: Vec::new(),
+            removed_columns: Vec::new(),
+            changed_columns: Vec::new(),
+            added_indexes: Vec::new(),
+            removed_indexes: Vec::new(),
+            added_foreign_keys: Vec::new(),
+            removed_foreign_keys: Vec::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added_columns.is_empty()
+            && self.removed_columns.is_empty()
+            && self.changed_columns.is_empty()
+            && self.added_indexes.is_empty()
+            && self.removed_indexes.is_empty()
+            && self.added_foreign_keys.is_empty()
+            && self.removed_foreign_keys.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnDrift {
+    pub column: String,
+    pub changes: Vec<ColumnDriftKind>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum ColumnDriftKind {
+    Type,
+    Arity,
+    Default,
+    Generated,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexDrift {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub unique: bool,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ForeignKeyDrift {
+    pub constraint_name: Option<String>,
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
+/// The drift in an enum that is both in the database and in the migrations.
+#[derive(Debug, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumDrift {
+    pub r#enum: String,
+    pub added_values: Vec<String>,
+    pub removed_values: Vec<String>,
+}
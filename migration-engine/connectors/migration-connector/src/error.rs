@@ -1,7 +1,7 @@
 use anyhow::format_err;
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 use thiserror::Error;
-use user_facing_errors::KnownError;
+use user_facing_errors::{migration_engine::MigrationLockTimeout, KnownError};
 
 #[derive(Debug, Error)]
 #[error("{}", kind)]
@@ -30,6 +30,16 @@ impl ConnectorError {
             )),
         }
     }
+
+    pub fn migration_lock_timeout(timeout: Duration) -> Self {
+        // Rounded up, so that timeouts under a second are not reported as zero seconds.
+        let timeout_seconds = ((timeout.as_millis() + 999) / 1000) as u64;
+
+        ConnectorError {
+            user_facing_error: KnownError::new(MigrationLockTimeout { timeout_seconds }).ok(),
+            kind: ErrorKind::MigrationLockTimeout { timeout_seconds },
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -76,4 +86,7 @@ pub enum ErrorKind {
 
     #[error("Unique constraint violation.")]
     UniqueConstraintViolation { field_name: String },
+
+    #[error("Timed out after {} seconds waiting for the migration lock.", timeout_seconds)]
+    MigrationLockTimeout { timeout_seconds: u64 },
 }
@@ -3,21 +3,24 @@
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_changes_checker;
+mod drift;
 mod error;
 mod migration_applier;
 mod migration_persistence;
 
+pub mod migrations_directory;
 pub mod steps;
 
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_changes_checker::*;
+pub use drift::*;
 pub use error::*;
 pub use migration_applier::*;
 pub use migration_persistence::*;
 pub use steps::MigrationStep;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 /// The top-level trait for connectors. This is the abstraction the migration engine core relies on to
 /// interface with different database backends.
@@ -42,6 +45,33 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Run a migration script, as rendered by
+    /// [render_script](trait.DatabaseMigrationStepApplier.html#tymethod.render_script) and possibly
+    /// edited by hand since.
+    async fn apply_script(&self, script: &str) -> ConnectorResult<()>;
+
+    /// The database migration to record for a script applied with `apply_script`: the statements
+    /// of the script, so it can be replayed, and the schema of the database after it was applied.
+    async fn script_migration(&self, script: &str) -> ConnectorResult<Self::DatabaseMigration>;
+
+    /// Compare the database with the schema it would have after migrating to the datamodel, to find
+    /// changes made outside of migrations.
+    async fn detect_drift(&self, expected: &datamodel::Datamodel) -> ConnectorResult<DatabaseDrift>;
+
+    /// Compare the database with the schema it had right after the database migration was
+    /// applied, for migrations that don't record a datamodel.
+    async fn detect_drift_since(&self, database_migration: &Self::DatabaseMigration) -> ConnectorResult<DatabaseDrift>;
+
+    /// Calculate a datamodel from the current state of the database, the way introspection would.
+    async fn introspect(&self) -> ConnectorResult<datamodel::Datamodel>;
+
+    /// Take the migration lock on the database, so that only one migration engine changes it at a
+    /// time. Fails with a migration lock timeout error if the lock is not acquired within `timeout`.
+    async fn acquire_lock(&self, timeout: Duration) -> ConnectorResult<()>;
+
+    /// Release the lock taken with `acquire_lock`.
+    async fn release_lock(&self) -> ConnectorResult<()>;
+
     /// See [MigrationPersistence](trait.MigrationPersistence.html).
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a>;
 
@@ -11,6 +11,9 @@ where
     async fn unapply(&self, migration: &Migration, database_migration: &T) -> ConnectorResult<()>;
 }
 
+/// Applies migrations in one transaction when the database supports transactional DDL, so a
+/// failed migration leaves the database and the migration record untouched. Otherwise, the steps
+/// are applied one by one and the migration record tracks how many of them were applied.
 pub struct MigrationApplierImpl<'a, T>
 where
     T: Send + Sync + 'static,
@@ -30,21 +33,8 @@ where
         migration_updates.status = MigrationStatus::MigrationInProgress;
         self.migration_persistence.update(&migration_updates).await?;
 
-        let apply_result = self.go_forward(&mut migration_updates, database_migration).await;
-
-        match apply_result {
-            Ok(()) => {
-                migration_updates.mark_as_finished();
-                self.migration_persistence.update(&migration_updates).await?;
-                Ok(())
-            }
-            Err(err) => {
-                migration_updates.status = MigrationStatus::MigrationFailure;
-                migration_updates.errors = vec![format!("{:?}", err)];
-                self.migration_persistence.update(&migration_updates).await?;
-                Err(err)
-            }
-        }
+        self.run(migration_updates, database_migration, Direction::Forward)
+            .await
     }
 
     async fn unapply(&self, migration: &Migration, database_migration: &T) -> ConnectorResult<()> {
@@ -53,20 +43,37 @@ where
         migration_updates.status = MigrationStatus::RollingBack;
         self.migration_persistence.update(&migration_updates).await?;
 
-        let unapply_result = self.go_backward(&mut migration_updates, database_migration).await;
+        self.run(migration_updates, database_migration, Direction::Backward)
+            .await
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Forward,
+    Backward,
+}
 
-        match unapply_result {
-            Ok(()) => {
-                migration_updates.status = MigrationStatus::RollbackSuccess;
-                self.migration_persistence.update(&migration_updates).await?;
-                Ok(())
-            }
-            Err(err) => {
-                migration_updates.status = MigrationStatus::RollbackFailure;
-                migration_updates.errors = vec![format!("{:?}", err)];
-                self.migration_persistence.update(&migration_updates).await?;
-                Err(err)
-            }
+impl Direction {
+    fn mark_as_succeeded(self, migration_updates: &mut MigrationUpdateParams) {
+        match self {
+            Direction::Forward => migration_updates.mark_as_finished(),
+            Direction::Backward => migration_updates.status = MigrationStatus::RollbackSuccess,
+        }
+    }
+
+    fn failure_status(self) -> MigrationStatus {
+        match self {
+            Direction::Forward => MigrationStatus::MigrationFailure,
+            Direction::Backward => MigrationStatus::RollbackFailure,
+        }
+    }
+
+    /// The counter of steps run in this direction on the migration record.
+    fn progress(self, migration_updates: &mut MigrationUpdateParams) -> &mut usize {
+        match self {
+            Direction::Forward => &mut migration_updates.applied,
+            Direction::Backward => &mut migration_updates.rolled_back,
         }
     }
 }
@@ -75,31 +82,105 @@ impl<'a, T> MigrationApplierImpl<'a, T>
 where
     T: Send + Sync,
 {
-    async fn go_forward(
+    async fn run(
         &self,
-        migration_updates: &mut MigrationUpdateParams,
+        migration_updates: MigrationUpdateParams,
         database_migration: &T,
+        direction: Direction,
     ) -> ConnectorResult<()> {
-        let mut step = 0;
-        while self.step_applier.apply_step(&database_migration, step).await? {
-            step += 1;
-            migration_updates.applied += 1;
-            self.migration_persistence.update(&migration_updates).await?;
+        if self.step_applier.supports_transactional_ddl(database_migration) {
+            self.run_in_transaction(migration_updates, database_migration, direction)
+                .await
+        } else {
+            self.run_step_by_step(migration_updates, database_migration, direction)
+                .await
         }
-        Ok(())
     }
 
-    async fn go_backward(
+    /// Runs all the steps and records the outcome in one transaction.
+    async fn run_in_transaction(
         &self,
-        migration_updates: &mut MigrationUpdateParams,
+        migration_updates: MigrationUpdateParams,
         database_migration: &T,
+        direction: Direction,
     ) -> ConnectorResult<()> {
-        let mut step = 0;
-        while self.step_applier.unapply_step(&database_migration, step).await? {
-            step += 1;
-            migration_updates.rolled_back += 1;
+        let step_count = self.step_count(database_migration, direction);
+        let mut finished_updates = migration_updates.clone();
+
+        direction.mark_as_succeeded(&mut finished_updates);
+        *direction.progress(&mut finished_updates) = step_count;
+
+        self.step_applier.begin_transaction().await?;
+
+        let result: ConnectorResult<()> = async {
+            for step in 0..step_count {
+                self.run_step(database_migration, step, direction).await?;
+            }
+
+            self.migration_persistence.update(&finished_updates).await?;
+            self.step_applier.commit_transaction().await
+        }
+        .await;
+
+        let err = match result {
+            Ok(()) => return Ok(()),
+            Err(err) => err,
+        };
+
+        if let Err(rollback_err) = self.step_applier.rollback_transaction().await {
+            tracing::warn!("Rolling back the migration transaction failed: {}", rollback_err);
+        }
+
+        // Nothing was applied, the record is still the one from before the transaction.
+        let mut failure_updates = migration_updates;
+        failure_updates.status = direction.failure_status();
+        failure_updates.errors = vec![format!("{:?}", err)];
+        self.migration_persistence.update(&failure_updates).await?;
+
+        Err(err)
+    }
+
+    /// Runs the steps one by one, recording the progress after each of them.
+    async fn run_step_by_step(
+        &self,
+        mut migration_updates: MigrationUpdateParams,
+        database_migration: &T,
+        direction: Direction,
+    ) -> ConnectorResult<()> {
+        let step_count = self.step_count(database_migration, direction);
+
+        for step in 0..step_count {
+            if let Err(err) = self.run_step(database_migration, step, direction).await {
+                migration_updates.status = direction.failure_status();
+                migration_updates.errors = vec![format!("Step {} of {} failed: {:?}", step + 1, step_count, err)];
+                self.migration_persistence.update(&migration_updates).await?;
+
+                return Err(err);
+            }
+
+            *direction.progress(&mut migration_updates) += 1;
             self.migration_persistence.update(&migration_updates).await?;
         }
+
+        direction.mark_as_succeeded(&mut migration_updates);
+        self.migration_persistence.update(&migration_updates).await?;
+
+        Ok(())
+    }
+
+    fn step_count(&self, database_migration: &T, direction: Direction) -> usize {
+        match direction {
+            Direction::Forward => self.step_applier.step_count(database_migration),
+            Direction::Backward => self.step_applier.rollback_step_count(database_migration),
+        }
+    }
+
+    async fn run_step(&self, database_migration: &T, step: usize, direction: Direction) -> ConnectorResult<()> {
+        match direction {
+            Direction::Forward => self.step_applier.apply_step(database_migration, step).await?,
+            Direction::Backward => self.step_applier.unapply_step(database_migration, step).await?,
+        };
+
         Ok(())
     }
 }
@@ -55,6 +55,10 @@ pub trait MigrationPersistence: Send + Sync {
     /// into the database.
     async fn update(&self, params: &MigrationUpdateParams) -> Result<(), ConnectorError>;
 
+    /// Replace the given migrations with a single migration record. The record takes the place of
+    /// the last of the squashed migrations in the history.
+    async fn squash(&self, squashed: &[Migration], migration: Migration) -> Result<Migration, ConnectorError>;
+
     /// Returns whether the migration with the provided migration id has already been successfully applied.
     ///
     /// The default impl will load all migrations and scan for the provided migration id. Implementors are encouraged to implement this more efficiently.
@@ -83,6 +87,8 @@ pub struct Migration {
     pub errors: Vec<String>,
     pub started_at: DateTime<Utc>,
     pub finished_at: Option<DateTime<Utc>>,
+    /// The checksum of the migration script, for migrations applied from a migrations directory.
+    pub checksum: Option<String>,
 }
 
 impl Migration {
@@ -106,6 +112,8 @@ pub struct MigrationUpdateParams {
     pub rolled_back: usize,
     pub errors: Vec<String>,
     pub finished_at: Option<DateTime<Utc>>,
+    /// Replaces the recorded database migration, when set.
+    pub database_migration: Option<serde_json::Value>,
 }
 
 impl MigrationUpdateParams {
@@ -133,6 +141,7 @@ impl Migration {
             errors: Vec::new(),
             started_at: Self::timestamp_without_nanos(),
             finished_at: None,
+            checksum: None,
         }
     }
 
@@ -146,6 +155,7 @@ impl Migration {
             rolled_back: self.rolled_back,
             errors: self.errors.clone(),
             finished_at: self.finished_at.clone(),
+            database_migration: None,
         }
     }
 
@@ -263,6 +273,10 @@ impl MigrationPersistence for EmptyMigrationPersistence {
         unimplemented!("Not allowed on a EmptyMigrationPersistence")
     }
 
+    async fn squash(&self, _squashed: &[Migration], _migration: Migration) -> Result<Migration, ConnectorError> {
+        unimplemented!("Not allowed on a EmptyMigrationPersistence")
+    }
+
     async fn current_datamodel_ast(&self) -> Result<datamodel::ast::SchemaAst, ConnectorError> {
         Ok(datamodel::ast::SchemaAst { tops: Vec::new() })
     }
@@ -0,0 +1,139 @@
+//! A migrations directory holds one folder per migration, named after the time the migration was
+//! created and its name, e.g. `20200415121032_add_users`. Each folder contains the migration
+//! script, which can be edited by hand before it is applied.
+
+use chrono::{Duration, NaiveDateTime, Timelike, Utc};
+use sha2::{Digest, Sha256};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The name of the migration script in a migration folder.
+pub const MIGRATION_SCRIPT_FILENAME: &str = "migration.sql";
+
+/// The format of the timestamp the migration folder names start with.
+const TIMESTAMP_FORMAT: &str = "%Y%m%d%H%M%S";
+
+/// Creates the folder for a new migration, named after the current time and the migration name.
+pub fn create_migration_directory(
+    migrations_directory_path: &Path,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
+    let mut timestamp = Utc::now().naive_utc().with_nanosecond(0).expect("Invalid timestamp.");
+
+    // Migrations are applied in the order of their folder names, so a migration created in the
+    // same second as the previous one must still come after it.
+    let latest_timestamp = list_migrations(migrations_directory_path)?
+        .last()
+        .and_then(|migration| migration.migration_name().get(..14))
+        .and_then(|prefix| NaiveDateTime::parse_from_str(prefix, TIMESTAMP_FORMAT).ok());
+
+    if let Some(latest_timestamp) = latest_timestamp {
+        if latest_timestamp >= timestamp {
+            timestamp = latest_timestamp + Duration::seconds(1);
+        }
+    }
+
+    let folder_name = format!(
+        "{}_{}",
+        timestamp.format(TIMESTAMP_FORMAT),
+        sanitize_migration_name(migration_name)
+    );
+    let path = migrations_directory_path.join(&folder_name);
+
+    if path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("The migration folder {} already exists.", path.display()),
+        ));
+    }
+
+    fs::create_dir_all(&path)?;
+
+    Ok(MigrationDirectory {
+        name: folder_name,
+        path,
+    })
+}
+
+/// Lists the migration folders in a migrations directory, in the order they have to be applied.
+/// A directory that does not exist yet has no migrations.
+pub fn list_migrations(migrations_directory_path: &Path) -> io::Result<Vec<MigrationDirectory>> {
+    let entries = match fs::read_dir(migrations_directory_path) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut migrations = Vec::new();
+
+    for entry in entries {
+        let entry = entry?;
+
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name().into_string().map_err(|name| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("The migration folder name {:?} is not valid UTF-8.", name),
+            )
+        })?;
+
+        migrations.push(MigrationDirectory {
+            name,
+            path: entry.path(),
+        });
+    }
+
+    migrations.sort_by(|a, b| a.migration_name().cmp(b.migration_name()));
+
+    Ok(migrations)
+}
+
+/// The checksum of a migration script, recorded when the migration is applied. Line endings are
+/// normalized, so a checkout on another platform does not count as an edit.
+pub fn script_checksum(script: &str) -> String {
+    let normalized = script.replace("\r\n", "\n");
+
+    format!("{:x}", Sha256::digest(normalized.as_bytes()))
+}
+
+fn sanitize_migration_name(migration_name: &str) -> String {
+    migration_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect()
+}
+
+/// A migration folder in a migrations directory.
+#[derive(Debug, Clone)]
+pub struct MigrationDirectory {
+    name: String,
+    path: PathBuf,
+}
+
+impl MigrationDirectory {
+    /// The name of the folder, which identifies the migration.
+    pub fn migration_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn script_path(&self) -> PathBuf {
+        self.path.join(MIGRATION_SCRIPT_FILENAME)
+    }
+
+    pub fn read_migration_script(&self) -> io::Result<String> {
+        fs::read_to_string(self.script_path())
+    }
+
+    pub fn write_migration_script(&self, script: &str) -> io::Result<()> {
+        fs::write(self.script_path(), script)
+    }
+}
@@ -25,7 +25,7 @@ pub(crate) trait Component {
         Ok(self
             .connector()
             .database_describer
-            .describe(&self.schema_name())
+            .describe_with_schemas(&self.schema_name(), &self.connector().schemas)
             .await?)
     }
 
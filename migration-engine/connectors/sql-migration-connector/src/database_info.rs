@@ -20,6 +20,14 @@ impl DatabaseInfo {
         })
     }
 
+    /// Information about a database that is not connected to. Nothing is known about the server.
+    pub(crate) fn offline(connection_info: ConnectionInfo) -> Self {
+        DatabaseInfo {
+            connection_info,
+            database_version: None,
+        }
+    }
+
     pub(crate) fn is_mariadb(&self) -> bool {
         self.connection_info.sql_family() == SqlFamily::Mysql
             && self
@@ -29,6 +37,25 @@ impl DatabaseInfo {
                 .unwrap_or(false)
     }
 
+    /// MySQL only builds descending indexes from 8.0 on, and MariaDB not at all.
+    pub(crate) fn supports_descending_indexes(&self) -> bool {
+        if self.connection_info.sql_family() != SqlFamily::Mysql {
+            return true;
+        }
+
+        match &self.database_version {
+            Some(version) if !version.contains("MariaDB") => version
+                .split('.')
+                .next()
+                .and_then(|major| major.parse::<u32>().ok())
+                .map(|major| major >= 8)
+                .unwrap_or(false),
+            Some(_) => false,
+            // Nothing is known about the server when working offline.
+            None => true,
+        }
+    }
+
     pub(crate) fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
@@ -0,0 +1,76 @@
+use crate::{
+    sql_database_migration_inferrer::infer_database_migration_steps_and_fix, sql_database_step_applier::render_script,
+    sql_renderer::SqlRenderer, sql_schema_calculator::SqlSchemaCalculator, DatabaseInfo, SqlError, SqlResult,
+};
+use datamodel::Datamodel;
+use migration_connector::{ConnectorError, ConnectorResult};
+use quaint::prelude::{ConnectionInfo, SqlFamily};
+use sql_schema_describer::SqlSchema;
+
+/// Renders the SQL script that creates the database schema for a datamodel in an empty database,
+/// without connecting to a database.
+///
+/// The connection string only determines the SQL family and the schema name the tables are
+/// created in. The script is the same for the same datamodel and connection string.
+pub fn render_ddl_script(datamodel: &Datamodel, database_str: &str) -> ConnectorResult<String> {
+    let connection_info =
+        ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
+    let database_info = DatabaseInfo::offline(connection_info.clone());
+
+    render_ddl_script_impl(datamodel, &database_info).map_err(|err| err.into_connector_error(&connection_info))
+}
+
+fn render_ddl_script_impl(datamodel: &Datamodel, database_info: &DatabaseInfo) -> SqlResult<String> {
+    let sql_family = database_info.sql_family();
+    let schema_name = database_info.connection_info().schema_name();
+    let empty_schema = SqlSchema::empty();
+    let expected_schema = SqlSchemaCalculator::calculate(datamodel, database_info)?;
+    let (_, steps) = infer_database_migration_steps_and_fix(&empty_schema, &expected_schema, schema_name, sql_family)?;
+    let renderer = SqlRenderer::for_family(&sql_family);
+
+    let mut script = String::new();
+
+    // The migration engine creates the schemas when it initializes a database, before migrating.
+    match sql_family {
+        SqlFamily::Postgres => {
+            let mut schema_names = vec![schema_name];
+
+            for other_schema in expected_schema
+                .tables
+                .iter()
+                .filter_map(|table| table.schema.as_ref())
+                .chain(expected_schema.enums.iter().filter_map(|r#enum| r#enum.schema.as_ref()))
+            {
+                if !schema_names.contains(&other_schema.as_str()) {
+                    schema_names.push(other_schema.as_str());
+                }
+            }
+
+            for schema_name in schema_names {
+                script.push_str(&format!("CREATE SCHEMA IF NOT EXISTS \"{}\";\n", schema_name));
+            }
+        }
+        SqlFamily::Mysql => script.push_str(&format!(
+            "CREATE SCHEMA IF NOT EXISTS `{}` DEFAULT CHARACTER SET latin1;\n",
+            schema_name
+        )),
+        SqlFamily::Sqlite => (),
+    }
+
+    let statements = render_script(
+        &steps,
+        renderer.as_ref(),
+        database_info,
+        &empty_schema,
+        &expected_schema,
+    )
+    .map_err(SqlError::Generic)?;
+
+    if !script.is_empty() && !statements.is_empty() {
+        script.push('\n');
+    }
+
+    script.push_str(&statements);
+
+    Ok(script)
+}
@@ -1,16 +1,20 @@
 mod component;
 mod database_info;
+mod ddl_script;
 mod error;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
 mod sql_migration;
+mod sql_migration_lock;
 mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
 mod sql_schema_helpers;
+mod sql_script;
 
+pub use ddl_script::render_ddl_script;
 pub use error::*;
 pub use sql_migration::*;
 
@@ -26,7 +30,9 @@ use sql_database_migration_inferrer::*;
 use sql_database_step_applier::*;
 use sql_destructive_changes_checker::*;
 use sql_migration_persistence::*;
-use sql_schema_describer::SqlSchemaDescriberBackend;
+use sql_schema_calculator::SqlSchemaCalculator;
+use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
+use sql_schema_differ::SqlSchemaDiffer;
 use std::{fs, path::PathBuf, sync::Arc, time::Duration};
 use tracing::debug;
 
@@ -34,6 +40,8 @@ const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct SqlMigrationConnector {
     pub schema_name: String,
+    /// Additional schemas managed by the connector, besides `schema_name`.
+    pub schemas: Vec<String>,
     pub database: Arc<dyn Queryable + Send + Sync + 'static>,
     pub database_info: DatabaseInfo,
     pub database_describer: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
@@ -88,11 +96,18 @@ impl SqlMigrationConnector {
         Ok(Self {
             database_info,
             schema_name,
+            schemas: Vec::new(),
             database: conn,
             database_describer: Arc::clone(&describer),
         })
     }
 
+    /// Sets the additional schemas the connector manages, besides the one in the connection string.
+    pub fn with_schemas(mut self, schemas: Vec<String>) -> Self {
+        self.schemas = schemas;
+        self
+    }
+
     async fn create_database_impl(&self, db_name: &str) -> SqlResult<()> {
         match self.database_info.sql_family() {
             SqlFamily::Postgres => {
@@ -123,11 +138,13 @@ impl SqlMigrationConnector {
                 }
             }
             ConnectionInfo::Postgres(_) => {
-                let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", &self.schema_name);
+                for schema_name in std::iter::once(&self.schema_name).chain(self.schemas.iter()) {
+                    let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", schema_name);
 
-                debug!("{}", schema_sql);
+                    debug!("{}", schema_sql);
 
-                self.database.query_raw(&schema_sql, &[]).await?;
+                    self.database.query_raw(&schema_sql, &[]).await?;
+                }
             }
             ConnectionInfo::Mysql(_) => {
                 let schema_sql = format!(
@@ -154,10 +171,15 @@ impl SqlMigrationConnector {
         catch(self.connection_info(), async {
             match &self.connection_info() {
                 ConnectionInfo::Postgres(_) => {
-                    let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, self.schema_name());
-                    debug!("{}", sql_str);
+                    let schema_names =
+                        std::iter::once(self.schema_name()).chain(self.schemas.iter().map(String::as_str));
+
+                    for schema_name in schema_names {
+                        let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, schema_name);
+                        debug!("{}", sql_str);
 
-                    self.conn().query_raw(&sql_str, &[]).await.ok();
+                        self.conn().query_raw(&sql_str, &[]).await.ok();
+                    }
                 }
                 ConnectionInfo::Sqlite { file_path, .. } => {
                     self.conn()
@@ -217,6 +239,88 @@ impl MigrationConnector for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn apply_script(&self, script: &str) -> ConnectorResult<()> {
+        catch(self.connection_info(), async {
+            for statement in sql_script::split_statements(script, self.sql_family()) {
+                debug!("{}", statement);
+
+                self.conn().query_raw(statement, &[]).await?;
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn script_migration(&self, script: &str) -> ConnectorResult<SqlMigration> {
+        catch(self.connection_info(), async {
+            let steps: Vec<SqlMigrationStep> = sql_script::split_statements(script, self.sql_family())
+                .into_iter()
+                .map(|statement| SqlMigrationStep::RawSql {
+                    raw: statement.to_owned(),
+                })
+                .collect();
+
+            Ok(SqlMigration {
+                before: SqlSchema::empty(),
+                after: self.describe().await?,
+                original_steps: steps.clone(),
+                corrected_steps: steps,
+                rollback: Vec::new(),
+            })
+        })
+        .await
+    }
+
+    async fn detect_drift(&self, expected: &datamodel::Datamodel) -> ConnectorResult<DatabaseDrift> {
+        catch(self.connection_info(), async {
+            let expected_schema = SqlSchemaCalculator::calculate(expected, &self.database_info)?;
+            let actual_schema = self.describe().await?;
+
+            Ok(SqlSchemaDiffer::drift(
+                &expected_schema,
+                &actual_schema,
+                self.sql_family(),
+            ))
+        })
+        .await
+    }
+
+    async fn detect_drift_since(&self, database_migration: &SqlMigration) -> ConnectorResult<DatabaseDrift> {
+        catch(self.connection_info(), async {
+            let actual_schema = self.describe().await?;
+
+            Ok(SqlSchemaDiffer::drift(
+                &database_migration.after,
+                &actual_schema,
+                self.sql_family(),
+            ))
+        })
+        .await
+    }
+
+    async fn introspect(&self) -> ConnectorResult<datamodel::Datamodel> {
+        catch(self.connection_info(), async {
+            let schema = self.describe().await?;
+
+            sql_introspection_connector::calculate_datamodel::calculate_model(&schema)
+                .map_err(|err| SqlError::Generic(err.into()))
+        })
+        .await
+    }
+
+    async fn acquire_lock(&self, timeout: Duration) -> ConnectorResult<()> {
+        if catch(self.connection_info(), self.acquire_lock_impl(timeout)).await? {
+            Ok(())
+        } else {
+            Err(ConnectorError::migration_lock_timeout(timeout))
+        }
+    }
+
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        catch(self.connection_info(), self.release_lock_impl()).await
+    }
+
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a> {
         Box::new(SqlMigrationPersistence { connector: self })
     }
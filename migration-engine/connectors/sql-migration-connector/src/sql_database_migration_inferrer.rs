@@ -1,5 +1,7 @@
+mod renames;
 mod sqlite;
 
+use crate::sql_migration::renames::{apply_renames, reverse_renames};
 use crate::sql_schema_calculator::SqlSchemaCalculator;
 use crate::sql_schema_differ::{SqlSchemaDiff, SqlSchemaDiffer};
 use crate::*;
@@ -22,9 +24,9 @@ impl Component for SqlDatabaseMigrationInferrer<'_> {
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_> {
     async fn infer(
         &self,
-        _previous: &Datamodel,
+        previous: &Datamodel,
         next: &Datamodel,
-        _steps: &[MigrationStep],
+        steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let fut = async {
             let current_database_schema: SqlSchema = self.describe().await?;
@@ -32,6 +34,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
             infer(
                 &current_database_schema,
                 &expected_database_schema,
+                renames::infer_renames(previous, next, steps, self.schema_name()),
                 self.schema_name(),
                 self.sql_family(),
             )
@@ -44,7 +47,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
         &self,
         previous: &Datamodel,
         next: &Datamodel,
-        _steps: &[MigrationStep],
+        steps: &[MigrationStep],
     ) -> ConnectorResult<SqlMigration> {
         let result: SqlResult<SqlMigration> = (|| {
             let current_database_schema: SqlSchema = SqlSchemaCalculator::calculate(previous, self.database_info())?;
@@ -52,6 +55,7 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
             infer(
                 &current_database_schema,
                 &expected_database_schema,
+                renames::infer_renames(previous, next, steps, self.schema_name()),
                 self.schema_name(),
                 self.sql_family(),
             )
@@ -61,34 +65,43 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
     }
 }
 
+/// Infers the migration between the two schemas. The tables and columns in `renames` are renamed
+/// first, and the rest of the migration is inferred from the renamed schema.
 fn infer(
     current_database_schema: &SqlSchema,
     expected_database_schema: &SqlSchema,
+    renames: Vec<SqlMigrationStep>,
     schema_name: &str,
     sql_family: SqlFamily,
 ) -> SqlResult<SqlMigration> {
+    let mut renamed_current_schema = current_database_schema.clone();
+    let renames = apply_renames(&mut renamed_current_schema, renames);
     let (original_steps, corrected_steps) = infer_database_migration_steps_and_fix(
-        &current_database_schema,
+        &renamed_current_schema,
         &expected_database_schema,
         &schema_name,
         sql_family,
     )?;
+
+    let mut renamed_expected_schema = expected_database_schema.clone();
+    let rollback_renames = apply_renames(&mut renamed_expected_schema, reverse_renames(&renames));
     let (_, rollback) = infer_database_migration_steps_and_fix(
-        &expected_database_schema,
+        &renamed_expected_schema,
         &current_database_schema,
         &schema_name,
         sql_family,
     )?;
+
     Ok(SqlMigration {
         before: current_database_schema.clone(),
         after: expected_database_schema.clone(),
-        original_steps,
-        corrected_steps,
-        rollback,
+        original_steps: renames.iter().cloned().chain(original_steps).collect(),
+        corrected_steps: renames.into_iter().chain(corrected_steps).collect(),
+        rollback: rollback_renames.into_iter().chain(rollback).collect(),
     })
 }
 
-fn infer_database_migration_steps_and_fix(
+pub(crate) fn infer_database_migration_steps_and_fix(
     from: &SqlSchema,
     to: &SqlSchema,
     schema_name: &str,
@@ -147,13 +160,19 @@ fn fix_id_column_type_change(
     // TODO: There's probably a much more graceful way to handle this. But this would also involve a lot of data loss probably. Let's tackle that after P Day
     if has_id_type_change {
         let mut radical_steps = Vec::new();
-        let tables_to_drop: Vec<String> = from
-            .tables
-            .iter()
-            .filter(|t| t.name != "_Migration")
-            .map(|t| t.name.clone())
-            .collect();
-        radical_steps.push(SqlMigrationStep::DropTables(DropTables { names: tables_to_drop }));
+        let mut tables_to_drop: Vec<DropTables> = Vec::new();
+
+        for table in from.tables.iter().filter(|t| t.name != "_Migration") {
+            match tables_to_drop.iter_mut().find(|drop| drop.schema == table.schema) {
+                Some(drop) => drop.names.push(table.name.clone()),
+                None => tables_to_drop.push(DropTables {
+                    names: vec![table.name.clone()],
+                    schema: table.schema.clone(),
+                }),
+            }
+        }
+
+        radical_steps.extend(tables_to_drop.into_iter().map(SqlMigrationStep::DropTables));
         let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(&SqlSchema::empty(), &to, sql_family);
         let mut steps_from_empty = diff_from_empty.into_steps();
         radical_steps.append(&mut steps_from_empty);
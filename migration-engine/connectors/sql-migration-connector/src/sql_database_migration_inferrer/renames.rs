@@ -0,0 +1,136 @@
+use crate::sql_migration::{RenameColumn, SqlMigrationStep};
+use datamodel::dml::{Datamodel, Field, FieldType, Model, WithDatabaseName};
+use migration_connector::steps::MigrationStep;
+
+/// The tables and columns renamed from `previous` to `next`, following the models and fields
+/// renamed by the datamodel steps. A column is only renamed when its type and arity stay the same.
+///
+/// The tables are renamed first, so the column renames refer to the new table names. Tables are
+/// only renamed within their schema, `default_schema` being the schema of the connection.
+pub(super) fn infer_renames(
+    previous: &Datamodel,
+    next: &Datamodel,
+    steps: &[MigrationStep],
+    default_schema: &str,
+) -> Vec<SqlMigrationStep> {
+    let mut table_renames = Vec::new();
+    let mut column_renames = Vec::new();
+
+    for next_model in next.models() {
+        let previous_model =
+            match previous_model_name(steps, &next_model.name).and_then(|name| previous.find_model(name)) {
+                Some(previous_model) => previous_model,
+                None => continue,
+            };
+
+        let schema = table_schema(next_model, default_schema);
+
+        if schema != table_schema(previous_model, default_schema) {
+            continue;
+        }
+
+        if table_name(previous_model) != table_name(next_model) {
+            table_renames.push(SqlMigrationStep::RenameTable {
+                name: table_name(previous_model).to_owned(),
+                new_name: table_name(next_model).to_owned(),
+                schema: schema.clone(),
+            });
+        }
+
+        for next_field in next_model.fields() {
+            let previous_field = match previous_field_name(steps, &next_model.name, &next_field.name)
+                .and_then(|name| previous_model.find_field(name))
+            {
+                Some(previous_field) => previous_field,
+                None => continue,
+            };
+
+            let same_type =
+                previous_field.field_type == next_field.field_type && previous_field.arity == next_field.arity;
+
+            match (column_name(previous_field), column_name(next_field)) {
+                (Some(name), Some(new_name)) if name != new_name && same_type => column_renames.push(RenameColumn {
+                    table: table_name(next_model).to_owned(),
+                    schema: schema.clone(),
+                    name: name.to_owned(),
+                    new_name: new_name.to_owned(),
+                }),
+                _ => (),
+            }
+        }
+    }
+
+    table_renames
+        .into_iter()
+        .chain(column_renames.into_iter().map(SqlMigrationStep::RenameColumn))
+        .collect()
+}
+
+/// The name of a model in the previous datamodel, if it existed.
+fn previous_model_name<'a>(steps: &'a [MigrationStep], name: &'a str) -> Option<&'a str> {
+    let mut previous_name = Some(name);
+
+    for step in steps {
+        match step {
+            MigrationStep::UpdateModel(update_model) if update_model.new_name.as_deref() == Some(name) => {
+                return Some(&update_model.model)
+            }
+            // Another model was renamed away from this name.
+            MigrationStep::UpdateModel(update_model)
+                if update_model.model == name && update_model.new_name.is_some() =>
+            {
+                previous_name = None
+            }
+            _ => (),
+        }
+    }
+
+    previous_name
+}
+
+/// The name of a field in the previous datamodel, if it existed. `model` is the name of the model
+/// in the next datamodel.
+fn previous_field_name<'a>(steps: &'a [MigrationStep], model: &str, name: &'a str) -> Option<&'a str> {
+    let mut previous_name = Some(name);
+
+    for step in steps {
+        match step {
+            MigrationStep::UpdateField(update_field)
+                if update_field.model == model && update_field.new_name.as_deref() == Some(name) =>
+            {
+                return Some(&update_field.field)
+            }
+            // Another field was renamed away from this name.
+            MigrationStep::UpdateField(update_field)
+                if update_field.model == model && update_field.field == name && update_field.new_name.is_some() =>
+            {
+                previous_name = None
+            }
+            _ => (),
+        }
+    }
+
+    previous_name
+}
+
+fn table_name(model: &Model) -> &str {
+    model.database_name.as_deref().unwrap_or(&model.name)
+}
+
+/// The schema of the model's table, `None` for the default schema like in the described schema.
+fn table_schema(model: &Model, default_schema: &str) -> Option<String> {
+    model
+        .schema
+        .as_deref()
+        .filter(|schema| *schema != default_schema)
+        .map(String::from)
+}
+
+/// The column backing a field, for fields backed by exactly one column.
+fn column_name(field: &Field) -> Option<&str> {
+    match field.field_type {
+        FieldType::Relation(_) => None,
+        _ if field.database_names.len() > 1 => None,
+        _ => Some(field.single_database_name().unwrap_or(&field.name)),
+    }
+}
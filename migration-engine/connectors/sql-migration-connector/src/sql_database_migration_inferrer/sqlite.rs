@@ -2,10 +2,10 @@ use crate::{
     sql_migration::*,
     sql_renderer::sqlite_quoted,
     sql_renderer::SqlRenderer,
-    sql_schema_differ::{ColumnDiffer, SqlSchemaDiff, TableDiffer},
+    sql_schema_differ::{ColumnDiffer, ColumnTypeChange, SqlSchemaDiff, TableDiffer},
     SqlFamily, SqlResult,
 };
-use sql_schema_describer::{ColumnArity, SqlSchema, Table};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, SqlSchema, Table};
 
 pub(super) fn fix(
     diff: SqlSchemaDiff,
@@ -114,17 +114,20 @@ fn fix_table(current: &Table, next: &Table, schema_name: &str) -> Vec<SqlMigrati
 
     result.push(SqlMigrationStep::DropTable(DropTable {
         name: current.name.clone(),
+        schema: None,
     }));
 
     result.push(SqlMigrationStep::RenameTable {
         name: name_of_temporary_table,
         new_name: next.name.clone(),
+        schema: None,
     });
 
     // Recreate the indices
     result.extend(next.indices.iter().map(|index| {
         SqlMigrationStep::CreateIndex(CreateIndex {
             table: next.name.clone(),
+            schema: None,
             index: index.clone(),
         })
     }));
@@ -159,14 +162,15 @@ fn copy_current_table_into_new_table(
                 && columns.next.default.is_some()
         })
         .collect();
-    let intersection_columns: Vec<&str> = differ
+    let intersection_columns: Vec<ColumnDiffer<'_>> = differ
         .column_pairs()
+        // Generated columns are computed again by the new table, they can't be written to.
+        .filter(|columns| !columns.previous.is_generated() && !columns.next.is_generated())
         .filter(|columns| {
             !columns_that_became_required_with_a_default
                 .iter()
                 .any(|excluded| excluded.name() == columns.name())
         })
-        .map(|columns| columns.name())
         .collect();
 
     let mut query = String::with_capacity(40);
@@ -180,7 +184,7 @@ fn copy_current_table_into_new_table(
 
     let mut destination_columns = intersection_columns
         .iter()
-        .map(|s| *s)
+        .map(|columns| columns.name())
         .chain(
             columns_that_became_required_with_a_default
                 .iter()
@@ -200,13 +204,14 @@ fn copy_current_table_into_new_table(
 
     let mut source_columns = intersection_columns
         .iter()
-        .map(|s| format!("{}", sqlite_quoted(s)))
+        .map(|columns| render_copied_value(columns))
         .chain(
             columns_that_became_required_with_a_default
                 .iter()
                 .map(|columns| {
                     format!(
-                        "coalesce({column_name}, {default_value}) AS {column_name}",
+                        "coalesce({value}, {default_value}) AS {column_name}",
+                        value = render_copied_value(columns),
                         column_name = sqlite_quoted(columns.name()),
                         default_value = SqlRenderer::for_family(&SqlFamily::Sqlite).render_default(
                             columns
@@ -240,3 +245,22 @@ fn copy_current_table_into_new_table(
 
     Ok(())
 }
+
+/// Values are cast explicitly when the column type changes. SQLite would otherwise keep them with
+/// their previous type, since column types are only an affinity.
+fn render_copied_value(columns: &ColumnDiffer<'_>) -> String {
+    let cast_type = match columns.type_change() {
+        Some(ColumnTypeChange::SafeCast) | Some(ColumnTypeChange::RiskyCast) => match columns.next.tpe.family {
+            ColumnTypeFamily::Int => Some("INTEGER"),
+            ColumnTypeFamily::Float => Some("REAL"),
+            ColumnTypeFamily::String => Some("TEXT"),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    match cast_type {
+        Some(cast_type) => format!("CAST({} AS {})", sqlite_quoted(columns.name()), cast_type),
+        None => format!("{}", sqlite_quoted(columns.name())),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use sql_migration::renames::renamed_schema;
 use sql_renderer::{
     mysql_quoted, mysql_quoted_string, postgres_quoted, postgres_quoted_string, postgres_render_column_type,
     IteratorJoin, SqlRenderer,
@@ -22,12 +23,13 @@ impl crate::component::Component for SqlDatabaseStepApplier<'_> {
 impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
     async fn apply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
         let renderer = self.renderer();
+        let current_schema = renamed_schema(&database_migration.before, &database_migration.corrected_steps);
         let fut = self
             .apply_next_step(
                 &database_migration.corrected_steps,
                 index,
                 renderer.as_ref(),
-                &database_migration.before,
+                &current_schema,
                 &database_migration.after,
             )
             .instrument(tracing::debug_span!("ApplySqlStep", index));
@@ -37,12 +39,13 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
 
     async fn unapply_step(&self, database_migration: &SqlMigration, index: usize) -> ConnectorResult<bool> {
         let renderer = self.renderer();
+        let current_schema = renamed_schema(&database_migration.after, &database_migration.rollback);
         let fut = self
             .apply_next_step(
                 &database_migration.rollback,
                 index,
                 renderer.as_ref(),
-                &database_migration.after,
+                &current_schema,
                 &database_migration.before,
             )
             .instrument(tracing::debug_span!("UnapplySqlStep", index));
@@ -50,12 +53,59 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
         crate::catch(self.connection_info(), fut).await
     }
 
+    fn step_count(&self, database_migration: &SqlMigration) -> usize {
+        database_migration.corrected_steps.len()
+    }
+
+    fn rollback_step_count(&self, database_migration: &SqlMigration) -> usize {
+        database_migration.rollback.len()
+    }
+
+    fn supports_transactional_ddl(&self, database_migration: &SqlMigration) -> bool {
+        match self.sql_family() {
+            SqlFamily::Sqlite => true,
+            // `ALTER TYPE ... ADD VALUE` can't run in a transaction block before Postgres 12.
+            SqlFamily::Postgres => !database_migration
+                .corrected_steps
+                .iter()
+                .chain(database_migration.rollback.iter())
+                .any(|step| match step {
+                    SqlMigrationStep::AlterEnum(alter_enum) => alter_enum.dropped_variants.is_empty(),
+                    _ => false,
+                }),
+            SqlFamily::Mysql => false,
+        }
+    }
+
+    async fn begin_transaction(&self) -> ConnectorResult<()> {
+        crate::catch(self.connection_info(), async {
+            // Foreign keys can't be toggled inside a transaction on SQLite, so the steps that
+            // redefine tables rely on them being off for the whole transaction.
+            if self.sql_family().is_sqlite() {
+                self.conn().query_raw("PRAGMA foreign_keys=OFF", &[]).await?;
+            }
+
+            self.conn().query_raw("BEGIN", &[]).await?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn commit_transaction(&self) -> ConnectorResult<()> {
+        self.end_transaction("COMMIT").await
+    }
+
+    async fn rollback_transaction(&self) -> ConnectorResult<()> {
+        self.end_transaction("ROLLBACK").await
+    }
+
     fn render_steps_pretty(&self, database_migration: &SqlMigration) -> ConnectorResult<Vec<serde_json::Value>> {
         render_steps_pretty(
-            &database_migration,
+            &database_migration.corrected_steps,
             self.renderer().as_ref(),
             self.database_info(),
-            &database_migration.before,
+            &renamed_schema(&database_migration.before, &database_migration.corrected_steps),
             &database_migration.after,
         )?
         .into_iter()
@@ -65,9 +115,52 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
         })
         .collect()
     }
+
+    fn render_rollback_steps_pretty(
+        &self,
+        database_migration: &SqlMigration,
+    ) -> ConnectorResult<Vec<serde_json::Value>> {
+        render_steps_pretty(
+            &database_migration.rollback,
+            self.renderer().as_ref(),
+            self.database_info(),
+            &renamed_schema(&database_migration.after, &database_migration.rollback),
+            &database_migration.before,
+        )?
+        .into_iter()
+        .map(|pretty_step| {
+            serde_json::to_value(&pretty_step)
+                .map_err(|err| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err.into())))
+        })
+        .collect()
+    }
+
+    fn render_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        render_script(
+            &database_migration.corrected_steps,
+            self.renderer().as_ref(),
+            self.database_info(),
+            &renamed_schema(&database_migration.before, &database_migration.corrected_steps),
+            &database_migration.after,
+        )
+        .map_err(|err: anyhow::Error| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))
+    }
 }
 
 impl SqlDatabaseStepApplier<'_> {
+    async fn end_transaction(&self, statement: &str) -> ConnectorResult<()> {
+        crate::catch(self.connection_info(), async {
+            self.conn().query_raw(statement, &[]).await?;
+
+            if self.sql_family().is_sqlite() {
+                self.conn().query_raw("PRAGMA foreign_keys=ON", &[]).await?;
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
     async fn apply_next_step(
         &self,
         steps: &[SqlMigrationStep],
@@ -105,15 +198,15 @@ impl SqlDatabaseStepApplier<'_> {
 }
 
 fn render_steps_pretty(
-    database_migration: &SqlMigration,
+    steps_to_render: &[SqlMigrationStep],
     renderer: &(dyn SqlRenderer + Send + Sync),
     database_info: &DatabaseInfo,
     current_schema: &SqlSchema,
     next_schema: &SqlSchema,
 ) -> ConnectorResult<Vec<PrettySqlMigrationStep>> {
-    let mut steps = Vec::with_capacity(database_migration.corrected_steps.len());
+    let mut steps = Vec::with_capacity(steps_to_render.len());
 
-    for step in &database_migration.corrected_steps {
+    for step in steps_to_render {
         let sql = render_raw_sql(&step, renderer, database_info, current_schema, next_schema)
             .map_err(|err: anyhow::Error| {
                 ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err.into()))
@@ -131,7 +224,32 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
-fn render_raw_sql(
+/// Renders the steps as one script, with every statement terminated by a semicolon.
+pub(crate) fn render_script(
+    steps: &[SqlMigrationStep],
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    database_info: &DatabaseInfo,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> Result<String, anyhow::Error> {
+    let mut script = String::new();
+
+    for step in steps {
+        // Some statements come with a terminating semicolon, others without.
+        for statement in render_raw_sql(step, renderer, database_info, current_schema, next_schema)? {
+            if !script.is_empty() {
+                script.push('\n');
+            }
+
+            script.push_str(statement.trim_end().trim_end_matches(';'));
+            script.push_str(";\n");
+        }
+    }
+
+    Ok(script)
+}
+
+pub(crate) fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlRenderer + Send + Sync),
     database_info: &DatabaseInfo,
@@ -153,7 +271,7 @@ fn render_raw_sql(
             let mut create_table = String::with_capacity(100);
 
             write!(create_table, "CREATE TABLE ")?;
-            renderer.write_quoted_with_schema(&mut create_table, &schema_name, &table.name)?;
+            renderer.write_quoted_with_schema(&mut create_table, table_schema(table, &schema_name), &table.name)?;
             writeln!(create_table, " (")?;
 
             let mut columns = table.columns.iter().peekable();
@@ -205,29 +323,72 @@ fn render_raw_sql(
 
             Ok(vec![create_table])
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => Ok(vec![format!(
+        SqlMigrationStep::DropTable(DropTable { name, schema }) => Ok(vec![format!(
             "DROP TABLE {};",
-            renderer.quote_with_schema(&schema_name, &name)
+            renderer.quote_with_schema(step_schema(schema, &schema_name), &name)
         )]),
-        SqlMigrationStep::DropTables(DropTables { names }) => {
+        SqlMigrationStep::DropTables(DropTables { names, schema }) => {
             let fully_qualified_names: Vec<String> = names
                 .iter()
-                .map(|name| renderer.quote_with_schema(&schema_name, &name))
+                .map(|name| renderer.quote_with_schema(step_schema(schema, &schema_name), &name))
                 .collect();
             Ok(vec![format!("DROP TABLE {};", fully_qualified_names.join(","))])
         }
-        SqlMigrationStep::RenameTable { name, new_name } => {
+        SqlMigrationStep::RenameTable { name, new_name, schema } => {
+            let table_schema_name = step_schema(schema, &schema_name);
+            // Only MySQL accepts a schema-qualified new name, the table stays in its schema.
             let new_name = match sql_family {
-                SqlFamily::Sqlite => renderer.quote(new_name),
-                _ => renderer.quote_with_schema(&schema_name, &new_name),
+                SqlFamily::Mysql => renderer.quote_with_schema(table_schema_name, &new_name),
+                _ => renderer.quote(new_name),
             };
             Ok(vec![format!(
                 "ALTER TABLE {} RENAME TO {};",
-                renderer.quote_with_schema(&schema_name, &name),
+                renderer.quote_with_schema(table_schema_name, &name),
                 new_name
             )])
         }
-        SqlMigrationStep::AddForeignKey(AddForeignKey { table, foreign_key }) => match sql_family {
+        SqlMigrationStep::RenameColumn(RenameColumn {
+            table,
+            schema,
+            name,
+            new_name,
+        }) => {
+            let table_schema_name = step_schema(schema, &schema_name);
+            let rename = match sql_family {
+                // MySQL before 8.0 only renames columns along with their definition.
+                SqlFamily::Mysql => {
+                    let next_table = next_schema
+                        .get_table_in_schema(schema.as_ref().map(String::as_str), table)
+                        .ok_or_else(|| anyhow::anyhow!("Could not find table `{}` in the next schema.", table))?;
+                    let column = next_table
+                        .column(new_name)
+                        .ok_or_else(|| anyhow::anyhow!("Could not find column `{}` on table `{}`.", new_name, table))?;
+                    let column = ColumnRef {
+                        schema: next_schema,
+                        table: next_table,
+                        column,
+                    };
+
+                    format!(
+                        "CHANGE {} {}",
+                        renderer.quote(name),
+                        renderer.render_column(&schema_name, column, true)
+                    )
+                }
+                _ => format!("RENAME COLUMN {} TO {}", renderer.quote(name), renderer.quote(new_name)),
+            };
+
+            Ok(vec![format!(
+                "ALTER TABLE {} {};",
+                renderer.quote_with_schema(table_schema_name, table),
+                rename
+            )])
+        }
+        SqlMigrationStep::AddForeignKey(AddForeignKey {
+            table,
+            schema,
+            foreign_key,
+        }) => match sql_family {
             SqlFamily::Sqlite => Ok(Vec::new()),
             _ => {
                 let mut add_constraint = String::with_capacity(120);
@@ -235,7 +396,7 @@ fn render_raw_sql(
                 write!(
                     add_constraint,
                     "ALTER TABLE {table} ADD ",
-                    table = renderer.quote_with_schema(&schema_name, table)
+                    table = renderer.quote_with_schema(step_schema(schema, &schema_name), table)
                 )?;
 
                 if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
@@ -273,8 +434,17 @@ fn render_raw_sql(
                     TableChange::AlterColumn(AlterColumn { name, column }) => {
                         match safe_alter_column(
                             renderer,
-                            current_schema.get_table(&table.name).unwrap().column(&name).unwrap(),
-                            &column,
+                            &schema_name,
+                            current_schema
+                                .get_table_in_schema(table.schema.as_ref().map(String::as_str), &table.name)
+                                .unwrap()
+                                .column(&name)
+                                .unwrap(),
+                            ColumnRef {
+                                schema: next_schema,
+                                table,
+                                column,
+                            },
                         ) {
                             Some(safe_sql) => {
                                 for line in safe_sql {
@@ -309,26 +479,35 @@ fn render_raw_sql(
             }
             Ok(vec![format!(
                 "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(table_schema(table, &schema_name), &table.name),
                 lines.join(",\n")
             )])
         }
-        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
-            Ok(vec![render_create_index(renderer, database_info, table, index)])
+        SqlMigrationStep::CreateIndex(CreateIndex { table, schema, index }) => Ok(vec![render_create_index(
+            renderer,
+            database_info,
+            step_schema(schema, &schema_name),
+            table,
+            index,
+        )]),
+        SqlMigrationStep::DropIndex(DropIndex { table, schema, name }) => {
+            let table_schema_name = step_schema(schema, &schema_name);
+
+            match sql_family {
+                SqlFamily::Mysql => Ok(vec![format!(
+                    "DROP INDEX {} ON {}",
+                    renderer.quote(&name),
+                    renderer.quote_with_schema(table_schema_name, &table),
+                )]),
+                SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
+                    "DROP INDEX {}",
+                    renderer.quote_with_schema(table_schema_name, &name)
+                )]),
+            }
         }
-        SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
-            SqlFamily::Mysql => Ok(vec![format!(
-                "DROP INDEX {} ON {}",
-                renderer.quote(&name),
-                renderer.quote_with_schema(&schema_name, &table),
-            )]),
-            SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
-                "DROP INDEX {}",
-                renderer.quote_with_schema(&schema_name, &name)
-            )]),
-        },
         SqlMigrationStep::AlterIndex(AlterIndex {
             table,
+            schema,
             index_name,
             index_new_name,
         }) => match sql_family {
@@ -336,8 +515,8 @@ fn render_raw_sql(
                 // MariaDB does not support `ALTER TABLE ... RENAME INDEX`.
                 if database_info.is_mariadb() {
                     let old_index = current_schema
-                        .table(table)
-                        .map_err(|_| {
+                        .get_table_in_schema(schema.as_ref().map(String::as_str), table)
+                        .ok_or_else(|| {
                             anyhow::anyhow!(
                                 "Invariant violation: could not find table `{}` in current schema.",
                                 table
@@ -358,13 +537,19 @@ fn render_raw_sql(
 
                     // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
                     Ok(vec![
-                        render_create_index(renderer, database_info, table, &new_index),
-                        mysql_drop_index(renderer, &schema_name, table, index_name)?,
+                        render_create_index(
+                            renderer,
+                            database_info,
+                            step_schema(schema, &schema_name),
+                            table,
+                            &new_index,
+                        ),
+                        mysql_drop_index(renderer, step_schema(schema, &schema_name), table, index_name)?,
                     ])
                 } else {
                     Ok(vec![format!(
                         "ALTER TABLE {table_name} RENAME INDEX {index_name} TO {index_new_name}",
-                        table_name = renderer.quote_with_schema(&schema_name, &table),
+                        table_name = renderer.quote_with_schema(step_schema(schema, &schema_name), &table),
                         index_name = renderer.quote(index_name),
                         index_new_name = renderer.quote(index_new_name)
                     )])
@@ -372,7 +557,7 @@ fn render_raw_sql(
             }
             SqlFamily::Postgres => Ok(vec![format!(
                 "ALTER INDEX {} RENAME TO {}",
-                renderer.quote_with_schema(&schema_name, index_name),
+                renderer.quote_with_schema(step_schema(schema, &schema_name), index_name),
                 renderer.quote(index_new_name)
             )]),
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
@@ -381,37 +566,98 @@ fn render_raw_sql(
     }
 }
 
+/// The schema a table is rendered in: its own schema when it has one, the default schema otherwise.
+fn table_schema<'a>(table: &'a Table, default_schema: &'a str) -> &'a str {
+    table.schema.as_ref().map(String::as_str).unwrap_or(default_schema)
+}
+
+/// The schema a step referencing a table by name is rendered in.
+fn step_schema<'a>(schema: &'a Option<String>, default_schema: &'a str) -> &'a str {
+    schema.as_ref().map(String::as_str).unwrap_or(default_schema)
+}
+
 fn render_create_index(
     renderer: &dyn SqlRenderer,
     database_info: &DatabaseInfo,
+    schema_name: &str,
     table_name: &str,
     index: &Index,
 ) -> String {
-    let Index { name, columns, tpe } = index;
-    let index_type = match tpe {
-        IndexType::Unique => "UNIQUE",
-        IndexType::Normal => "",
+    let Index {
+        name,
+        columns,
+        tpe,
+        expressions,
+        predicate,
+        method,
+        ..
+    } = index;
+    let index_type = match (tpe, method) {
+        (_, Some(IndexMethod::FullText)) => "FULLTEXT",
+        (_, Some(IndexMethod::Spatial)) => "SPATIAL",
+        (IndexType::Unique, _) => "UNIQUE",
+        (IndexType::Normal, _) => "",
     };
     let sql_family = database_info.sql_family();
     let index_name = match sql_family {
-        SqlFamily::Sqlite => renderer.quote_with_schema(database_info.connection_info().schema_name(), &name),
+        SqlFamily::Sqlite => renderer.quote_with_schema(schema_name, &name),
         _ => renderer.quote(&name),
     };
     let table_reference = match sql_family {
         SqlFamily::Sqlite => renderer.quote(table_name),
-        _ => renderer.quote_with_schema(database_info.connection_info().schema_name(), table_name),
+        _ => renderer.quote_with_schema(schema_name, table_name),
+    };
+    let key_parts: Vec<String> = columns
+        .iter()
+        .enumerate()
+        .map(|(column_index, column)| match index.sort_order(column_index) {
+            SortOrder::Asc => renderer.quote(column),
+            SortOrder::Desc => format!("{} DESC", renderer.quote(column)),
+        })
+        .chain(expressions.iter().map(|expression| format!("({})", expression)))
+        .collect();
+    let (using, index_options) = match (sql_family, method) {
+        (SqlFamily::Postgres, Some(method)) => (
+            postgres_index_method(*method)
+                .map(|method| format!(" USING {}", method))
+                .unwrap_or_default(),
+            String::new(),
+        ),
+        (SqlFamily::Mysql, Some(IndexMethod::Hash)) => (String::new(), " USING HASH".to_owned()),
+        _ => (String::new(), String::new()),
+    };
+    // MySQL has no partial indexes.
+    let predicate = match (sql_family, predicate) {
+        (SqlFamily::Postgres, Some(predicate)) | (SqlFamily::Sqlite, Some(predicate)) => {
+            format!(" WHERE {}", predicate)
+        }
+        _ => String::new(),
     };
-    let columns: Vec<String> = columns.iter().map(|c| renderer.quote(c)).collect();
 
     format!(
-        "CREATE {} INDEX {} ON {}({})",
+        "CREATE {} INDEX {} ON {}{}({}){}{}",
         index_type,
         index_name,
         table_reference,
-        columns.join(",")
+        using,
+        key_parts.join(","),
+        index_options,
+        predicate,
     )
 }
 
+fn postgres_index_method(method: IndexMethod) -> Option<&'static str> {
+    match method {
+        IndexMethod::BTree => Some("btree"),
+        IndexMethod::Hash => Some("hash"),
+        IndexMethod::Gist => Some("gist"),
+        IndexMethod::Gin => Some("gin"),
+        IndexMethod::Brin => Some("brin"),
+        IndexMethod::SpGist => Some("spgist"),
+        IndexMethod::FullText | IndexMethod::Spatial => None,
+    }
+}
+
 fn mysql_drop_index(
     renderer: &dyn SqlRenderer,
     schema_name: &str,
@@ -437,11 +683,13 @@ fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
 
 fn safe_alter_column(
     renderer: &dyn SqlRenderer,
+    schema_name: &str,
     previous_column: &Column,
-    next_column: &Column,
+    next_column_ref: ColumnRef<'_>,
 ) -> Option<Vec<String>> {
     use crate::sql_migration::expanded_alter_column::*;
 
+    let next_column = next_column_ref.column;
     let expanded = crate::sql_migration::expanded_alter_column::expand_alter_column(
         previous_column,
         next_column,
@@ -462,9 +710,10 @@ fn safe_alter_column(
                 ),
                 PostgresAlterColumn::DropNotNull => format!("{} DROP NOT NULL", &alter_column_prefix),
                 PostgresAlterColumn::SetType(ty) => format!(
-                    "{} SET DATA TYPE {}",
+                    "{} SET DATA TYPE {} USING {}",
                     &alter_column_prefix,
-                    postgres_render_column_type(&ty)
+                    postgres_render_column_type(&ty),
+                    postgres_render_cast(&renderer.quote(&previous_column.name), previous_column, &ty),
                 ),
             })
             .collect(),
@@ -477,6 +726,9 @@ fn safe_alter_column(
                     &alter_column_prefix,
                     renderer.render_default(&new_default, &next_column.tpe.family)
                 ),
+                MysqlAlterColumn::Modify => {
+                    format!("MODIFY {}", renderer.render_column(schema_name, next_column_ref, false))
+                }
             })
             .collect(),
         ExpandedAlterColumn::Sqlite(_steps) => vec![],
@@ -485,6 +737,21 @@ fn safe_alter_column(
     Some(steps)
 }
 
+/// Postgres has no direct casts between enums, or from enums to other types than text.
+fn postgres_render_cast(column_name: &str, previous_column: &Column, next_type: &ColumnType) -> String {
+    let through_text = match (&previous_column.tpe.family, &next_type.family) {
+        (ColumnTypeFamily::Enum(_), _) | (_, ColumnTypeFamily::Enum(_)) => "::text",
+        _ => "",
+    };
+
+    format!(
+        "{}{}::{}",
+        column_name,
+        through_text,
+        postgres_render_column_type(next_type).trim_end()
+    )
+}
+
 fn render_create_enum(
     renderer: &(dyn SqlRenderer + Send + Sync),
     create_enum: &CreateEnum,
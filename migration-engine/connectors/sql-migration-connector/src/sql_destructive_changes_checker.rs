@@ -1,14 +1,18 @@
 mod sql_unexecutable_migration;
 
 use crate::{
-    AddColumn, AlterColumn, Component, DropColumn, DropTable, DropTables, SqlError, SqlMigration, SqlMigrationStep,
-    SqlResult, TableChange,
+    sql_migration::renames::{original_column_name, original_table_name, renamed_schema},
+    sql_renderer::SqlRenderer,
+    sql_schema_differ::ColumnTypeChange,
+    sql_schema_helpers::walk_columns,
+    AddColumn, AlterColumn, AlterEnum, Component, CreateIndex, DropColumn, DropTable, DropTables, SqlError,
+    SqlMigration, SqlMigrationStep, SqlResult, TableChange,
 };
 use migration_connector::{
     ConnectorResult, DestructiveChangeDiagnostics, DestructiveChangesChecker, MigrationWarning, UnexecutableMigration,
 };
 use quaint::{ast::*, prelude::SqlFamily};
-use sql_schema_describer::{ColumnArity, SqlSchema};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, SqlSchema};
 
 pub struct SqlDestructiveChangesChecker<'a> {
     pub connector: &'a crate::SqlMigrationConnector,
@@ -23,10 +27,11 @@ impl Component for SqlDestructiveChangesChecker<'_> {
 impl SqlDestructiveChangesChecker<'_> {
     async fn check_table_drop(
         &self,
+        schema: &Option<String>,
         table_name: &str,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let rows_count = self.count_rows_in_table(table_name).await?;
+        let rows_count = self.count_rows_in_table(schema, table_name).await?;
 
         if rows_count > 0 {
             diagnostics.add_warning(MigrationWarning {
@@ -42,7 +47,7 @@ impl SqlDestructiveChangesChecker<'_> {
     }
 
     async fn count_values_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name(), table.name.as_str()))
+        let query = Select::from_table((self.table_schema(&table.schema), table.name.as_str()))
             .value(count(quaint::ast::Column::new(column_name)))
             .so_that(column_name.is_not_null());
 
@@ -67,8 +72,16 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(values_count)
     }
 
-    async fn count_rows_in_table(&self, table_name: &str) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name(), table_name)).value(count(asterisk()));
+    /// The schema a table lives in. `None` designates the default schema, like in the described schema.
+    fn table_schema<'a>(&'a self, schema: &'a Option<String>) -> &'a str {
+        schema
+            .as_ref()
+            .map(String::as_str)
+            .unwrap_or_else(|| self.schema_name())
+    }
+
+    async fn count_rows_in_table(&self, schema: &Option<String>, table_name: &str) -> SqlResult<i64> {
+        let query = Select::from_table((self.table_schema(schema), table_name)).value(count(asterisk()));
         let result_set = self.conn().query(query.into()).await?;
         let rows_count = result_set
             .first()
@@ -132,7 +145,7 @@ impl SqlDestructiveChangesChecker<'_> {
             return Ok(());
         }
 
-        let rows_count = self.count_rows_in_table(&table.name).await?;
+        let rows_count = self.count_rows_in_table(&table.schema, &table.name).await?;
 
         // Empty tables can be safely migrated.
         if rows_count == 0 {
@@ -157,6 +170,11 @@ impl SqlDestructiveChangesChecker<'_> {
     /// - renamings on SQLite
     /// - default changes on SQLite
     /// - Arity changes from required to optional on SQLite
+    /// - Type changes where every value can be cast to the new type
+    ///
+    /// Are considered risky, when the column contains values:
+    ///
+    /// - Type changes where the cast can fail or lose information for some values
     ///
     /// Are considered unexecutable:
     ///
@@ -165,6 +183,7 @@ impl SqlDestructiveChangesChecker<'_> {
         &self,
         alter_column: &AlterColumn,
         previous_table: &sql_schema_describer::Table,
+        after: &SqlSchema,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
         let previous_column = previous_table
@@ -180,12 +199,30 @@ impl SqlDestructiveChangesChecker<'_> {
             return Ok(());
         }
 
-        self.check_for_column_arity_change(&previous_table.name, &differ, diagnostics)
+        self.check_for_column_arity_change(previous_table, &differ, diagnostics)
             .await?;
 
         let values_count = self.count_values_in_column(&alter_column.name, previous_table).await?;
 
-        if values_count > 0 {
+        if values_count > 0
+            && differ.type_change() == Some(ColumnTypeChange::RiskyCast)
+            && self.alter_column_keeps_data(&differ)
+        {
+            let failing_values_count = self.count_values_failing_cast(&differ, previous_table, after).await?;
+
+            if failing_values_count > 0 {
+                diagnostics.add_warning(MigrationWarning {
+                    description: format!(
+                        "You are about to change the type of the column `{column_name}` on the `{table_name}` table from {previous_type} to {next_type}. {values_count} of the values in that column cannot be cast to the new type without failing or losing information.",
+                        column_name = alter_column.name,
+                        table_name = &previous_table.name,
+                        previous_type = differ.previous.tpe.family,
+                        next_type = differ.next.tpe.family,
+                        values_count = failing_values_count,
+                    ),
+                })
+            }
+        } else if values_count > 0 {
             diagnostics.add_warning(MigrationWarning {
                 description: format!(
                                  "You are about to alter the column `{column_name}` on the `{table_name}` table, which still contains {values_count} non-null values. The data in that column will be lost.",
@@ -227,7 +264,12 @@ impl SqlDestructiveChangesChecker<'_> {
                     (ColumnArity::List, _) | (_, ColumnArity::List) => unreachable!(),
                 };
 
-                !differ.all_changes().type_changed() && arity_change_is_safe
+                let type_change_is_safe = match differ.type_change() {
+                    None | Some(ColumnTypeChange::SafeCast) => true,
+                    Some(ColumnTypeChange::RiskyCast) | Some(ColumnTypeChange::NotCastable) => false,
+                };
+
+                type_change_is_safe && arity_change_is_safe
             }
             SqlFamily::Postgres => {
                 let expanded = expand_postgres_alter_column(differ);
@@ -241,7 +283,9 @@ impl SqlDestructiveChangesChecker<'_> {
                             PostgresAlterColumn::SetDefault(_)
                             | PostgresAlterColumn::DropDefault
                             | PostgresAlterColumn::DropNotNull => (),
-                            PostgresAlterColumn::SetType(_) => is_safe = false,
+                            PostgresAlterColumn::SetType(_) => {
+                                is_safe = is_safe && differ.type_change() == Some(ColumnTypeChange::SafeCast)
+                            }
                         }
                     }
 
@@ -255,11 +299,14 @@ impl SqlDestructiveChangesChecker<'_> {
 
                 // We keep the match here to keep the exhaustiveness checking for when we add variants.
                 if let Some(steps) = expanded {
-                    let is_safe = true;
+                    let mut is_safe = true;
 
                     for step in steps {
                         match step {
                             MysqlAlterColumn::SetDefault(_) | MysqlAlterColumn::DropDefault => (),
+                            MysqlAlterColumn::Modify => {
+                                is_safe = is_safe && differ.type_change() == Some(ColumnTypeChange::SafeCast)
+                            }
                         }
                     }
 
@@ -271,13 +318,53 @@ impl SqlDestructiveChangesChecker<'_> {
         }
     }
 
+    /// Whether the existing values are converted, rather than the column being dropped and
+    /// recreated. SQLite tables are redefined with their data copied over.
+    fn alter_column_keeps_data(&self, differ: &crate::sql_schema_differ::ColumnDiffer<'_>) -> bool {
+        use crate::sql_migration::expanded_alter_column::*;
+
+        match self.sql_family() {
+            SqlFamily::Sqlite => true,
+            SqlFamily::Postgres => expand_postgres_alter_column(differ).is_some(),
+            SqlFamily::Mysql => expand_mysql_alter_column(differ).is_some(),
+        }
+    }
+
+    /// Counts the non-null values of the column that fail to cast to the next column type, or lose
+    /// information in the cast. Every value is counted when the database can't tell them apart.
+    async fn count_values_failing_cast(
+        &self,
+        differ: &crate::sql_schema_differ::ColumnDiffer<'_>,
+        table: &sql_schema_describer::Table,
+        after: &SqlSchema,
+    ) -> SqlResult<i64> {
+        let renderer = SqlRenderer::for_family(&self.sql_family());
+        let column = renderer.quote(differ.previous.name.as_str());
+
+        let condition = match failing_cast_condition(self.sql_family(), &column, differ, after) {
+            Some(condition) => condition,
+            None => return self.count_values_in_column(&differ.previous.name, table).await,
+        };
+
+        let query = format!(
+            "SELECT COUNT(*) FROM {table} WHERE {column} IS NOT NULL AND ({condition})",
+            table = renderer.quote_with_schema(self.table_schema(&table.schema), &table.name),
+            column = column,
+            condition = condition,
+        );
+
+        self.query_count(&query, &[]).await
+    }
+
     async fn check_for_column_arity_change(
         &self,
-        table_name: &str,
+        previous_table: &sql_schema_describer::Table,
         differ: &crate::sql_schema_differ::ColumnDiffer<'_>,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let rows_count = self.count_rows_in_table(table_name).await?;
+        let rows_count = self
+            .count_rows_in_table(&previous_table.schema, &previous_table.name)
+            .await?;
 
         if !differ.all_changes().arity_changed()
             || !differ.next.tpe.arity.is_required()
@@ -288,7 +375,7 @@ impl SqlDestructiveChangesChecker<'_> {
         }
 
         let typed_unexecutable = sql_unexecutable_migration::SqlUnexecutableMigration::MadeOptionalFieldRequired {
-            table: table_name.to_owned(),
+            table: previous_table.name.clone(),
             column: differ.previous.name.clone(),
         };
 
@@ -299,10 +386,202 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(())
     }
 
+    /// Unique indexes cannot be created on existing columns that contain duplicate values.
+    async fn check_create_unique_index(
+        &self,
+        create_index: &CreateIndex,
+        steps: &[SqlMigrationStep],
+        before: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let index = &create_index.index;
+        // The existing data is queried with the names from before the renames.
+        let table_name = original_table_name(steps, &create_index.schema, &create_index.table);
+        let column_names: Vec<&str> = index
+            .columns
+            .iter()
+            .map(|column| original_column_name(steps, &create_index.schema, &create_index.table, column))
+            .collect();
+
+        // Indexes on new tables or new columns cannot be violated by existing data.
+        let columns_exist = before
+            .get_table_in_schema(create_index.schema.as_ref().map(String::as_str), table_name)
+            .map(|table| column_names.iter().all(|column| table.column(column).is_some()))
+            .unwrap_or(false);
+
+        if !columns_exist {
+            return Ok(());
+        }
+
+        let renderer = SqlRenderer::for_family(&self.sql_family());
+        let columns: Vec<String> = column_names.iter().map(|column| renderer.quote(column)).collect();
+        let not_null_conditions: Vec<String> = columns.iter().map(|column| format!("{} IS NOT NULL", column)).collect();
+
+        // NULLs are distinct in unique indexes, so they never count as duplicates.
+        let query = format!(
+            "SELECT COUNT(*) FROM (SELECT 1 FROM {table} WHERE {conditions} GROUP BY {columns} HAVING COUNT(*) > 1) AS duplicates",
+            table = renderer.quote_with_schema(self.table_schema(&create_index.schema), table_name),
+            conditions = not_null_conditions.join(" AND "),
+            columns = columns.join(", "),
+        );
+
+        let duplicates_count = self.query_count(&query, &[]).await?;
+
+        if duplicates_count == 0 {
+            return Ok(());
+        }
+
+        let typed_unexecutable =
+            sql_unexecutable_migration::SqlUnexecutableMigration::AddedUnimplementableUniqueConstraint {
+                table: create_index.table.clone(),
+                constrained_columns: index.columns.clone(),
+                duplicates_count: duplicates_count as u64,
+            };
+
+        diagnostics.unexecutable_migrations.push(UnexecutableMigration {
+            description: format!("{}", typed_unexecutable),
+        });
+
+        Ok(())
+    }
+
+    /// Enum values cannot be removed while rows still use them.
+    async fn check_alter_enum(
+        &self,
+        alter_enum: &AlterEnum,
+        before: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        if alter_enum.dropped_variants.is_empty() {
+            return Ok(());
+        }
+
+        let renderer = SqlRenderer::for_family(&self.sql_family());
+        // Enum columns use the enums of the schema their table lives in.
+        let enum_columns = walk_columns(before).filter(|column| match &column.column_type().family {
+            ColumnTypeFamily::Enum(enum_name) => {
+                enum_name == &alter_enum.name && column.table().schema() == &alter_enum.schema
+            }
+            _ => false,
+        });
+
+        for column in enum_columns {
+            let column_name = match self.sql_family() {
+                // Postgres enum columns cannot be compared with a text parameter directly.
+                SqlFamily::Postgres => format!("{}::text", renderer.quote(column.name())),
+                _ => renderer.quote(column.name()),
+            };
+            let placeholder = match self.sql_family() {
+                SqlFamily::Postgres => "$1",
+                _ => "?",
+            };
+            let query = format!(
+                "SELECT COUNT(*) FROM {table} WHERE {column} = {placeholder}",
+                table = renderer.quote_with_schema(self.table_schema(column.table().schema()), column.table().name()),
+                column = column_name,
+                placeholder = placeholder,
+            );
+
+            for value in &alter_enum.dropped_variants {
+                let uses_count = self
+                    .query_count(&query, &[ParameterizedValue::Text(value.as_str().into())])
+                    .await?;
+
+                if uses_count == 0 {
+                    continue;
+                }
+
+                let typed_unexecutable = sql_unexecutable_migration::SqlUnexecutableMigration::DeletedUsedEnumValue {
+                    r#enum: alter_enum.name.clone(),
+                    value: value.clone(),
+                    table: column.table().name().to_owned(),
+                    column: column.name().to_owned(),
+                    uses_count: uses_count as u64,
+                };
+
+                diagnostics.unexecutable_migrations.push(UnexecutableMigration {
+                    description: format!("{}", typed_unexecutable),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The primary key of a table cannot change while the table contains rows, since the existing
+    /// rows are not guaranteed to be identifiable by the new primary key.
+    async fn check_primary_key_changes(
+        &self,
+        steps: &[SqlMigrationStep],
+        before: &SqlSchema,
+        after: &SqlSchema,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        // Renamed tables and columns are compared under their new names.
+        let before = renamed_schema(before, steps);
+
+        for previous_table in &before.tables {
+            let next_table = match after
+                .get_table_in_schema(previous_table.schema.as_ref().map(String::as_str), &previous_table.name)
+            {
+                Some(table) => table,
+                None => continue,
+            };
+
+            let next_columns = next_table.primary_key_columns();
+            let columns_changed = previous_table.primary_key_columns() != next_columns;
+            let column_types_changed = next_columns.iter().any(|column_name| {
+                match (previous_table.column(column_name), next_table.column(column_name)) {
+                    (Some(previous), Some(next)) => previous.tpe.family != next.tpe.family,
+                    _ => false,
+                }
+            });
+
+            if !columns_changed && !column_types_changed {
+                continue;
+            }
+
+            let rows_count = self
+                .count_rows_in_table(
+                    &previous_table.schema,
+                    original_table_name(steps, &previous_table.schema, &previous_table.name),
+                )
+                .await?;
+
+            if rows_count == 0 {
+                continue;
+            }
+
+            let typed_unexecutable = sql_unexecutable_migration::SqlUnexecutableMigration::PrimaryKeyChanged {
+                table: previous_table.name.clone(),
+                columns: next_columns,
+                rows_count: rows_count as u64,
+            };
+
+            diagnostics.unexecutable_migrations.push(UnexecutableMigration {
+                description: format!("{}", typed_unexecutable),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn query_count(&self, query: &str, params: &[ParameterizedValue<'_>]) -> SqlResult<i64> {
+        let result_set = self.conn().query_raw(query, params).await?;
+
+        result_set
+            .first()
+            .as_ref()
+            .and_then(|row| row.at(0))
+            .and_then(|count| count.as_i64())
+            .ok_or_else(|| SqlError::Generic(anyhow::anyhow!("Unexpected result set shape for `{}`.", query)))
+    }
+
     async fn check_impl(
         &self,
         steps: &[SqlMigrationStep],
         before: &SqlSchema,
+        after: &SqlSchema,
     ) -> SqlResult<DestructiveChangeDiagnostics> {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
@@ -310,8 +589,13 @@ impl SqlDestructiveChangesChecker<'_> {
             match step {
                 SqlMigrationStep::AlterTable(alter_table) => {
                     // The table in alter_table is the updated table, but we want to
-                    // check against the current state of the table.
-                    let before_table = before.get_table(&alter_table.table.name);
+                    // check against the current state of the table, under its name before the
+                    // renames.
+                    let table = &alter_table.table;
+                    let before_table = before.get_table_in_schema(
+                        table.schema.as_ref().map(String::as_str),
+                        original_table_name(steps, &table.schema, &table.name),
+                    );
 
                     if let Some(before_table) = before_table {
                         for change in &alter_table.changes {
@@ -321,7 +605,18 @@ impl SqlDestructiveChangesChecker<'_> {
                                         .await?
                                 }
                                 TableChange::AlterColumn(ref alter_column) => {
-                                    self.check_alter_column(alter_column, before_table, &mut diagnostics)
+                                    let alter_column = AlterColumn {
+                                        name: original_column_name(
+                                            steps,
+                                            &table.schema,
+                                            &table.name,
+                                            &alter_column.name,
+                                        )
+                                        .to_owned(),
+                                        column: alter_column.column.clone(),
+                                    };
+
+                                    self.check_alter_column(&alter_column, before_table, after, &mut diagnostics)
                                         .await?
                                 }
                                 TableChange::AddColumn(ref add_column) => {
@@ -335,20 +630,29 @@ impl SqlDestructiveChangesChecker<'_> {
                 }
                 // Here, check for each table we are going to delete if it is empty. If
                 // not, return a warning.
-                SqlMigrationStep::DropTable(DropTable { name }) => {
-                    self.check_table_drop(name, &mut diagnostics).await?;
+                SqlMigrationStep::DropTable(DropTable { name, schema }) => {
+                    self.check_table_drop(schema, name, &mut diagnostics).await?;
                 }
-                SqlMigrationStep::DropTables(DropTables { names }) => {
+                SqlMigrationStep::DropTables(DropTables { names, schema }) => {
                     for name in names {
-                        self.check_table_drop(name, &mut diagnostics).await?;
+                        self.check_table_drop(schema, name, &mut diagnostics).await?;
                     }
                 }
-                // SqlMigrationStep::CreateIndex(CreateIndex { table, index }) if index.is_unique() => todo!(),
+                SqlMigrationStep::CreateIndex(create_index) if create_index.index.is_unique() => {
+                    self.check_create_unique_index(create_index, steps, before, &mut diagnostics)
+                        .await?;
+                }
+                SqlMigrationStep::AlterEnum(alter_enum) => {
+                    self.check_alter_enum(alter_enum, before, &mut diagnostics).await?;
+                }
                 // do nothing
                 _ => (),
             }
         }
 
+        self.check_primary_key_changes(steps, before, after, &mut diagnostics)
+            .await?;
+
         Ok(diagnostics)
     }
 }
@@ -356,14 +660,99 @@ impl SqlDestructiveChangesChecker<'_> {
 #[async_trait::async_trait]
 impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker<'_> {
     async fn check(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
-        self.check_impl(&database_migration.original_steps, &database_migration.before)
-            .await
-            .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
+        self.check_impl(
+            &database_migration.original_steps,
+            &database_migration.before,
+            &database_migration.after,
+        )
+        .await
+        .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
     }
 
     async fn check_unapply(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
-        self.check_impl(&database_migration.rollback, &database_migration.after)
-            .await
-            .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
+        self.check_impl(
+            &database_migration.rollback,
+            &database_migration.after,
+            &database_migration.before,
+        )
+        .await
+        .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
     }
 }
+
+/// A condition matching the values of `column` that fail to cast to the next column type, or lose
+/// information in the cast. `None` when the database can't tell these values apart.
+fn failing_cast_condition(
+    sql_family: SqlFamily,
+    column: &str,
+    differ: &crate::sql_schema_differ::ColumnDiffer<'_>,
+    after: &SqlSchema,
+) -> Option<String> {
+    use ColumnTypeFamily::*;
+
+    let condition = match (&differ.previous.tpe.family, &differ.next.tpe.family, sql_family) {
+        (Float, Int, SqlFamily::Sqlite) => format!("{column} <> CAST({column} AS INTEGER)", column = column),
+        (Float, Int, _) => format!("{column} <> FLOOR({column})", column = column),
+        (Int, Boolean, _) => format!("{} NOT IN (0, 1)", column),
+        (String, Int, SqlFamily::Postgres) => format!(r#"{} !~ '^\s*[+-]?[0-9]+\s*$'"#, column),
+        (String, Int, SqlFamily::Mysql) => format!("{} NOT REGEXP '^[[:space:]]*[+-]?[0-9]+[[:space:]]*$'", column),
+        // SQLite has no regular expressions, the values have to survive a round trip instead.
+        (String, Int, SqlFamily::Sqlite) => format!(
+            "CAST(CAST({column} AS INTEGER) AS TEXT) <> TRIM({column})",
+            column = column
+        ),
+        (String, Float, SqlFamily::Postgres) => format!(
+            r#"{} !~ '^\s*[+-]?([0-9]+[.]?[0-9]*|[.][0-9]+)([eE][+-]?[0-9]+)?\s*$'"#,
+            column
+        ),
+        (String, Float, SqlFamily::Mysql) => format!(
+            "{} NOT REGEXP '^[[:space:]]*[+-]?([0-9]+[.]?[0-9]*|[.][0-9]+)([eE][+-]?[0-9]+)?[[:space:]]*$'",
+            column
+        ),
+        (String, Float, SqlFamily::Sqlite) => format!(
+            "TRIM({column}) = '' OR TRIM({column}) GLOB '*[^0-9.eE+-]*'",
+            column = column
+        ),
+        (String, Boolean, SqlFamily::Postgres) => format!(
+            "LOWER(TRIM({})) NOT IN ('t', 'true', 'y', 'yes', 'on', '1', 'f', 'false', 'n', 'no', 'off', '0')",
+            column
+        ),
+        // Booleans are stored as integers.
+        (String, Boolean, _) => format!("TRIM({}) NOT IN ('0', '1')", column),
+        (String, DateTime, SqlFamily::Postgres) => format!(
+            r#"{} !~ '^\s*[0-9]{{4}}-[0-9]{{2}}-[0-9]{{2}}([ T][0-9]{{2}}:[0-9]{{2}}(:[0-9]{{2}}([.][0-9]+)?)?)?\s*(Z|[+-][0-9]{{2}}(:?[0-9]{{2}})?)?\s*$'"#,
+            column
+        ),
+        (String, DateTime, SqlFamily::Mysql) => format!(
+            "{} NOT REGEXP '^[[:space:]]*[0-9]{{4}}-[0-9]{{2}}-[0-9]{{2}}([ T][0-9]{{2}}:[0-9]{{2}}(:[0-9]{{2}}([.][0-9]+)?)?)?[[:space:]]*$'",
+            column
+        ),
+        (String, DateTime, SqlFamily::Sqlite) => format!("datetime({}) IS NULL", column),
+        (String, Json, SqlFamily::Mysql) => format!("NOT JSON_VALID({})", column),
+        (String, Uuid, SqlFamily::Postgres) => format!(
+            r#"{} !~* '^\s*[{{]?[0-9a-f]{{8}}-?([0-9a-f]{{4}}-?){{3}}[0-9a-f]{{12}}[}}]?\s*$'"#,
+            column
+        ),
+        (String, Enum(enum_name), _) | (Enum(_), Enum(enum_name), _) => {
+            let variants: Vec<std::string::String> = after
+                .get_enum(enum_name)
+                .map(|r#enum| r#enum.values.iter().map(|value| format!("'{}'", value.replace('\'', "''"))).collect())
+                .unwrap_or_default();
+
+            if variants.is_empty() {
+                return None;
+            }
+
+            let column = match sql_family {
+                // Postgres enum values can only be compared with text after a cast.
+                SqlFamily::Postgres => format!("{}::text", column),
+                _ => column.to_owned(),
+            };
+
+            format!("{} NOT IN ({})", column, variants.join(", "))
+        }
+        _ => return None,
+    };
+
+    Some(condition)
+}
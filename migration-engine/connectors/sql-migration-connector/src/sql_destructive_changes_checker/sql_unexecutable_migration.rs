@@ -9,19 +9,23 @@ pub(crate) enum SqlUnexecutableMigration {
         table: String,
         column: String,
     },
-    // TODO:
-    // AddedUnimplementableUniqueConstraint {
-    //     table: String,
-    //     constrained_columns: Vec<String>,
-    // },
-    // DeletedUsedEnumValue {
-    //     r#enum: String,
-    //     value: String,
-    //     uses_count: Option<u64>,
-    // },
-    // PrimaryKeyChanged {
-    //     table: String,
-    // },
+    AddedUnimplementableUniqueConstraint {
+        table: String,
+        constrained_columns: Vec<String>,
+        duplicates_count: u64,
+    },
+    DeletedUsedEnumValue {
+        r#enum: String,
+        value: String,
+        table: String,
+        column: String,
+        uses_count: u64,
+    },
+    PrimaryKeyChanged {
+        table: String,
+        columns: Vec<String>,
+        rows_count: u64,
+    },
 }
 
 impl std::fmt::Display for SqlUnexecutableMigration {
@@ -33,23 +37,25 @@ impl std::fmt::Display for SqlUnexecutableMigration {
             SqlUnexecutableMigration::MadeOptionalFieldRequired { table, column } => {
                 write!(f, "Made the column `{column}` on table `{table}` required, but there are existing NULL values.", column = column, table = table)?
             },
-            // TODO
-            //
-            // SqlUnexecutableMigration::AddedUnimplementableUniqueConstraint { table, constrained_columns } => write!(f, "Added a unique constraint that would not hold given existing data on `{table}`.{constrained_columns:?}", table = table, constrained_columns = constrained_columns)?,
-            // SqlUnexecutableMigration::DeletedUsedEnumValue {
-            //     r#enum,
-            //     value,
-            //     uses_count,
-            // } => {
-            //     write!(f, "You deleted the value `{value}` of the `{enum_name}` enum, but it is still used `{uses_count:?}` times in the database. (TODO: say which tables)", value = value, enum_name = r#enum, uses_count = uses_count)?
-            // }
-            // SqlUnexecutableMigration::PrimaryKeyChanged { table } => write!(
-            //     f,
-            //     "The id field(s) for table {table} changed. This is currently not supported by prisma.",
-            //     table = table
-            // )?,
+            SqlUnexecutableMigration::AddedUnimplementableUniqueConstraint { table, constrained_columns, duplicates_count } => {
+                write!(f, "Added a unique constraint on the columns {columns} of the `{table}` table, but {duplicates_count} values appear more than once in the existing data.", columns = quoted_columns(constrained_columns), table = table, duplicates_count = duplicates_count)?
+            },
+            SqlUnexecutableMigration::DeletedUsedEnumValue { r#enum, value, table, column, uses_count } => {
+                write!(f, "Removed the value `{value}` from the `{enum_name}` enum, but it is still used {uses_count} times in the column `{column}` of the `{table}` table.", value = value, enum_name = r#enum, uses_count = uses_count, column = column, table = table)?
+            },
+            SqlUnexecutableMigration::PrimaryKeyChanged { table, columns, rows_count } => {
+                write!(f, "Changed the primary key of the `{table}` table to ({columns}), but there are {rows_count} rows in this table, it is not possible.", table = table, columns = quoted_columns(columns), rows_count = rows_count)?
+            },
         }
 
         Ok(())
     }
 }
+
+fn quoted_columns(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| format!("`{}`", column))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -1,4 +1,5 @@
 pub(crate) mod expanded_alter_column;
+pub(crate) mod renames;
 
 use migration_connector::DatabaseMigrationMarker;
 use serde::{Deserialize, Serialize};
@@ -41,8 +42,17 @@ pub enum SqlMigrationStep {
     AlterTable(AlterTable),
     DropTable(DropTable),
     DropTables(DropTables),
-    RenameTable { name: String, new_name: String },
-    RawSql { raw: String },
+    RenameTable {
+        name: String,
+        new_name: String,
+        /// The schema of the table, when it is not the default schema of the connection.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schema: Option<String>,
+    },
+    RenameColumn(RenameColumn),
+    RawSql {
+        raw: String,
+    },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
@@ -68,11 +78,17 @@ pub struct CreateTable {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTable {
     pub name: String,
+    /// The schema of the table, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTables {
     pub names: Vec<String>,
+    /// The schema of the tables, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -107,9 +123,24 @@ pub struct AlterColumn {
     pub column: Column,
 }
 
+/// Renames a column, keeping its data.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameColumn {
+    /// The name of the table, once the tables of the migration are renamed.
+    pub table: String,
+    /// The schema of `table`, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub name: String,
+    pub new_name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AddForeignKey {
     pub table: String,
+    /// The schema of `table`, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub foreign_key: ForeignKey,
 }
 
@@ -121,18 +152,27 @@ pub struct DropForeignKey {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
+    /// The schema of `table`, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub index: Index,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
+    /// The schema of `table`, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterIndex {
     pub table: String,
+    /// The schema of `table`, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub index_name: String,
     pub index_new_name: String,
 }
@@ -151,6 +191,9 @@ pub struct DropEnum {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterEnum {
     pub name: String,
+    /// The schema of the enum, when it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub created_variants: Vec<String>,
     pub dropped_variants: Vec<String>,
 }
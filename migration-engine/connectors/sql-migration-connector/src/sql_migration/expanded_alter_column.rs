@@ -1,6 +1,6 @@
-use crate::sql_schema_differ::{ColumnChange, ColumnDiffer};
+use crate::sql_schema_differ::{ColumnChange, ColumnDiffer, ColumnTypeChange};
 use quaint::prelude::SqlFamily;
-use sql_schema_describer::{Column, ColumnArity, ColumnType, ColumnTypeFamily};
+use sql_schema_describer::{Column, ColumnArity, ColumnType};
 
 pub(crate) fn expand_alter_column(
     previous_column: &Column,
@@ -24,6 +24,21 @@ pub(crate) fn expand_sqlite_alter_column(_columns: &ColumnDiffer) -> Option<Vec<
 }
 
 pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer) -> Option<Vec<MysqlAlterColumn>> {
+    // Generation expressions can't be altered in place.
+    if columns.all_changes().iter().any(|change| change == ColumnChange::Generated) {
+        return None;
+    }
+
+    // MODIFY redefines the whole column, including the arity and the default, and converts the
+    // existing values to the new type.
+    match columns.type_change() {
+        Some(ColumnTypeChange::SafeCast) | Some(ColumnTypeChange::RiskyCast) => {
+            return Some(vec![MysqlAlterColumn::Modify])
+        }
+        Some(ColumnTypeChange::NotCastable) => return None,
+        None => (),
+    }
+
     let mut changes: Vec<MysqlAlterColumn> = Vec::new();
 
     for change in columns.all_changes().iter() {
@@ -41,8 +56,10 @@ pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer) -> Option<Vec<My
 
 pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer) -> Option<Vec<PostgresAlterColumn>> {
     let mut changes = Vec::new();
+    let all_changes = columns.all_changes();
+    let default_changed = all_changes.iter().any(|change| change == ColumnChange::Default);
 
-    for change in columns.all_changes().iter() {
+    for change in all_changes.iter() {
         match change {
             ColumnChange::Default => match (&columns.previous.default, &columns.next.default) {
                 (_, Some(next_default)) => changes.push(PostgresAlterColumn::SetDefault(next_default.clone())),
@@ -52,13 +69,27 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer) -> Option<Vec
                 (ColumnArity::Required, ColumnArity::Nullable) => changes.push(PostgresAlterColumn::DropNotNull),
                 _ => return None,
             },
-            ColumnChange::Type => match (&columns.previous.tpe.family, &columns.next.tpe.family) {
-                // Ints can be cast to text.
-                (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
-                    changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()))
+            ColumnChange::Type => match columns.type_change() {
+                Some(ColumnTypeChange::SafeCast) | Some(ColumnTypeChange::RiskyCast) => {
+                    // The current default would have to be cast with the values, so it is reset
+                    // around the type change instead.
+                    if columns.previous.default.is_some() {
+                        changes.push(PostgresAlterColumn::DropDefault);
+                    }
+
+                    changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()));
+
+                    match &columns.next.default {
+                        Some(next_default) if !default_changed => {
+                            changes.push(PostgresAlterColumn::SetDefault(next_default.clone()))
+                        }
+                        _ => (),
+                    }
                 }
                 _ => return None,
             },
+            // Generation expressions can't be altered in place.
+            ColumnChange::Generated => return None,
             ColumnChange::Renaming => unreachable!("column renaming"),
         }
     }
@@ -79,6 +110,7 @@ pub(crate) enum PostgresAlterColumn {
     SetDefault(sql_schema_describer::DefaultValue),
     DropDefault,
     DropNotNull,
+    /// Converts the existing values with a `USING` cast.
     SetType(ColumnType),
     // Not used yet:
     // SetNotNull,
@@ -90,6 +122,8 @@ pub(crate) enum PostgresAlterColumn {
 pub(crate) enum MysqlAlterColumn {
     SetDefault(sql_schema_describer::DefaultValue),
     DropDefault,
+    /// Redefines the column with MODIFY, converting the existing values to the new type.
+    Modify,
     // Not used yet:
     // Rename { previous_name: String, next_name: String },
 }
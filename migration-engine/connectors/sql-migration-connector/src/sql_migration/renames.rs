@@ -0,0 +1,167 @@
+//! Tables and columns renamed by a migration, instead of being dropped and created again.
+
+use super::{RenameColumn, SqlMigrationStep};
+use sql_schema_describer::SqlSchema;
+
+/// Applies the renames to the schema, and returns the ones that could be applied: the renamed
+/// table or column has to exist, and its new name has to be free.
+pub(crate) fn apply_renames(schema: &mut SqlSchema, renames: Vec<SqlMigrationStep>) -> Vec<SqlMigrationStep> {
+    renames
+        .into_iter()
+        .filter(|rename| apply_rename(schema, rename))
+        .collect()
+}
+
+/// The schema with the renames among `steps` applied, to look up tables and columns by the names
+/// they have once the renames ran.
+pub(crate) fn renamed_schema(schema: &SqlSchema, steps: &[SqlMigrationStep]) -> SqlSchema {
+    let mut schema = schema.clone();
+
+    for step in steps {
+        apply_rename(&mut schema, step);
+    }
+
+    schema
+}
+
+/// The renames undoing `renames`.
+pub(crate) fn reverse_renames(renames: &[SqlMigrationStep]) -> Vec<SqlMigrationStep> {
+    renames
+        .iter()
+        .rev()
+        .filter_map(|rename| match rename {
+            SqlMigrationStep::RenameTable { name, new_name, schema } => Some(SqlMigrationStep::RenameTable {
+                name: new_name.clone(),
+                new_name: name.clone(),
+                schema: schema.clone(),
+            }),
+            SqlMigrationStep::RenameColumn(rename_column) => Some(SqlMigrationStep::RenameColumn(RenameColumn {
+                table: rename_column.table.clone(),
+                schema: rename_column.schema.clone(),
+                name: rename_column.new_name.clone(),
+                new_name: rename_column.name.clone(),
+            })),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The name a table had before the renames among `steps`. `schema` is the schema of the table.
+pub(crate) fn original_table_name<'a>(
+    steps: &'a [SqlMigrationStep],
+    schema: &Option<String>,
+    table: &'a str,
+) -> &'a str {
+    steps.iter().rev().fold(table, |table, step| match step {
+        SqlMigrationStep::RenameTable {
+            name,
+            new_name,
+            schema: rename_schema,
+        } if new_name == table && rename_schema == schema => name.as_str(),
+        _ => table,
+    })
+}
+
+/// The name a column had before the renames among `steps`. `table` is the name of its table once
+/// the renames ran, and `schema` the schema of the table.
+pub(crate) fn original_column_name<'a>(
+    steps: &'a [SqlMigrationStep],
+    schema: &Option<String>,
+    table: &str,
+    column: &'a str,
+) -> &'a str {
+    steps.iter().rev().fold(column, |column, step| match step {
+        SqlMigrationStep::RenameColumn(rename)
+            if rename.table == table && &rename.schema == schema && rename.new_name == column =>
+        {
+            rename.name.as_str()
+        }
+        _ => column,
+    })
+}
+
+fn apply_rename(schema: &mut SqlSchema, step: &SqlMigrationStep) -> bool {
+    match step {
+        SqlMigrationStep::RenameTable {
+            name,
+            new_name,
+            schema: table_schema,
+        } => rename_table(schema, table_schema.as_ref().map(String::as_str), name, new_name),
+        SqlMigrationStep::RenameColumn(rename) => rename_column(schema, rename),
+        _ => false,
+    }
+}
+
+fn rename_table(schema: &mut SqlSchema, table_schema: Option<&str>, name: &str, new_name: &str) -> bool {
+    if schema.get_table_in_schema(table_schema, name).is_none()
+        || schema.get_table_in_schema(table_schema, new_name).is_some()
+    {
+        return false;
+    }
+
+    let in_schema = |other_schema: &Option<String>| other_schema.as_ref().map(String::as_str) == table_schema;
+
+    for table in schema.tables.iter_mut() {
+        if table.name == name && in_schema(&table.schema) {
+            table.name = new_name.to_owned();
+        }
+
+        for foreign_key in table.foreign_keys.iter_mut() {
+            if foreign_key.referenced_table == name && in_schema(&foreign_key.referenced_schema) {
+                foreign_key.referenced_table = new_name.to_owned();
+            }
+        }
+    }
+
+    true
+}
+
+fn rename_column(schema: &mut SqlSchema, rename: &RenameColumn) -> bool {
+    let table = match schema
+        .tables
+        .iter_mut()
+        .find(|table| table.name == rename.table && table.schema == rename.schema)
+    {
+        Some(table) => table,
+        None => return false,
+    };
+
+    if !table.has_column(&rename.name) || table.has_column(&rename.new_name) {
+        return false;
+    }
+
+    let renamed = |column: &mut String| {
+        if *column == rename.name {
+            *column = rename.new_name.clone();
+        }
+    };
+
+    table.columns.iter_mut().for_each(|column| renamed(&mut column.name));
+    table
+        .indices
+        .iter_mut()
+        .flat_map(|index| index.columns.iter_mut())
+        .for_each(renamed);
+    table
+        .primary_key
+        .iter_mut()
+        .flat_map(|primary_key| primary_key.columns.iter_mut())
+        .for_each(renamed);
+    table
+        .foreign_keys
+        .iter_mut()
+        .flat_map(|foreign_key| foreign_key.columns.iter_mut())
+        .for_each(renamed);
+
+    schema
+        .tables
+        .iter_mut()
+        .flat_map(|table| table.foreign_keys.iter_mut())
+        .filter(|foreign_key| {
+            foreign_key.referenced_table == rename.table && foreign_key.referenced_schema == rename.schema
+        })
+        .flat_map(|foreign_key| foreign_key.referenced_columns.iter_mut())
+        .for_each(renamed);
+
+    true
+}
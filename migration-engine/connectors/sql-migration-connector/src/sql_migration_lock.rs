@@ -0,0 +1,164 @@
+//! The migration lock, built on the locking primitives of each database: advisory locks on
+//! Postgres, named locks on MySQL and the exclusive locking mode on SQLite.
+
+use crate::{Component, SqlMigrationConnector, SqlResult};
+use quaint::{ast::ParameterizedValue, prelude::SqlFamily};
+use std::time::{Duration, Instant};
+
+/// The first key of the Postgres advisory lock. The second key is a hash of the schema name, so
+/// that migration engines working on different schemas of the same database don't wait for each
+/// other.
+const POSTGRES_ADVISORY_LOCK_KEY: i32 = 72_707_369;
+
+/// How long to wait between two attempts at taking the Postgres advisory lock.
+const POSTGRES_LOCK_POLLING_INTERVAL: Duration = Duration::from_millis(100);
+
+/// MySQL rejects lock names longer than this.
+const MYSQL_LOCK_NAME_MAX_LENGTH: usize = 64;
+
+impl SqlMigrationConnector {
+    /// Returns whether the lock was acquired within the timeout.
+    pub(crate) async fn acquire_lock_impl(&self, timeout: Duration) -> SqlResult<bool> {
+        match self.sql_family() {
+            SqlFamily::Postgres => self.acquire_postgres_lock(timeout).await,
+            SqlFamily::Mysql => self.acquire_mysql_lock(timeout).await,
+            SqlFamily::Sqlite => self.acquire_sqlite_lock(timeout).await,
+        }
+    }
+
+    pub(crate) async fn release_lock_impl(&self) -> SqlResult<()> {
+        match self.sql_family() {
+            SqlFamily::Postgres => {
+                let query = format!(
+                    "SELECT pg_advisory_unlock({}, hashtext($1))",
+                    POSTGRES_ADVISORY_LOCK_KEY
+                );
+
+                self.conn()
+                    .query_raw(&query, &[ParameterizedValue::from(self.schema_name())])
+                    .await?;
+            }
+            SqlFamily::Mysql => {
+                self.conn()
+                    .query_raw(
+                        "SELECT RELEASE_LOCK(?)",
+                        &[ParameterizedValue::from(self.mysql_lock_name())],
+                    )
+                    .await?;
+            }
+            SqlFamily::Sqlite => {
+                // The lock is only released the next time the database is read in normal locking mode.
+                self.conn().query_raw("PRAGMA locking_mode = NORMAL", &[]).await?;
+
+                let query = format!("SELECT COUNT(*) FROM \"{}\".sqlite_master", self.schema_name());
+                self.conn().query_raw(&query, &[]).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Postgres can't time out when waiting for an advisory lock, so we poll for it instead.
+    async fn acquire_postgres_lock(&self, timeout: Duration) -> SqlResult<bool> {
+        let query = format!(
+            "SELECT pg_try_advisory_lock({}, hashtext($1))",
+            POSTGRES_ADVISORY_LOCK_KEY
+        );
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let acquired = self
+                .conn()
+                .query_raw(&query, &[ParameterizedValue::from(self.schema_name())])
+                .await?
+                .first()
+                .as_ref()
+                .and_then(|row| row.at(0))
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false);
+
+            if acquired {
+                return Ok(true);
+            }
+
+            if Instant::now() >= deadline {
+                return Ok(false);
+            }
+
+            tokio::time::delay_for(POSTGRES_LOCK_POLLING_INTERVAL).await;
+        }
+    }
+
+    /// `GET_LOCK` takes the timeout in whole seconds. It is rounded up, so that short timeouts
+    /// don't turn into not waiting at all.
+    async fn acquire_mysql_lock(&self, timeout: Duration) -> SqlResult<bool> {
+        let timeout_seconds = (timeout.as_millis() + 999) / 1000;
+        let result_set = self
+            .conn()
+            .query_raw(
+                "SELECT GET_LOCK(?, ?)",
+                &[
+                    ParameterizedValue::from(self.mysql_lock_name()),
+                    ParameterizedValue::Integer(timeout_seconds as i64),
+                ],
+            )
+            .await?;
+
+        // `GET_LOCK` returns 1 when the lock was acquired, 0 when it timed out and NULL on errors.
+        let acquired = result_set
+            .first()
+            .as_ref()
+            .and_then(|row| row.at(0))
+            .and_then(|value| value.as_i64())
+            == Some(1);
+
+        Ok(acquired)
+    }
+
+    /// In exclusive locking mode, SQLite keeps the lock taken by a write transaction until the
+    /// locking mode is set back to normal. The busy timeout of the connection is only changed
+    /// while waiting for the lock.
+    async fn acquire_sqlite_lock(&self, timeout: Duration) -> SqlResult<bool> {
+        let previous_busy_timeout = self
+            .conn()
+            .query_raw("PRAGMA busy_timeout", &[])
+            .await?
+            .first()
+            .as_ref()
+            .and_then(|row| row.at(0))
+            .and_then(|value| value.as_i64())
+            .unwrap_or(0);
+
+        let busy_timeout = format!("PRAGMA busy_timeout = {}", timeout.as_millis());
+
+        self.conn().query_raw(&busy_timeout, &[]).await?;
+        self.conn().query_raw("PRAGMA locking_mode = EXCLUSIVE", &[]).await?;
+
+        let acquired = match self.conn().query_raw("BEGIN EXCLUSIVE", &[]).await {
+            Ok(_) => {
+                self.conn().query_raw("COMMIT", &[]).await?;
+
+                true
+            }
+            Err(err) => {
+                tracing::debug!("Could not take the SQLite exclusive lock: {}", err);
+                self.conn().query_raw("PRAGMA locking_mode = NORMAL", &[]).await?;
+
+                false
+            }
+        };
+
+        let busy_timeout = format!("PRAGMA busy_timeout = {}", previous_busy_timeout);
+        self.conn().query_raw(&busy_timeout, &[]).await?;
+
+        Ok(acquired)
+    }
+
+    /// Named locks are global to the MySQL server, so the name includes the database name.
+    fn mysql_lock_name(&self) -> String {
+        format!("prisma_migrate_{}", self.schema_name())
+            .chars()
+            .take(MYSQL_LOCK_NAME_MAX_LENGTH)
+            .collect()
+    }
+}
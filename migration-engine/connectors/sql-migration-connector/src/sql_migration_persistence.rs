@@ -1,4 +1,4 @@
-use crate::Component;
+use crate::{Component, SqlResult};
 use barrel::types;
 use chrono::*;
 use migration_connector::*;
@@ -40,6 +40,26 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
 
             self.conn().query_raw(&sql_str, &[]).await.ok();
 
+            // Tables created before checksums were recorded don't have the column yet.
+            if !self.has_checksum_column().await? {
+                let add_checksum_column = match self.sql_family() {
+                    SqlFamily::Mysql => format!(
+                        "ALTER TABLE `{}`.`{}` ADD COLUMN `{}` TEXT",
+                        self.schema_name(),
+                        TABLE_NAME,
+                        CHECKSUM_COLUMN
+                    ),
+                    SqlFamily::Postgres | SqlFamily::Sqlite => format!(
+                        "ALTER TABLE \"{}\".\"{}\" ADD COLUMN \"{}\" TEXT",
+                        self.schema_name(),
+                        TABLE_NAME,
+                        CHECKSUM_COLUMN
+                    ),
+                };
+
+                self.conn().query_raw(&add_checksum_column, &[]).await?;
+            }
+
             Ok(())
         };
 
@@ -115,7 +135,14 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
                 STARTED_AT_COLUMN,
                 self.convert_datetime(migration.started_at),
             )
-            .value(FINISHED_AT_COLUMN, ParameterizedValue::Null);
+            .value(FINISHED_AT_COLUMN, ParameterizedValue::Null)
+            .value(
+                CHECKSUM_COLUMN,
+                migration
+                    .checksum
+                    .map(ParameterizedValue::from)
+                    .unwrap_or(ParameterizedValue::Null),
+            );
 
         match self.sql_family() {
             SqlFamily::Sqlite | SqlFamily::Mysql => {
@@ -144,18 +171,26 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
                 None => ParameterizedValue::Null,
             };
             let errors_json = serde_json::to_string(&params.errors).unwrap();
-            let query = Update::table(self.table())
+            let mut query = Update::table(self.table())
                 .set(NAME_COLUMN, params.new_name.clone())
                 .set(STATUS_COLUMN, params.status.code())
                 .set(APPLIED_COLUMN, params.applied)
                 .set(ROLLED_BACK_COLUMN, params.rolled_back)
                 .set(ERRORS_COLUMN, errors_json)
-                .set(FINISHED_AT_COLUMN, finished_at_value)
-                .so_that(
-                    NAME_COLUMN
-                        .equals(params.name.clone())
-                        .and(REVISION_COLUMN.equals(params.revision)),
+                .set(FINISHED_AT_COLUMN, finished_at_value);
+
+            if let Some(database_migration) = &params.database_migration {
+                query = query.set(
+                    DATABASE_MIGRATION_COLUMN,
+                    serde_json::to_string(database_migration).unwrap(),
                 );
+            }
+
+            let query = query.so_that(
+                NAME_COLUMN
+                    .equals(params.name.clone())
+                    .and(REVISION_COLUMN.equals(params.revision)),
+            );
 
             self.conn().query(query.into()).await?;
 
@@ -163,6 +198,34 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
         })
         .await
     }
+
+    async fn squash(&self, squashed: &[Migration], migration: Migration) -> Result<Migration, ConnectorError> {
+        crate::catch(self.connection_info(), async {
+            let (last, rest) = squashed
+                .split_last()
+                .expect("Squashing requires at least one migration.");
+
+            // The history must not be left with only some of the squashed migrations removed.
+            self.conn().query_raw("BEGIN", &[]).await?;
+
+            if let Err(err) = self.replace_squashed_migrations(rest, last, &migration).await {
+                if let Err(rollback_err) = self.conn().query_raw("ROLLBACK", &[]).await {
+                    tracing::warn!("Rolling back the squash transaction failed: {}", rollback_err);
+                }
+
+                return Err(err);
+            }
+
+            self.conn().query_raw("COMMIT", &[]).await?;
+
+            Ok(Migration {
+                revision: last.revision,
+                checksum: None,
+                ..migration
+            })
+        })
+        .await
+    }
 }
 
 fn migration_table_setup_sqlite(t: &mut barrel::Table) {
@@ -193,6 +256,7 @@ fn migration_table_setup(
     t.add_column(ERRORS_COLUMN, unlimited_text_type.clone());
     t.add_column(STARTED_AT_COLUMN, datetime_type.clone());
     t.add_column(FINISHED_AT_COLUMN, datetime_type.clone().nullable(true));
+    t.add_column(CHECKSUM_COLUMN, types::text().nullable(true));
 }
 
 impl<'a> SqlMigrationPersistence<'a> {
@@ -206,6 +270,36 @@ impl<'a> SqlMigrationPersistence<'a> {
         }
     }
 
+    async fn has_checksum_column(&self) -> SqlResult<bool> {
+        let rows = match self.sql_family() {
+            SqlFamily::Sqlite => {
+                let pragma = format!("PRAGMA \"{}\".table_info(\"{}\")", self.schema_name(), TABLE_NAME);
+
+                self.conn().query_raw(&pragma, &[]).await?
+            }
+            sql_family => {
+                let query = match sql_family {
+                    SqlFamily::Postgres => "SELECT column_name AS name FROM information_schema.columns WHERE table_schema = $1 AND table_name = $2",
+                    _ => "SELECT column_name AS name FROM information_schema.columns WHERE table_schema = ? AND table_name = ?",
+                };
+
+                self.conn()
+                    .query_raw(
+                        query,
+                        &[
+                            ParameterizedValue::from(self.schema_name()),
+                            ParameterizedValue::from(TABLE_NAME),
+                        ],
+                    )
+                    .await?
+            }
+        };
+
+        Ok(rows
+            .into_iter()
+            .any(|row| row.get("name").and_then(|name| name.as_str()) == Some(CHECKSUM_COLUMN)))
+    }
+
     fn convert_datetime(&self, datetime: DateTime<Utc>) -> ParameterizedValue {
         match self.sql_family() {
             SqlFamily::Sqlite => ParameterizedValue::Integer(datetime.timestamp_millis()),
@@ -213,6 +307,49 @@ impl<'a> SqlMigrationPersistence<'a> {
             SqlFamily::Mysql => ParameterizedValue::DateTime(datetime),
         }
     }
+
+    /// Deletes all squashed migrations but the last one, which is replaced by the squashed migration.
+    async fn replace_squashed_migrations(
+        &self,
+        rest: &[Migration],
+        last: &Migration,
+        migration: &Migration,
+    ) -> SqlResult<()> {
+        for squashed_migration in rest {
+            let delete = Delete::from_table(self.table()).so_that(REVISION_COLUMN.equals(squashed_migration.revision));
+
+            self.conn().query(delete.into()).await?;
+        }
+
+        let finished_at_value = match migration.finished_at {
+            Some(x) => self.convert_datetime(x),
+            None => ParameterizedValue::Null,
+        };
+
+        let query = Update::table(self.table())
+            .set(NAME_COLUMN, migration.name.clone())
+            .set(DATAMODEL_COLUMN, migration.datamodel_string.clone())
+            .set(STATUS_COLUMN, migration.status.code())
+            .set(APPLIED_COLUMN, migration.applied)
+            .set(ROLLED_BACK_COLUMN, migration.rolled_back)
+            .set(
+                DATAMODEL_STEPS_COLUMN,
+                serde_json::to_string(&migration.datamodel_steps).unwrap(),
+            )
+            .set(
+                DATABASE_MIGRATION_COLUMN,
+                serde_json::to_string(&migration.database_migration).unwrap(),
+            )
+            .set(ERRORS_COLUMN, serde_json::to_string(&migration.errors).unwrap())
+            .set(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
+            .set(FINISHED_AT_COLUMN, finished_at_value)
+            .set(CHECKSUM_COLUMN, ParameterizedValue::Null)
+            .so_that(REVISION_COLUMN.equals(last.revision));
+
+        self.conn().query(query.into()).await?;
+
+        Ok(())
+    }
 }
 
 fn convert_parameterized_date_value(db_value: &ParameterizedValue) -> DateTime<Utc> {
@@ -248,6 +385,8 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
                 x => Some(convert_parameterized_date_value(x)),
             };
 
+            let checksum = row.get(CHECKSUM_COLUMN).and_then(|value| value.to_string());
+
             let datamodel_steps = serde_json::from_str(&datamodel_steps_json)
                 .expect("Error parsing the migration steps");
 
@@ -267,6 +406,7 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
                 errors,
                 started_at: convert_parameterized_date_value(&row[STARTED_AT_COLUMN]),
                 finished_at,
+                checksum,
             }
         })
         .collect()
@@ -284,3 +424,4 @@ static DATABASE_MIGRATION_COLUMN: &str = "database_migration";
 static ERRORS_COLUMN: &str = "errors";
 static STARTED_AT_COLUMN: &str = "started_at";
 static FINISHED_AT_COLUMN: &str = "finished_at";
+static CHECKSUM_COLUMN: &str = "checksum";
@@ -65,6 +65,16 @@ pub(crate) trait SqlRenderer {
         }
     }
 
+    /// Renders the generation clause of a generated column, in the syntax shared by all supported databases.
+    fn render_generated(&self, generated: &GeneratedColumn) -> String {
+        let kind = match generated.kind {
+            GeneratedColumnKind::Stored => "STORED",
+            GeneratedColumnKind::Virtual => "VIRTUAL",
+        };
+
+        format!("GENERATED ALWAYS AS ({}) {}", generated.expression, kind)
+    }
+
     fn sql_family(&self) -> SqlFamily;
 }
 
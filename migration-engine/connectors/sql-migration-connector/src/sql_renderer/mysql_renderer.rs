@@ -24,18 +24,28 @@ impl super::SqlRenderer for MySqlRenderer {
         let column_name = self.quote(column.name());
         let tpe_str = self.render_column_type(&column).unwrap();
         let nullability_str = render_nullability(&column);
-        let default_str = column
-            .default()
-            .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
-            .unwrap_or_else(String::new);
+        let (generated_str, default_str) = match column.generated() {
+            // Generated columns can't have a default.
+            Some(generated) => (self.render_generated(generated), String::new()),
+            None => (
+                String::new(),
+                column
+                    .default()
+                    .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
+                    .unwrap_or_else(String::new),
+            ),
+        };
         let foreign_key = column.table().foreign_key_for_column(column.name());
         let auto_increment_str = if column.auto_increment() { "AUTO_INCREMENT" } else { "" };
 
         match foreign_key {
-            Some(_) => format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str),
-            None => format!(
+            Some(_) => format!(
                 "{} {} {} {} {}",
-                column_name, tpe_str, nullability_str, default_str, auto_increment_str
+                column_name, tpe_str, generated_str, nullability_str, default_str
+            ),
+            None => format!(
+                "{} {} {} {} {} {}",
+                column_name, tpe_str, generated_str, nullability_str, default_str, auto_increment_str
             ),
         }
     }
@@ -67,6 +77,7 @@ impl MySqlRenderer {
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => Ok(format!("varchar{}", VARCHAR_LENGTH_PREFIX)),
+            ColumnTypeFamily::Json => Ok(format!("json")),
             ColumnTypeFamily::Enum(enum_name) => {
                 let r#enum = column
                     .schema()
@@ -21,25 +21,40 @@ impl super::SqlRenderer for PostgresRenderer {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(column.column_type());
         let nullability_str = render_nullability(&column);
-        let default_str = column
-            .default()
-            .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
-            .unwrap_or_else(String::new);
+        let (generated_str, default_str) = match column.generated() {
+            // Generated columns can't have a default.
+            Some(generated) => (self.render_generated(generated), String::new()),
+            None => (
+                String::new(),
+                column
+                    .default()
+                    .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
+                    .unwrap_or_else(String::new),
+            ),
+        };
         let is_serial = column.auto_increment();
 
         if is_serial {
             format!("{} SERIAL", column_name)
         } else {
-            format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
+            format!(
+                "{} {} {} {} {}",
+                column_name, tpe_str, generated_str, nullability_str, default_str
+            )
         }
     }
 
     fn render_references(&self, schema_name: &str, foreign_key: &ForeignKey) -> String {
         let referenced_columns = foreign_key.referenced_columns.iter().map(quoted).join(",");
+        let referenced_schema = foreign_key
+            .referenced_schema
+            .as_ref()
+            .map(String::as_str)
+            .unwrap_or(schema_name);
 
         format!(
             "REFERENCES {}.{}({}) {}  ON UPDATE CASCADE",
-            quoted(schema_name),
+            quoted(referenced_schema),
             quoted(&foreign_key.referenced_table),
             referenced_columns,
             render_on_delete(&foreign_key.on_delete_action)
@@ -59,6 +74,7 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
         ColumnTypeFamily::String => format!("text {}", array),
+        ColumnTypeFamily::Json => format!("jsonb {}", array),
         ColumnTypeFamily::Enum(name) => format!("{}{}", quoted(name), array),
         x => unimplemented!("{:?} not handled yet", x),
     }
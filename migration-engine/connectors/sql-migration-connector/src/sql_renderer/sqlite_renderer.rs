@@ -22,10 +22,17 @@ impl super::SqlRenderer for SqliteRenderer {
         let column_name = quoted(column.name());
         let tpe_str = self.render_column_type(column.column_type());
         let nullability_str = render_nullability(&column);
-        let default_str = column
-            .default()
-            .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
-            .unwrap_or_else(String::new);
+        let (generated_str, default_str) = match column.generated() {
+            // Generated columns can't have a default.
+            Some(generated) => (self.render_generated(generated), String::new()),
+            None => (
+                String::new(),
+                column
+                    .default()
+                    .map(|default| format!("DEFAULT {}", self.render_default(default, &column.column.tpe.family)))
+                    .unwrap_or_else(String::new),
+            ),
+        };
         let auto_increment_str = if column.auto_increment() {
             "PRIMARY KEY AUTOINCREMENT"
         } else {
@@ -33,8 +40,8 @@ impl super::SqlRenderer for SqliteRenderer {
         };
 
         format!(
-            "{} {} {} {} {}",
-            column_name, tpe_str, nullability_str, default_str, auto_increment_str
+            "{} {} {} {} {} {}",
+            column_name, tpe_str, generated_str, nullability_str, default_str, auto_increment_str
         )
     }
 
@@ -58,6 +65,7 @@ impl SqliteRenderer {
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Json => format!("JSON"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
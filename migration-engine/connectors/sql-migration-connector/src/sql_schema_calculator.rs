@@ -24,6 +24,8 @@ impl<'a> SqlSchemaCalculator<'a> {
     }
 
     fn calculate_internal(&self) -> SqlResult<sql::SqlSchema> {
+        self.check_index_sort_orders()?;
+
         let mut tables = Vec::new();
         let model_tables_without_inline_relations = self.calculate_model_tables()?;
         let mut model_tables = self.add_inline_relations_to_model_tables(model_tables_without_inline_relations)?;
@@ -49,6 +51,31 @@ impl<'a> SqlSchemaCalculator<'a> {
         })
     }
 
+    /// Older MySQL versions parse `DESC` in index definitions, but silently build ascending indexes.
+    fn check_index_sort_orders(&self) -> SqlResult<()> {
+        if self.database_info.supports_descending_indexes() {
+            return Ok(());
+        }
+
+        for model in self.data_model.models() {
+            let has_descending_index = model.indices.iter().any(|index| {
+                index
+                    .sort_orders
+                    .iter()
+                    .any(|sort_order| *sort_order == SortOrder::Desc)
+            });
+
+            if has_descending_index {
+                return Err(SqlError::Generic(anyhow::anyhow!(
+                    "The index on `{model_name}` has a descending sort order, but descending indexes are not supported by this database version.",
+                    model_name = model.name,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     fn calculate_enums(&self) -> Vec<sql::Enum> {
         match self.database_info.sql_family() {
             SqlFamily::Postgres => self
@@ -59,6 +86,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .single_database_name()
                         .map(|s| s.to_owned())
                         .unwrap_or_else(|| r#enum.name.clone()),
+                    schema: None,
                     values: r#enum.database_values(),
                 })
                 .collect(),
@@ -77,6 +105,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             model_name = field.model().database_name(),
                             field_name = field.db_name()
                         ),
+                        schema: None,
                         values: enum_tpe.r#enum.database_values(),
                     };
 
@@ -109,6 +138,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                     _ => false,
                                 }
                             },
+                            generated: generated_column(&f),
                         }),
                         TypeRef::Enum(r#enum) => {
                             let enum_db_name = r#enum.db_name();
@@ -117,8 +147,16 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 tpe: enum_column_type(&f, &self.database_info, enum_db_name),
                                 default: migration_value_new(&f),
                                 auto_increment: false,
+                                generated: generated_column(&f),
                             })
                         }
+                        TypeRef::Composite(_) => Some(sql::Column {
+                            name: f.db_name().to_owned(),
+                            tpe: sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity(f.arity())),
+                            default: None,
+                            auto_increment: false,
+                            generated: None,
+                        }),
                         _ => None,
                     })
                     .collect();
@@ -144,6 +182,10 @@ impl<'a> SqlSchemaCalculator<'a> {
                             name: format!("{}.{}", &model.db_name(), &f.db_name()),
                             columns: f.data_source_fields().iter().map(|f| f.name.clone()).collect(),
                             tpe: sql::IndexType::Unique,
+                            sort_orders: Vec::new(),
+                            expressions: Vec::new(),
+                            predicate: None,
+                            method: None,
                         })
                     } else {
                         None
@@ -157,30 +199,45 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field_name| model.find_field(field_name).expect("Unknown field in index directive."))
                         .collect();
 
-                    sql::Index {
+                    let mut index = sql::Index {
                         name: index_definition.name.clone().unwrap_or_else(|| {
                             format!(
                                 "{}.{}",
                                 &model.db_name(),
-                                referenced_fields.iter().map(|field| field.db_name()).join("_")
+                                referenced_fields.iter().map(|field| field.joined_db_names()).join("_")
                             )
                         }),
-                        // The model index definition uses the model field names, but the SQL Index
-                        // wants the column names.
-                        columns: referenced_fields
-                            .iter()
-                            .flat_map(|field| field.data_source_fields().into_iter().map(|f| f.name.clone()))
-                            .collect(),
+                        columns: Vec::new(),
                         tpe: if index_definition.tpe == IndexType::Unique {
                             sql::IndexType::Unique
                         } else {
                             sql::IndexType::Normal
                         },
+                        sort_orders: Vec::new(),
+                        expressions: Vec::new(),
+                        predicate: None,
+                        method: index_definition.method.and_then(index_method),
+                    };
+
+                    // The model index definition uses the model field names, but the SQL Index
+                    // wants the column names.
+                    for (field_index, field) in referenced_fields.iter().enumerate() {
+                        let sort_order = match index_definition.sort_order(field_index) {
+                            SortOrder::Asc => sql::SortOrder::Asc,
+                            SortOrder::Desc => sql::SortOrder::Desc,
+                        };
+
+                        for data_source_field in field.data_source_fields() {
+                            index.push_column(data_source_field.name.clone(), sort_order);
+                        }
                     }
+
+                    index
                 });
 
                 let table = sql::Table {
                     name: model.database_name().to_owned(),
+                    schema: self.table_schema(model.schema()),
                     columns,
                     indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                     primary_key: Some(primary_key),
@@ -257,6 +314,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                                 tpe: column_type_for_scalar_type(&dsf.field_type, column_arity(dsf.arity)),
                                 default: None,
                                 auto_increment: false,
+                                generated: None,
                             })
                             .collect();
 
@@ -264,6 +322,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: columns.iter().map(|col| col.name.to_owned()).collect(),
                             referenced_table: related_model.db_name().to_owned(),
+                            referenced_schema: self.table_schema(related_model.schema()),
                             referenced_columns: referenced_fields
                                 .iter()
                                 .flat_map(|field| field.data_source_fields().into_iter().map(|f| f.name.clone()))
@@ -310,6 +369,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: a_columns.iter().map(|col| col.name.clone()).collect(),
                             referenced_table: model_a.db_name().to_owned(),
+                            referenced_schema: self.table_schema(model_a.schema()),
                             referenced_columns: first_unique_criterion(model_a)
                                 .map_err(SqlError::Generic)?
                                 .into_iter()
@@ -327,6 +387,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: b_columns.iter().map(|col| col.name.clone()).collect(),
                             referenced_table: model_b.db_name().to_owned(),
+                            referenced_schema: self.table_schema(model_b.schema()),
                             referenced_columns: first_unique_criterion(model_b)
                                 .map_err(SqlError::Generic)?
                                 .into_iter()
@@ -350,16 +411,25 @@ impl<'a> SqlSchemaCalculator<'a> {
                             name: format!("{}_AB_unique", relation.table_name()),
                             columns: columns.iter().map(|col| col.name.clone()).collect(),
                             tpe: sql::IndexType::Unique,
+                            sort_orders: Vec::new(),
+                            expressions: Vec::new(),
+                            predicate: None,
+                            method: None,
                         },
                         sql::Index {
                             name: format!("{}_B_index", relation.table_name()),
                             columns: b_columns.into_iter().map(|col| col.name).collect(),
                             tpe: sql::IndexType::Normal,
+                            sort_orders: Vec::new(),
+                            expressions: Vec::new(),
+                            predicate: None,
+                            method: None,
                         },
                     ];
 
                     let table = sql::Table {
                         name: relation.table_name(),
+                        schema: None,
                         columns,
                         indices: indexes,
                         primary_key: None,
@@ -376,6 +446,14 @@ impl<'a> SqlSchemaCalculator<'a> {
     fn calculate_relations(&self) -> Vec<TempRelationHolder> {
         DatamodelConverter::calculate_relations(&self.data_model)
     }
+
+    /// The describer leaves the tables of the default schema unqualified, so a model placed in the
+    /// default schema with `@@schema` must be too.
+    fn table_schema(&self, model_schema: Option<&str>) -> Option<String> {
+        model_schema
+            .filter(|schema| *schema != self.database_info.connection_info().schema_name())
+            .map(String::from)
+    }
 }
 
 fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name: String) -> Vec<sql::Column> {
@@ -394,6 +472,7 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
             tpe: column_type(&unique_field),
             default: None,
             auto_increment: false,
+            generated: None,
         }]
     } else {
         referenced_model
@@ -407,6 +486,7 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
                 tpe: column_type(&referenced_field),
                 default: None,
                 auto_increment: false,
+                generated: None,
             })
             .collect()
     }
@@ -418,11 +498,34 @@ struct ModelTable {
     model: Model,
 }
 
+fn generated_column(field: &FieldRef<'_>) -> Option<sql::GeneratedColumn> {
+    field.computed().map(|computed| sql::GeneratedColumn {
+        expression: unescape_string_literal(&computed.expression),
+        kind: if computed.stored {
+            sql::GeneratedColumnKind::Stored
+        } else {
+            sql::GeneratedColumnKind::Virtual
+        },
+    })
+}
+
 fn migration_value_new(field: &FieldRef<'_>) -> Option<sql_schema_describer::DefaultValue> {
+    // Generated columns can't have a default value.
+    if field.computed().is_some() {
+        return None;
+    }
+
     let value = match (&field.default_value(), field.arity()) {
         (Some(df), _) => match df {
             dml::DefaultValue::Single(s) => s.clone(),
-            dml::DefaultValue::Expression(_) => default_migration_value(&field.field_type()),
+            dml::DefaultValue::Expression(generator) => match generator.db_generated_expression() {
+                Some(expression) => {
+                    return Some(sql_schema_describer::DefaultValue::DBGENERATED(
+                        unescape_string_literal(expression),
+                    ))
+                }
+                None => default_migration_value(&field.field_type()),
+            },
         },
         // This is a temporary hack until we can report impossible unexecutable migrations.
         (None, FieldArity::Required) => default_migration_value(&field.field_type()),
@@ -469,6 +572,32 @@ fn migration_value_new(field: &FieldRef<'_>) -> Option<sql_schema_describer::Def
     }
 }
 
+/// Datamodel string literals keep their escape sequences, the database must receive the raw expression.
+/// This is the inverse of the escaping done when introspecting the expression.
+fn unescape_string_literal(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some('0') => unescaped.push('\0'),
+            // `\\`, `\"`, `\'` and `\${`.
+            Some(escaped) => unescaped.push(escaped),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
 fn default_migration_value(field_type: &TypeRef<'_>) -> ScalarValue {
     match field_type {
         TypeRef::Base(ScalarType::Boolean) => ScalarValue::Boolean(false),
@@ -543,6 +672,20 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
     }
 }
 
+/// B-tree is the default index method, so it is left implicit.
+fn index_method(method: IndexMethod) -> Option<sql::IndexMethod> {
+    match method {
+        IndexMethod::BTree => None,
+        IndexMethod::Hash => Some(sql::IndexMethod::Hash),
+        IndexMethod::Gist => Some(sql::IndexMethod::Gist),
+        IndexMethod::Gin => Some(sql::IndexMethod::Gin),
+        IndexMethod::Brin => Some(sql::IndexMethod::Brin),
+        IndexMethod::SpGist => Some(sql::IndexMethod::SpGist),
+        IndexMethod::FullText => Some(sql::IndexMethod::FullText),
+        IndexMethod::Spatial => Some(sql::IndexMethod::Spatial),
+    }
+}
+
 fn add_one_to_one_relation_unique_index(table: &mut sql::Table, columns: &Vec<sql::Column>) {
     let column_names: Vec<String> = columns.iter().map(|c| c.name.to_owned()).collect();
     let columns_suffix = column_names.join("_");
@@ -550,6 +693,10 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, columns: &Vec<sq
         name: format!("{}_{}", table.name, columns_suffix),
         columns: column_names,
         tpe: sql::IndexType::Unique,
+        sort_orders: Vec::new(),
+        expressions: Vec::new(),
+        predicate: None,
+        method: None,
     };
 
     table.indices.push(index);
@@ -1,7 +1,7 @@
 use datamodel::{
     dml::{
-        Datamodel, DefaultValue, Enum, Field, FieldArity, FieldType, IndexDefinition, Model,
-        ScalarType, WithDatabaseName,
+        CompositeType, ComputedValue, Datamodel, DefaultValue, Enum, Field, FieldArity, FieldType, IndexDefinition,
+        Model, ScalarType, WithDatabaseName,
     },
     DataSourceField, EnumValue,
 };
@@ -44,6 +44,11 @@ impl<'a> ModelRef<'a> {
             .unwrap_or_else(|| &self.model.name)
     }
 
+    /// The schema the model lives in, if it is not the default schema.
+    pub(super) fn schema(&self) -> Option<&'a str> {
+        self.model.schema.as_ref().map(String::as_str)
+    }
+
     pub(super) fn fields<'b>(&'b self) -> impl Iterator<Item = FieldRef<'a>> + 'b {
         self.model.fields().map(move |field| FieldRef {
             datamodel: self.datamodel,
@@ -122,6 +127,19 @@ impl<'a> FieldRef<'a> {
         self.field.single_database_name().unwrap_or(self.name())
     }
 
+    /// Like `db_name`, for fields that can be mapped to several columns, like relation fields
+    /// with a compound foreign key.
+    pub(super) fn joined_db_names(&self) -> String {
+        match self.field.database_names.len() {
+            0 | 1 => self.db_name().to_owned(),
+            _ => self.field.database_names.join("_"),
+        }
+    }
+
+    pub(super) fn computed(&self) -> Option<&'a ComputedValue> {
+        self.field.computed.as_ref()
+    }
+
     pub(super) fn data_source_fields(&self) -> &[DataSourceField] {
         &self.field.data_source_fields
     }
@@ -137,6 +155,7 @@ impl<'a> FieldRef<'a> {
                 r#enum: self.datamodel.find_enum(name).unwrap(),
             }),
             FieldType::Base(scalar_type, _) => TypeRef::Base(*scalar_type),
+            FieldType::Composite(name) => TypeRef::Composite(self.datamodel.find_composite_type(name).unwrap()),
             _ => TypeRef::Other,
         }
     }
@@ -172,6 +191,7 @@ impl<'a> FieldRef<'a> {
 pub(super) enum TypeRef<'a> {
     Enum(EnumRef<'a>),
     Base(ScalarType),
+    Composite(&'a CompositeType),
     Other,
 }
 
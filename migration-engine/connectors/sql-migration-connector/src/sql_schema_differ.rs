@@ -1,9 +1,10 @@
 mod column;
+mod drift;
 mod enums;
 mod index;
 mod table;
 
-pub(crate) use column::{ColumnChange, ColumnDiffer};
+pub(crate) use column::{ColumnChange, ColumnDiffer, ColumnTypeChange};
 pub(crate) use table::TableDiffer;
 
 use crate::*;
@@ -97,6 +98,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         self.dropped_tables()
             .map(|dropped_table| DropTable {
                 name: dropped_table.name.clone(),
+                schema: dropped_table.schema.clone(),
             })
             .collect()
     }
@@ -114,7 +116,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         // TODO: this does not diff primary key columns yet
         let mut result = Vec::new();
         for previous_table in &self.previous.tables {
-            if let Ok(next_table) = self.next.table(&previous_table.name) {
+            let next_table = self
+                .next
+                .get_table_in_schema(previous_table.schema.as_ref().map(String::as_str), &previous_table.name);
+
+            if let Some(next_table) = next_table {
                 let differ = TableDiffer {
                     previous: &previous_table,
                     next: &next_table,
@@ -201,6 +207,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for index in &table.indices {
                 let create = CreateIndex {
                     table: table.name.clone(),
+                    schema: table.schema.clone(),
                     index: index.clone(),
                 };
 
@@ -212,6 +219,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for index in tables.created_indexes() {
                 let create = CreateIndex {
                     table: tables.next.name.clone(),
+                    schema: tables.next.schema.clone(),
                     index: index.clone(),
                 };
 
@@ -234,6 +242,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 }
                 drop_indexes.push(DropIndex {
                     table: tables.previous.name.clone(),
+                    schema: tables.previous.schema.clone(),
                     name: index.name.clone(),
                 })
             }
@@ -266,6 +275,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     created_variants: differ.created_values().map(String::from).collect(),
                     dropped_variants: differ.dropped_values().map(String::from).collect(),
                     name: differ.previous.name.clone(),
+                    schema: differ.previous.schema.clone(),
                 };
 
                 if step.is_empty() {
@@ -302,6 +312,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     index_name: previous_index.name.clone(),
                     index_new_name: renamed_index.name.clone(),
                     table: differ.next.name.clone(),
+                    schema: differ.next.schema.clone(),
                 })
             })
         });
@@ -310,8 +321,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
     }
 
     fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'a Table> + 'a {
-        self.next_tables()
-            .filter(move |next_table| !self.previous.has_table(&next_table.name))
+        self.next_tables().filter(move |next_table| {
+            !self
+                .previous_tables()
+                .any(|previous_table| tables_match(previous_table, next_table))
+        })
     }
 
     fn dropped_tables(&self) -> impl Iterator<Item = &Table> {
@@ -354,12 +368,13 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(move |previous| !self.next_enums().any(|next| enums_match(previous, next)))
     }
 
+    /// Enums can only be declared in the default schema: the enums of the other schemas are left alone.
     fn previous_enums(&self) -> impl Iterator<Item = &Enum> {
-        self.previous.enums.iter()
+        self.previous.enums.iter().filter(|r#enum| r#enum.schema.is_none())
     }
 
     fn next_enums(&self) -> impl Iterator<Item = &Enum> {
-        self.next.enums.iter()
+        self.next.enums.iter().filter(|r#enum| r#enum.schema.is_none())
     }
 }
 
@@ -370,6 +385,7 @@ fn push_created_foreign_keys<'a, 'schema>(
     table_pairs.for_each(|differ| {
         added_foreign_keys.extend(differ.created_foreign_keys().map(|created_fk| AddForeignKey {
             table: differ.next.name.clone(),
+            schema: differ.next.schema.clone(),
             foreign_key: created_fk.clone(),
         }))
     })
@@ -382,6 +398,7 @@ fn push_foreign_keys_from_created_tables<'a>(
     for table in created_tables {
         steps.extend(table.foreign_keys.iter().map(|fk| AddForeignKey {
             table: table.name.clone(),
+            schema: table.schema.clone(),
             foreign_key: fk.clone(),
         }));
     }
@@ -401,13 +418,14 @@ fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>)
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
     previous.referenced_table == next.referenced_table
+        && previous.referenced_schema == next.referenced_schema
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
         && previous.on_delete_action == next.on_delete_action
 }
 
 fn tables_match(previous: &Table, next: &Table) -> bool {
-    previous.name == next.name
+    previous.name == next.name && previous.schema == next.schema
 }
 
 fn enums_match(previous: &Enum, next: &Enum) -> bool {
@@ -1,4 +1,4 @@
-use sql_schema_describer::{Column, ColumnTypeFamily, DefaultValue};
+use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -42,8 +42,64 @@ impl<'a> ColumnDiffer<'a> {
             None
         };
 
+        let generated = if !self.generated_match() {
+            Some(ColumnChange::Generated)
+        } else {
+            None
+        };
+
         ColumnChanges {
-            changes: [renaming, r#type, arity, default],
+            changes: [renaming, r#type, arity, default, generated],
+        }
+    }
+
+    /// How the values of the column can be converted to the next column type, if the type changed.
+    pub(crate) fn type_change(&self) -> Option<ColumnTypeChange> {
+        use ColumnTypeFamily::*;
+
+        if self.previous.tpe.family == self.next.tpe.family {
+            return None;
+        }
+
+        // Values can't be cast between lists and scalars.
+        if (self.previous.tpe.arity == ColumnArity::List) != (self.next.tpe.arity == ColumnArity::List) {
+            return Some(ColumnTypeChange::NotCastable);
+        }
+
+        let change = match (&self.previous.tpe.family, &self.next.tpe.family) {
+            (Int, Float) | (Boolean, Int) => ColumnTypeChange::SafeCast,
+            (Int, String)
+            | (Float, String)
+            | (Boolean, String)
+            | (DateTime, String)
+            | (Json, String)
+            | (Uuid, String)
+            | (Enum(_), String) => ColumnTypeChange::SafeCast,
+            (Float, Int)
+            | (Int, Boolean)
+            | (String, Int)
+            | (String, Float)
+            | (String, Boolean)
+            | (String, DateTime)
+            | (String, Json)
+            | (String, Uuid)
+            | (String, Enum(_))
+            | (Enum(_), Enum(_)) => ColumnTypeChange::RiskyCast,
+            _ => ColumnTypeChange::NotCastable,
+        };
+
+        Some(change)
+    }
+
+    /// Generation expressions are compared like database-generated defaults.
+    fn generated_match(&self) -> bool {
+        match (&self.previous.generated, &self.next.generated) {
+            (None, None) => true,
+            (Some(previous), Some(next)) => {
+                previous.kind == next.kind
+                    && normalize_db_generated(&previous.expression) == normalize_db_generated(&next.expression)
+            }
+            _ => false,
         }
     }
 
@@ -54,11 +110,21 @@ impl<'a> ColumnDiffer<'a> {
     /// - Postgres autoincrement fields get inferred with a default, which we want to ignore.
     ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified, like strings containing escaped characters.
+    ///
+    /// - Database-generated defaults are compared as normalized SQL expressions, whatever the column type.
     fn defaults_match(&self) -> bool {
         if self.previous.auto_increment {
             return true;
         }
 
+        match (&self.previous.default, &self.next.default) {
+            (Some(DefaultValue::DBGENERATED(previous)), Some(DefaultValue::DBGENERATED(next))) => {
+                return normalize_db_generated(previous) == normalize_db_generated(next)
+            }
+            (Some(DefaultValue::DBGENERATED(_)), None) | (None, Some(DefaultValue::DBGENERATED(_))) => return false,
+            _ => (),
+        }
+
         let previous_value: Option<&str> = self
             .previous
             .default
@@ -85,6 +151,57 @@ fn expand_default_value(default_value: &DefaultValue) -> Option<&str> {
     }
 }
 
+/// Databases echo expressions back with varying whitespace, casing, identifier quoting and wrapping parentheses.
+fn normalize_db_generated(expr: &str) -> String {
+    let mut expr = expr.trim();
+
+    while expr.starts_with('(') && expr.ends_with(')') && parentheses_are_balanced(&expr[1..expr.len() - 1]) {
+        expr = expr[1..expr.len() - 1].trim();
+    }
+
+    let mut normalized = String::with_capacity(expr.len());
+    let mut in_string_literal = false;
+
+    for word in expr.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+
+        for c in word.chars() {
+            if c == '\'' {
+                in_string_literal = !in_string_literal;
+            }
+
+            match (in_string_literal, c) {
+                (true, _) => normalized.push(c),
+                // Identifier quotes, as MySQL adds them to the expressions it echoes back.
+                (false, '`') | (false, '"') => (),
+                (false, _) => normalized.extend(c.to_lowercase()),
+            }
+        }
+    }
+
+    normalized
+}
+
+fn parentheses_are_balanced(s: &str) -> bool {
+    let mut depth: i32 = 0;
+
+    for c in s.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => (),
+        }
+
+        if depth < 0 {
+            return false;
+        }
+    }
+
+    depth == 0
+}
+
 fn float_default(s: Option<&str>) -> Option<f64> {
     s.and_then(|s| s.parse().ok())
 }
@@ -126,11 +243,23 @@ pub(crate) enum ColumnChange {
     Arity,
     Default,
     Type,
+    Generated,
+}
+
+/// The ways the values of a column can be converted when its type changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ColumnTypeChange {
+    /// Every value has a representation in the new type.
+    SafeCast,
+    /// The conversion fails or loses information for some values.
+    RiskyCast,
+    /// The values can't be converted, the column has to be recreated.
+    NotCastable,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct ColumnChanges {
-    changes: [Option<ColumnChange>; 4],
+    changes: [Option<ColumnChange>; 5],
 }
 
 impl ColumnChanges {
@@ -140,12 +269,6 @@ impl ColumnChanges {
             .filter_map(|c| c.as_ref().map(|c| c.clone()))
     }
 
-    pub(crate) fn type_changed(&self) -> bool {
-        self.changes
-            .iter()
-            .any(|c| c.as_ref() == Some(&ColumnChange::Type))
-    }
-
     pub(crate) fn arity_changed(&self) -> bool {
         self.changes
             .iter()
@@ -165,6 +288,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
             default: Some(DefaultValue::VALUE("abc".to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         let col_b = Column {
@@ -172,6 +296,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
             default: Some(DefaultValue::VALUE(r##""abc""##.to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         let col_c = Column {
@@ -179,6 +304,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required),
             default: Some(DefaultValue::VALUE(r##"'abc'"##.to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         assert!(ColumnDiffer {
@@ -207,6 +333,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
             default: Some(DefaultValue::VALUE("2019-09-01T08:00:00Z".to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         let col_b = Column {
@@ -214,13 +341,47 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
             default: Some(DefaultValue::VALUE("2019-09-01 18:00:00 UTC".to_owned())),
             auto_increment: false,
+            generated: None,
+        };
+
+        assert!(ColumnDiffer {
+            previous: &col_a,
+            next: &col_b,
+        }
+        .defaults_match());
+    }
+
+    #[test]
+    fn db_generated_defaults_match() {
+        let column = |default: &str| Column {
+            name: "A".to_owned(),
+            tpe: ColumnType::pure(ColumnTypeFamily::DateTime, ColumnArity::Required),
+            default: Some(DefaultValue::DBGENERATED(default.to_owned())),
+            auto_increment: false,
+            generated: None,
         };
 
+        let col_a = column("CURRENT_DATE");
+        let col_b = column("(current_date)");
+        let col_c = column("uuid_generate_v4()");
+
         assert!(ColumnDiffer {
             previous: &col_a,
             next: &col_b,
         }
         .defaults_match());
+
+        assert!(!ColumnDiffer {
+            previous: &col_a,
+            next: &col_c,
+        }
+        .defaults_match());
+
+        assert!(!ColumnDiffer {
+            previous: &column("(a) + (b)"),
+            next: &column("a) + (b"),
+        }
+        .defaults_match());
     }
 
     #[test]
@@ -230,6 +391,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
             default: Some(DefaultValue::VALUE("0.33".to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         let col_b = Column {
@@ -237,6 +399,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
             default: Some(DefaultValue::VALUE("0.33000".to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         assert!(ColumnDiffer {
@@ -250,6 +413,7 @@ mod tests {
             tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required),
             default: Some(DefaultValue::VALUE("0.34".to_owned())),
             auto_increment: false,
+            generated: None,
         };
 
         assert!(!ColumnDiffer {
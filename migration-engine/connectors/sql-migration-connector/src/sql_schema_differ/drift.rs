@@ -0,0 +1,112 @@
+use super::{column::ColumnChange, index::index_covers_fk, SqlSchemaDiffer, TableDiffer};
+use migration_connector::{
+    ColumnDrift, ColumnDriftKind, DatabaseDrift, EnumDrift, ForeignKeyDrift, IndexDrift, TableDrift,
+};
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::{ForeignKey, Index, SqlSchema, Table};
+
+impl SqlSchemaDiffer<'_> {
+    /// Describes how the `actual` schema of the database differs from the `expected` one.
+    pub(crate) fn drift(expected: &SqlSchema, actual: &SqlSchema, sql_family: SqlFamily) -> DatabaseDrift {
+        let differ = SqlSchemaDiffer {
+            previous: expected,
+            next: actual,
+            sql_family,
+        };
+
+        DatabaseDrift {
+            added_tables: differ.created_tables().map(qualified_name).collect(),
+            removed_tables: differ.dropped_tables().map(qualified_name).collect(),
+            changed_tables: differ
+                .table_pairs()
+                .map(|tables| table_drift(&tables, sql_family))
+                .filter(|drift| !drift.is_empty())
+                .collect(),
+            added_enums: differ.created_enums().map(|r#enum| r#enum.name.clone()).collect(),
+            removed_enums: differ.dropped_enums().map(|r#enum| r#enum.name.clone()).collect(),
+            changed_enums: differ
+                .enum_pairs()
+                .map(|enums| EnumDrift {
+                    r#enum: enums.previous.name.clone(),
+                    added_values: enums.created_values().map(String::from).collect(),
+                    removed_values: enums.dropped_values().map(String::from).collect(),
+                })
+                .filter(|drift| !drift.added_values.is_empty() || !drift.removed_values.is_empty())
+                .collect(),
+        }
+    }
+}
+
+fn table_drift(tables: &TableDiffer<'_>, sql_family: SqlFamily) -> TableDrift {
+    let mut drift = TableDrift::new(qualified_name(tables.next));
+
+    drift.added_columns = tables.added_columns().map(|column| column.name.clone()).collect();
+    drift.removed_columns = tables.dropped_columns().map(|column| column.name.clone()).collect();
+    drift.changed_columns = tables
+        .column_pairs()
+        .filter_map(|columns| {
+            let changes: Vec<ColumnDriftKind> = columns
+                .all_changes()
+                .iter()
+                .filter_map(|change| match change {
+                    ColumnChange::Type => Some(ColumnDriftKind::Type),
+                    ColumnChange::Arity => Some(ColumnDriftKind::Arity),
+                    ColumnChange::Default => Some(ColumnDriftKind::Default),
+                    ColumnChange::Generated => Some(ColumnDriftKind::Generated),
+                    // Columns are paired by name.
+                    ColumnChange::Renaming => None,
+                })
+                .collect();
+
+            if changes.is_empty() {
+                None
+            } else {
+                Some(ColumnDrift {
+                    column: columns.name().to_owned(),
+                    changes,
+                })
+            }
+        })
+        .collect();
+
+    // On MySQL, foreign keys come with an index the migrations don't create explicitly.
+    drift.added_indexes = tables
+        .created_indexes()
+        .filter(|index| !(sql_family.is_mysql() && index_covers_fk(tables.next, index)))
+        .map(index_drift)
+        .collect();
+    drift.removed_indexes = tables
+        .dropped_indexes()
+        .filter(|index| !(sql_family.is_mysql() && index_covers_fk(tables.previous, index)))
+        .map(index_drift)
+        .collect();
+
+    drift.added_foreign_keys = tables.created_foreign_keys().map(foreign_key_drift).collect();
+    drift.removed_foreign_keys = tables.dropped_foreign_keys().map(foreign_key_drift).collect();
+
+    drift
+}
+
+fn qualified_name(table: &Table) -> String {
+    match &table.schema {
+        Some(schema) => format!("{}.{}", schema, table.name),
+        None => table.name.clone(),
+    }
+}
+
+fn index_drift(index: &Index) -> IndexDrift {
+    IndexDrift {
+        name: index.name.clone(),
+        columns: index.columns.clone(),
+        unique: index.tpe.is_unique(),
+    }
+}
+
+fn foreign_key_drift(foreign_key: &ForeignKey) -> ForeignKeyDrift {
+    ForeignKeyDrift {
+        constraint_name: foreign_key.constraint_name.clone(),
+        columns: foreign_key.columns.clone(),
+        referenced_table: foreign_key.referenced_table.clone(),
+        referenced_columns: foreign_key.referenced_columns.clone(),
+    }
+}
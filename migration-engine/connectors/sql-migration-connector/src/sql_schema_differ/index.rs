@@ -3,3 +3,18 @@ use sql_schema_describer::*;
 pub(super) fn index_covers_fk(table: &Table, index: &Index) -> bool {
     table.foreign_keys.iter().any(|fk| fk.columns == index.columns)
 }
+
+/// Compare two indexes and return whether they should be considered equivalent for schema diffing
+/// purposes. The name is not taken into account, renamed indexes are detected separately.
+pub(super) fn indexes_match(first: &Index, second: &Index) -> bool {
+    first.columns == second.columns
+        && first.tpe == second.tpe
+        && sort_orders_match(first, second)
+        && first.expressions == second.expressions
+        && first.predicate == second.predicate
+        && first.method == second.method
+}
+
+fn sort_orders_match(first: &Index, second: &Index) -> bool {
+    (0..first.columns.len()).all(|column_index| first.sort_order(column_index) == second.sort_order(column_index))
+}
@@ -1,4 +1,4 @@
-use super::column::ColumnDiffer;
+use super::{column::ColumnDiffer, index::indexes_match};
 use sql_schema_describer::{Column, ForeignKey, Index, Table};
 
 pub(crate) struct TableDiffer<'schema> {
@@ -57,11 +57,13 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// Expression and partial indexes can not be represented in the datamodel, so they are left alone.
     pub(crate) fn dropped_indexes<'a>(&'a self) -> impl Iterator<Item = &'schema Index> + 'a {
         self.previous_indexes().filter(move |previous_index| {
-            !self
-                .next_indexes()
-                .any(|next_index| indexes_match(previous_index, next_index))
+            previous_index.is_plain()
+                && !self
+                    .next_indexes()
+                    .any(|next_index| indexes_match(previous_index, next_index))
         })
     }
 
@@ -101,8 +103,3 @@ impl<'schema> TableDiffer<'schema> {
 fn columns_match(a: &Column, b: &Column) -> bool {
     a.name == b.name
 }
-
-/// Compare two SQL indexes and return whether they only differ by name.
-fn indexes_match(first: &Index, second: &Index) -> bool {
-    first.columns == second.columns && first.tpe == second.tpe
-}
@@ -1,4 +1,4 @@
-use sql_schema_describer::{Column, ColumnType, DefaultValue, ForeignKey, SqlSchema, Table};
+use sql_schema_describer::{Column, ColumnType, DefaultValue, ForeignKey, GeneratedColumn, SqlSchema, Table};
 
 pub(crate) fn walk_columns<'a>(schema: &'a SqlSchema) -> impl Iterator<Item = ColumnRef<'a>> + 'a {
     schema.tables.iter().flat_map(move |table| {
@@ -9,6 +9,7 @@ pub(crate) fn walk_columns<'a>(schema: &'a SqlSchema) -> impl Iterator<Item = Co
     })
 }
 
+#[derive(Clone, Copy)]
 pub(crate) struct ColumnRef<'a> {
     pub(crate) schema: &'a SqlSchema,
     pub(crate) column: &'a Column,
@@ -28,6 +29,10 @@ impl<'a> ColumnRef<'a> {
         &self.column.tpe
     }
 
+    pub(crate) fn generated(&self) -> Option<&'a GeneratedColumn> {
+        self.column.generated.as_ref()
+    }
+
     pub(crate) fn auto_increment(&self) -> bool {
         self.column.auto_increment
     }
@@ -58,6 +63,11 @@ impl<'a> TableRef<'a> {
         &self.table.name
     }
 
+    /// The schema of the table, when it is not the default schema of the connection.
+    pub(crate) fn schema(&self) -> &'a Option<String> {
+        &self.table.schema
+    }
+
     pub(crate) fn foreign_key_for_column(&self, column: &str) -> Option<&'a ForeignKey> {
         self.table.foreign_key_for_column(column)
     }
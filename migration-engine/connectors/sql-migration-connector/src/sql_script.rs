@@ -0,0 +1,267 @@
+use quaint::prelude::SqlFamily;
+
+/// Splits a migration script into statements, on the semicolons outside of string literals, quoted
+/// identifiers and comments. Statements containing nothing but whitespace and comments are
+/// skipped.
+///
+/// Semicolons in the `BEGIN ... END` body of a `CREATE TRIGGER`, `CREATE PROCEDURE` or `CREATE
+/// FUNCTION` statement do not end the statement. On MySQL, the `DELIMITER` command of the mysql
+/// client is understood as well.
+pub(crate) fn split_statements(script: &str, sql_family: SqlFamily) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let mut statement_start = 0;
+    let mut has_content = false;
+    let mut is_create_statement = false;
+    let mut block_depth: usize = 0;
+    let mut delimiter = ";";
+    let mut chars = script.char_indices().peekable();
+
+    while let Some((idx, c)) = chars.next() {
+        if block_depth == 0 && script[idx..].starts_with(delimiter) {
+            if has_content {
+                statements.push(script[statement_start..idx].trim());
+            }
+
+            statement_start = idx + delimiter.len();
+            skip_to(&mut chars, statement_start);
+            has_content = false;
+            is_create_statement = false;
+            continue;
+        }
+
+        match c {
+            '-' if next_is(&mut chars, '-') => {
+                skip_until(&mut chars, |c, _| c == '\n');
+                continue;
+            }
+            '/' if next_is(&mut chars, '*') => {
+                chars.next();
+                let mut previous = ' ';
+                skip_until(&mut chars, |c, _| {
+                    let end_of_comment = previous == '*' && c == '/';
+                    previous = c;
+                    end_of_comment
+                });
+                continue;
+            }
+            '\'' | '"' | '`' => skip_quoted(&mut chars, c, sql_family == SqlFamily::Mysql),
+            '$' if sql_family == SqlFamily::Postgres => {
+                if let Some(tag) = dollar_quote_tag(&script[idx..]) {
+                    let body_start = idx + tag.len();
+                    let body_end = script[body_start..]
+                        .find(tag)
+                        .map(|end| body_start + end + tag.len())
+                        .unwrap_or_else(|| script.len());
+
+                    skip_until(&mut chars, |_, idx| idx + 1 >= body_end);
+                }
+            }
+            c if c.is_whitespace() => continue,
+            c if is_word_char(c) => {
+                let word_end = script[idx..]
+                    .find(|c: char| !is_word_char(c))
+                    .map(|end| idx + end)
+                    .unwrap_or_else(|| script.len());
+                let word = &script[idx..word_end];
+
+                skip_to(&mut chars, word_end);
+
+                if !has_content && sql_family == SqlFamily::Mysql && word.eq_ignore_ascii_case("DELIMITER") {
+                    let line_end = script[word_end..]
+                        .find('\n')
+                        .map(|end| word_end + end)
+                        .unwrap_or_else(|| script.len());
+
+                    delimiter = script[word_end..line_end].trim();
+                    skip_to(&mut chars, line_end);
+                    statement_start = line_end;
+                    continue;
+                }
+
+                if !has_content {
+                    is_create_statement = word.eq_ignore_ascii_case("CREATE");
+                } else if is_create_statement {
+                    if word.eq_ignore_ascii_case("BEGIN") || word.eq_ignore_ascii_case("CASE") {
+                        block_depth += 1;
+                    } else if word.eq_ignore_ascii_case("END") && !closes_control_flow(&script[word_end..]) {
+                        block_depth = block_depth.saturating_sub(1);
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        has_content = true;
+    }
+
+    if has_content {
+        statements.push(script[statement_start..].trim());
+    }
+
+    statements
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Whether the word after an `END` is one of the MySQL control flow statements, e.g. `END IF`,
+/// which do not close a `BEGIN` or `CASE`.
+fn closes_control_flow(rest: &str) -> bool {
+    let rest = rest.trim_start();
+    let next_word = &rest[..rest.find(|c: char| !is_word_char(c)).unwrap_or_else(|| rest.len())];
+
+    ["IF", "LOOP", "WHILE", "REPEAT"]
+        .iter()
+        .any(|keyword| next_word.eq_ignore_ascii_case(keyword))
+}
+
+/// Advances to the character at the given byte offset, without consuming it.
+fn skip_to(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>, offset: usize) {
+    while chars.peek().map(|(idx, _)| *idx < offset).unwrap_or(false) {
+        chars.next();
+    }
+}
+
+fn next_is(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>, expected: char) -> bool {
+    chars.peek().map(|(_, c)| *c == expected).unwrap_or(false)
+}
+
+/// Advances until and including the first character matching the predicate.
+fn skip_until(chars: &mut impl Iterator<Item = (usize, char)>, mut predicate: impl FnMut(char, usize) -> bool) {
+    for (idx, c) in chars {
+        if predicate(c, idx) {
+            return;
+        }
+    }
+}
+
+/// Advances past the closing quote. A doubled quote is read as a closing and an opening quote,
+/// which does not make a difference here.
+fn skip_quoted(chars: &mut impl Iterator<Item = (usize, char)>, quote: char, backslash_escapes: bool) {
+    while let Some((_, c)) = chars.next() {
+        if c == quote {
+            return;
+        }
+
+        if backslash_escapes && c == '\\' && quote != '`' {
+            chars.next();
+        }
+    }
+}
+
+/// The opening tag of a Postgres dollar-quoted string, e.g. `$$` or `$body$`.
+fn dollar_quote_tag(input: &str) -> Option<&str> {
+    let tag_end = input[1..].find('$')? + 2;
+    let tag = &input[..tag_end];
+
+    if tag[1..tag_end - 1].chars().all(|c| c.is_alphanumeric() || c == '_') {
+        Some(tag)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_statements_splits_on_semicolons() {
+        let script = "CREATE TABLE \"A\" (id INTEGER);\n\nCREATE TABLE \"B\" (id INTEGER);\n";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Postgres),
+            vec!["CREATE TABLE \"A\" (id INTEGER)", "CREATE TABLE \"B\" (id INTEGER)"]
+        );
+    }
+
+    #[test]
+    fn split_statements_ignores_semicolons_in_quotes_and_comments() {
+        let script = "-- create a; b\nINSERT INTO \"a;b\" VALUES ('x;y', 'it''s;');\n/* ; */ SELECT 1";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Sqlite),
+            vec![
+                "-- create a; b\nINSERT INTO \"a;b\" VALUES ('x;y', 'it''s;')",
+                "/* ; */ SELECT 1"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_skips_statements_without_content() {
+        let script = ";\n-- nothing to see here;\n  ;SELECT 1;\n-- the end\n";
+
+        assert_eq!(split_statements(script, SqlFamily::Mysql), vec!["SELECT 1"]);
+    }
+
+    #[test]
+    fn split_statements_handles_backslash_escapes_on_mysql() {
+        let script = "INSERT INTO `a` VALUES ('it\\'s;');SELECT 1;";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Mysql),
+            vec!["INSERT INTO `a` VALUES ('it\\'s;')", "SELECT 1"]
+        );
+    }
+
+    #[test]
+    fn split_statements_handles_dollar_quoted_strings_on_postgres() {
+        let script = "CREATE FUNCTION f() RETURNS INTEGER AS $body$ SELECT 1; $body$ LANGUAGE SQL;\nSELECT $1;";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Postgres),
+            vec![
+                "CREATE FUNCTION f() RETURNS INTEGER AS $body$ SELECT 1; $body$ LANGUAGE SQL",
+                "SELECT $1"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_keeps_sqlite_trigger_bodies_together() {
+        let script = "CREATE TRIGGER \"t\" AFTER INSERT ON \"A\" BEGIN\n    UPDATE \"B\" SET n = n + 1;\n    DELETE FROM \"C\";\nEND;\nSELECT 1;";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Sqlite),
+            vec![
+                "CREATE TRIGGER \"t\" AFTER INSERT ON \"A\" BEGIN\n    UPDATE \"B\" SET n = n + 1;\n    DELETE FROM \"C\";\nEND",
+                "SELECT 1"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_does_not_mistake_transactions_for_blocks() {
+        let script = "BEGIN;\nUPDATE \"A\" SET b = CASE WHEN b THEN 0 ELSE 1 END;\nCOMMIT;";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Sqlite),
+            vec!["BEGIN", "UPDATE \"A\" SET b = CASE WHEN b THEN 0 ELSE 1 END", "COMMIT"]
+        );
+    }
+
+    #[test]
+    fn split_statements_keeps_mysql_procedure_bodies_together() {
+        let script = "CREATE PROCEDURE p() BEGIN\n  IF 1 THEN SELECT 1; END IF;\n  SELECT 2;\nEND;\nCALL p();";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Mysql),
+            vec![
+                "CREATE PROCEDURE p() BEGIN\n  IF 1 THEN SELECT 1; END IF;\n  SELECT 2;\nEND",
+                "CALL p()"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_understands_the_mysql_delimiter_command() {
+        let script = "DELIMITER $$\nCREATE PROCEDURE p() SELECT 1; $$\nDELIMITER ;\nCALL p();";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Mysql),
+            vec!["CREATE PROCEDURE p() SELECT 1;", "CALL p()"]
+        );
+    }
+}
@@ -1,19 +1,26 @@
 mod error_rendering;
 mod rpc;
 
-pub use error_rendering::{pretty_print_datamodel_errors, render_error};
+pub use error_rendering::{pretty_print_datamodel_errors, pretty_print_datamodel_errors_in_files, render_error};
 pub use rpc::*;
 
 use crate::{commands::*, migration_engine::MigrationEngine, CoreResult};
+use futures::FutureExt;
 use migration_connector::*;
+use std::{panic::AssertUnwindSafe, time::Duration};
 use tracing_futures::Instrument;
 
+/// How long commands changing the database wait for another migration engine to release the
+/// migration lock, unless configured otherwise.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
 pub struct MigrationApi<C, D>
 where
     C: MigrationConnector<DatabaseMigration = D>,
     D: DatabaseMigrationMarker + 'static,
 {
     engine: MigrationEngine<C, D>,
+    lock_timeout: Duration,
 }
 
 impl<C, D> MigrationApi<C, D>
@@ -24,7 +31,22 @@ where
     pub async fn new(connector: C) -> CoreResult<Self> {
         let engine = MigrationEngine::new(connector).await?;
 
-        Ok(Self { engine })
+        Ok(Self {
+            engine,
+            lock_timeout: DEFAULT_LOCK_TIMEOUT,
+        })
+    }
+
+    /// Validates the migration history against the given shadow database before inferring migrations.
+    pub fn with_shadow_database(mut self, shadow_database: C) -> Self {
+        self.engine.set_shadow_database(shadow_database);
+        self
+    }
+
+    /// Sets how long commands changing the database wait for the migration lock.
+    pub fn with_lock_timeout(mut self, lock_timeout: Duration) -> Self {
+        self.lock_timeout = lock_timeout;
+        self
     }
 
     pub async fn handle_command<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
@@ -34,6 +56,25 @@ where
         Ok(E::execute(input, &self.engine).await?)
     }
 
+    /// Like `handle_command`, but holds the migration lock on the database while the command runs,
+    /// so that concurrent migration engines don't change the database at the same time.
+    pub async fn handle_locked_command<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
+    where
+        E: MigrationCommand,
+    {
+        let lock = MigrationLock::acquire(self.engine.connector(), self.lock_timeout).await?;
+
+        // The lock is released before a panic in the command is resumed.
+        let result = AssertUnwindSafe(E::execute(input, &self.engine)).catch_unwind().await;
+
+        lock.release().await;
+
+        match result {
+            Ok(result) => Ok(result?),
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+
     pub fn connector(&self) -> &C {
         self.engine.connector()
     }
@@ -44,15 +85,21 @@ where
 #[async_trait::async_trait]
 pub trait GenericApi: Send + Sync + 'static {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput>;
+    async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput>;
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
     ) -> CoreResult<MigrationStepsResultOutput>;
     async fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> CoreResult<CalculateDatamodelOutput>;
+    async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
+    async fn diagnose_migration_history(&self, input: &serde_json::Value)
+        -> CoreResult<DiagnoseMigrationHistoryOutput>;
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a>;
     fn connector_type(&self) -> &'static str;
@@ -73,7 +120,7 @@ where
     D: DatabaseMigrationMarker + Send + Sync + 'static,
 {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput> {
-        self.handle_command::<ApplyMigrationCommand>(input)
+        self.handle_locked_command::<ApplyMigrationCommand>(input)
             .instrument(tracing::info_span!(
                 "ApplyMigration",
                 migration_id = input.migration_id.as_str()
@@ -81,6 +128,21 @@ where
             .await
     }
 
+    async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput> {
+        self.handle_locked_command::<ApplyMigrationsCommand>(input)
+            .instrument(tracing::info_span!("ApplyMigrations"))
+            .await
+    }
+
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput> {
+        self.handle_locked_command::<BaselineCommand>(input)
+            .instrument(tracing::info_span!(
+                "Baseline",
+                migration_id = input.migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -96,6 +158,24 @@ where
             .await
     }
 
+    async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput> {
+        self.handle_command::<CreateMigrationCommand>(input)
+            .instrument(tracing::info_span!(
+                "CreateMigration",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
+    async fn diagnose_migration_history(
+        &self,
+        input: &serde_json::Value,
+    ) -> CoreResult<DiagnoseMigrationHistoryOutput> {
+        self.handle_command::<DiagnoseMigrationHistoryCommand>(input)
+            .instrument(tracing::info_span!("DiagnoseMigrationHistory"))
+            .await
+    }
+
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput> {
         self.handle_command::<InferMigrationStepsCommand>(input)
             .instrument(tracing::info_span!(
@@ -121,13 +201,22 @@ where
     }
 
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value> {
-        self.handle_command::<ResetCommand>(input)
+        self.handle_locked_command::<ResetCommand>(input)
             .instrument(tracing::info_span!("Reset"))
             .await
     }
 
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_locked_command::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                migration_id = input.migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
-        self.handle_command::<UnapplyMigrationCommand>(input)
+        self.handle_locked_command::<UnapplyMigrationCommand>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
             .await
     }
@@ -140,3 +229,28 @@ where
         self.engine.connector().connector_type()
     }
 }
+
+/// The migration lock held by a command. It has to be released explicitly, since releasing it
+/// talks to the database.
+struct MigrationLock<'a, C> {
+    connector: &'a C,
+}
+
+impl<'a, C> MigrationLock<'a, C>
+where
+    C: MigrationConnector,
+{
+    async fn acquire(connector: &'a C, timeout: Duration) -> ConnectorResult<MigrationLock<'a, C>> {
+        connector.acquire_lock(timeout).await?;
+
+        Ok(MigrationLock { connector })
+    }
+
+    /// A failure to release the lock doesn't change the outcome of the command: the lock goes away
+    /// with the connection at the latest.
+    async fn release(self) {
+        if let Err(err) = self.connector.release_lock().await {
+            tracing::warn!("Releasing the migration lock failed: {}", err);
+        }
+    }
+}
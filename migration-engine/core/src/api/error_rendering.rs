@@ -54,17 +54,25 @@ pub fn pretty_print_datamodel_errors(
     errors: &datamodel::error::ErrorCollection,
     datamodel: &str,
 ) -> std::io::Result<String> {
-    use std::io::Write as _;
+    pretty_print_datamodel_errors_in_files(errors, &[datamodel::SourceFile::new("schema.prisma", datamodel)])
+}
 
-    let file_name = "schema.prisma";
+/// Like `pretty_print_datamodel_errors`, for a schema split across several files.
+pub fn pretty_print_datamodel_errors_in_files(
+    errors: &datamodel::error::ErrorCollection,
+    files: &[datamodel::SourceFile],
+) -> std::io::Result<String> {
+    use std::io::Write as _;
 
     let mut message: Vec<u8> = Vec::new();
 
     for error in errors.to_iter() {
-        writeln!(&mut message)?;
-        error
-            .pretty_print(&mut message, file_name, datamodel)
-            .expect("Failed to write errors to stderr");
+        if let Some(file) = files.get(error.span().file_id.0) {
+            writeln!(&mut message)?;
+            error
+                .pretty_print(&mut message, &file.name, &file.contents)
+                .expect("Failed to write errors to stderr");
+        }
     }
 
     Ok(String::from_utf8_lossy(&message).into_owned())
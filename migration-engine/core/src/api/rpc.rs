@@ -1,5 +1,6 @@
 use super::GenericApi;
 use crate::{commands::*, CoreResult};
+use datamodel::SourceFile;
 use futures::{FutureExt, TryFutureExt};
 use jsonrpc_core::types::error::Error as JsonRpcError;
 use jsonrpc_core::{IoHandler, Params};
@@ -21,6 +22,11 @@ enum RpcCommand {
     Reset,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    CreateMigration,
+    ApplyMigrations,
+    DiagnoseMigrationHistory,
+    Baseline,
+    SquashMigrations,
 }
 
 impl RpcCommand {
@@ -34,6 +40,11 @@ impl RpcCommand {
             RpcCommand::Reset => "reset",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::CreateMigration => "createMigration",
+            RpcCommand::ApplyMigrations => "applyMigrations",
+            RpcCommand::DiagnoseMigrationHistory => "diagnoseMigrationHistory",
+            RpcCommand::Baseline => "baseline",
+            RpcCommand::SquashMigrations => "squashMigrations",
         }
     }
 }
@@ -47,13 +58,23 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::Reset,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::CreateMigration,
+    RpcCommand::ApplyMigrations,
+    RpcCommand::DiagnoseMigrationHistory,
+    RpcCommand::Baseline,
+    RpcCommand::SquashMigrations,
 ];
 
 impl RpcApi {
     pub async fn new(datamodel: &str) -> CoreResult<Self> {
+        Self::new_from_files(&[SourceFile::new("schema.prisma", datamodel)]).await
+    }
+
+    /// Starts the API for a schema split across several files.
+    pub async fn new_from_files(files: &[SourceFile]) -> CoreResult<Self> {
         let mut rpc_api = Self {
             io_handler: IoHandler::default(),
-            executor: crate::migration_api(datamodel).await?,
+            executor: crate::migration_api_from_files(files).await?,
         };
 
         for cmd in AVAILABLE_COMMANDS {
@@ -146,6 +167,25 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 render(executor.calculate_database_steps(&input).await?)
             }
+            RpcCommand::CreateMigration => {
+                let input: CreateMigrationInput = params.clone().parse()?;
+                render(executor.create_migration(&input).await?)
+            }
+            RpcCommand::ApplyMigrations => {
+                let input: ApplyMigrationsInput = params.clone().parse()?;
+                render(executor.apply_migrations(&input).await?)
+            }
+            RpcCommand::DiagnoseMigrationHistory => {
+                render(executor.diagnose_migration_history(&serde_json::Value::Null).await?)
+            }
+            RpcCommand::Baseline => {
+                let input: BaselineInput = params.clone().parse()?;
+                render(executor.baseline(&input).await?)
+            }
+            RpcCommand::SquashMigrations => {
+                let input: SquashMigrationsInput = params.clone().parse()?;
+                render(executor.squash_migrations(&input).await?)
+            }
         }
     }
 }
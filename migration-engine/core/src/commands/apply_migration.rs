@@ -134,9 +134,14 @@ impl<'a> ApplyMigrationCommand<'a> {
             .check(&database_migration)
             .await?;
 
-        match (diagnostics.has_warnings(), self.input.force.unwrap_or(false)) {
+        match (
+            self.input.dry_run.unwrap_or(false),
+            diagnostics.has_warnings(),
+            self.input.force.unwrap_or(false),
+        ) {
+            (true, _, _) => tracing::info!("Dry run, the migration will not be applied."),
             // We have no warnings, or the force flag is passed.
-            (false, _) | (true, true) => {
+            (false, false, _) | (false, true, true) => {
                 tracing::debug!("Applying the migration");
                 let saved_migration = migration_persistence.create(migration).await?;
 
@@ -148,7 +153,7 @@ impl<'a> ApplyMigrationCommand<'a> {
                 tracing::debug!("Migration applied");
             }
             // We have warnings, but no force flag was passed.
-            (true, false) => tracing::info!("The force flag was not passed, the migration will not be applied."),
+            (false, true, false) => tracing::info!("The force flag was not passed, the migration will not be applied."),
         }
 
         let DestructiveChangeDiagnostics {
@@ -165,6 +170,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             warnings,
             general_errors: Vec::new(),
             unexecutable_migrations,
+            rename_candidates: Vec::new(),
         })
     }
 }
@@ -175,6 +181,8 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    /// Infer and check the migration, and render its steps, without applying or persisting it.
+    pub dry_run: Option<bool>,
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
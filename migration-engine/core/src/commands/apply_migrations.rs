@@ -0,0 +1,212 @@
+//! The ApplyMigrations RPC method.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::{
+    migrations_directory::{list_migrations, script_checksum, MigrationDirectory},
+    *,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Applies the migrations in the migrations directory that are not applied yet, in order.
+///
+/// Nothing is applied if migrations were edited or removed from the directory after they were
+/// applied, or if a migration failed to apply before.
+pub struct ApplyMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for ApplyMigrationsCommand {
+    type Input = ApplyMigrationsInput;
+    type Output = ApplyMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+
+        let MigrationsDirectoryHistory { unapplied, problems } = migrations_directory_history(
+            Path::new(&input.migrations_directory_path),
+            migration_persistence.as_ref(),
+        )
+        .await?;
+
+        if !problems.is_empty() {
+            return Err(CommandError::MigrationHistoryMismatch(problems));
+        }
+
+        let step_applier = connector.database_migration_step_applier();
+        let mut applied_migration_names = Vec::with_capacity(unapplied.len());
+
+        for directory in unapplied {
+            let script = read_migration_script(&directory)?;
+
+            let mut migration = Migration::new(directory.migration_name().to_owned());
+            migration.status = MigrationStatus::MigrationInProgress;
+            migration.checksum = Some(script_checksum(&script));
+
+            let migration = migration_persistence.create(migration).await?;
+            let migration_updates = migration.update_params();
+
+            tracing::info!(migration_name = directory.migration_name(), "Applying migration");
+
+            // Whether the script can run in a transaction only depends on its statements.
+            let script_migration = connector.script_migration(&script).await?;
+
+            let result = if step_applier.supports_transactional_ddl(&script_migration) {
+                step_applier.begin_transaction().await?;
+
+                let result = apply_script(connector, migration_persistence.as_ref(), &migration_updates, &script).await;
+
+                match result {
+                    Ok(()) => step_applier.commit_transaction().await,
+                    Err(err) => {
+                        if let Err(rollback_err) = step_applier.rollback_transaction().await {
+                            tracing::warn!("Rolling back the migration transaction failed: {}", rollback_err);
+                        }
+
+                        Err(err)
+                    }
+                }
+            } else {
+                apply_script(connector, migration_persistence.as_ref(), &migration_updates, &script).await
+            };
+
+            if let Err(err) = result {
+                // In a transaction, nothing was applied and the record is still the one from
+                // before the script ran.
+                let mut failure_updates = migration_updates;
+                failure_updates.status = MigrationStatus::MigrationFailure;
+                failure_updates.errors = vec![format!("{}", err)];
+                migration_persistence.update(&failure_updates).await?;
+
+                return Err(err.into());
+            }
+
+            applied_migration_names.push(migration.name);
+        }
+
+        Ok(ApplyMigrationsOutput {
+            applied_migration_names,
+        })
+    }
+}
+
+/// Runs the script and records the migration as applied, with the statements of the script and
+/// the schema they produced.
+async fn apply_script<C, D>(
+    connector: &C,
+    migration_persistence: &dyn MigrationPersistence,
+    migration_updates: &MigrationUpdateParams,
+    script: &str,
+) -> ConnectorResult<()>
+where
+    C: MigrationConnector<DatabaseMigration = D>,
+    D: DatabaseMigrationMarker + Send + Sync + 'static,
+{
+    connector.apply_script(script).await?;
+
+    let database_migration = connector.script_migration(script).await?;
+    let mut finished_updates = migration_updates.clone();
+
+    finished_updates.applied = 1;
+    finished_updates.database_migration = Some(database_migration.serialize());
+    finished_updates.mark_as_finished();
+
+    migration_persistence.update(&finished_updates).await
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyMigrationsInput {
+    pub migrations_directory_path: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyMigrationsOutput {
+    pub applied_migration_names: Vec<String>,
+}
+
+/// The migrations directory compared with the migrations recorded as applied from it.
+pub(crate) struct MigrationsDirectoryHistory {
+    /// The migrations that are not applied yet, in order.
+    pub(crate) unapplied: Vec<MigrationDirectory>,
+    /// Applied migrations that were edited, removed or failed, described for the user.
+    pub(crate) problems: Vec<String>,
+}
+
+pub(crate) async fn migrations_directory_history(
+    migrations_directory_path: &Path,
+    migration_persistence: &dyn MigrationPersistence,
+) -> CommandResult<MigrationsDirectoryHistory> {
+    let mut unapplied = list_migrations(migrations_directory_path).map_err(|err| {
+        CommandError::Generic(anyhow::Error::new(err).context(format!(
+            "Reading the migrations directory at {} failed.",
+            migrations_directory_path.display()
+        )))
+    })?;
+
+    // Migrations applied through applyMigration have no checksum, they are not in the directory.
+    let applied_migrations = migration_persistence
+        .load_all()
+        .await?
+        .into_iter()
+        .filter(|migration| migration.checksum.is_some());
+
+    let mut problems = Vec::new();
+
+    for applied_migration in applied_migrations {
+        let position = unapplied
+            .iter()
+            .position(|directory| directory.migration_name() == applied_migration.name);
+
+        let directory = match position {
+            Some(position) => unapplied.remove(position),
+            None => {
+                problems.push(format!(
+                    "The migration `{}` was applied, but it is missing from the migrations directory.",
+                    applied_migration.name
+                ));
+                continue;
+            }
+        };
+
+        if !applied_migration.status.is_success() {
+            let errors = if applied_migration.errors.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", applied_migration.errors.join(", "))
+            };
+
+            problems.push(format!(
+                "The migration `{}` failed to apply{}. Fix the database and remove the migration's record from the `_Migration` table before applying migrations again.",
+                applied_migration.name, errors
+            ));
+            continue;
+        }
+
+        let script = read_migration_script(&directory)?;
+
+        if applied_migration.checksum.as_ref() != Some(&script_checksum(&script)) {
+            problems.push(format!(
+                "The migration `{}` was modified after it was applied.",
+                applied_migration.name
+            ));
+        }
+    }
+
+    Ok(MigrationsDirectoryHistory { unapplied, problems })
+}
+
+fn read_migration_script(directory: &MigrationDirectory) -> CommandResult<String> {
+    directory.read_migration_script().map_err(|err| {
+        CommandError::Generic(anyhow::Error::new(err).context(format!(
+            "Reading the migration script at {} failed.",
+            directory.script_path().display()
+        )))
+    })
+}
@@ -0,0 +1,87 @@
+//! The Baseline RPC method.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::{ast::SchemaAst, Datamodel};
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+/// Records the current state of the database as the first migration, without executing anything.
+/// This is how an existing database is brought under the control of the migration engine.
+pub struct BaselineCommand<'a> {
+    input: &'a BaselineInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for BaselineCommand<'a> {
+    type Input = BaselineInput;
+    type Output = BaselineOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = BaselineCommand { input };
+        tracing::debug!("{:?}", cmd.input);
+
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+
+        if let Some(migration) = migration_persistence.load_all().await?.into_iter().next() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The database already has a migration history, starting with `{}`. Only databases without migrations can be baselined.",
+                migration.name
+            )));
+        }
+
+        let datamodel = connector.introspect().await?;
+        let datamodel_string =
+            datamodel::render_datamodel_to_string(&datamodel).map_err(CommandError::ProducedBadDatamodel)?;
+        let schema_ast = datamodel::parse_schema_ast(&datamodel_string).map_err(CommandError::ProducedBadDatamodel)?;
+
+        // The migration describes how the database came to be, so the history can be replayed from
+        // an empty database. The database is already in that state: nothing is executed.
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer_from_datamodels(&Datamodel::empty(), &datamodel, &[])
+            .await?;
+
+        let mut migration = Migration::new(cmd.input.migration_id.clone());
+        migration.status = MigrationStatus::MigrationSuccess;
+        migration.datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&SchemaAst::empty(), &schema_ast);
+        migration.datamodel_string = datamodel_string.clone();
+        migration.applied = connector
+            .database_migration_step_applier()
+            .step_count(&database_migration);
+        migration.database_migration = database_migration.serialize();
+
+        let saved_migration = migration_persistence.create(migration).await?;
+
+        let mut migration_updates = saved_migration.update_params();
+        migration_updates.mark_as_finished();
+        migration_persistence.update(&migration_updates).await?;
+
+        Ok(BaselineOutput {
+            migration_id: saved_migration.name,
+            datamodel: datamodel_string,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The name of the migration recording the current state of the database.
+    pub migration_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    pub migration_id: String,
+    /// The introspected datamodel that was recorded with the migration.
+    pub datamodel: String,
+}
@@ -71,6 +71,7 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
             warnings,
             general_errors: Vec::new(),
             unexecutable_migrations,
+            rename_candidates: Vec::new(),
         })
     }
 }
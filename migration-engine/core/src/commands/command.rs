@@ -39,6 +39,15 @@ pub enum CommandError {
 
     #[error("Error in command input. (error: {0})")]
     Input(anyhow::Error),
+
+    /// When the migrations applied from a migrations directory do not match the directory.
+    #[error("The migrations directory does not match the applied migrations. {}", .0.join(" "))]
+    MigrationHistoryMismatch(Vec<String>),
+
+    /// When replaying the migration history in the shadow database does not lead to the schema
+    /// of the last migration.
+    #[error("The migration history does not reproduce the schema of the last migration in the shadow database. {0}")]
+    NonReproducibleMigrationHistory(String),
 }
 
 impl From<migration_connector::ConnectorError> for CommandError {
@@ -72,7 +81,7 @@ mod tests {
 
         assert_eq!(
             err.to_string(),
-            "The migration produced an invalid schema (ErrorCollection { errors: [TypeNotFoundError { type_name: \"Post\", span: Span { start: 76, end: 82 } }] })"
+            "The migration produced an invalid schema (ErrorCollection { errors: [TypeNotFoundError { type_name: \"Post\", span: Span { start: 76, end: 82, file_id: FileId(0) } }] })"
         )
     }
 }
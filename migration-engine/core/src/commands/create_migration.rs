@@ -0,0 +1,97 @@
+//! The CreateMigration RPC method.
+
+use super::apply_migrations::{migrations_directory_history, MigrationsDirectoryHistory};
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use crate::parse_datamodel;
+use datamodel::Datamodel;
+use migration_connector::{migrations_directory::create_migration_directory, *};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Writes the migration from the current database schema to the datamodel to a new folder in the
+/// migrations directory, without applying it.
+pub struct CreateMigrationCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for CreateMigrationCommand {
+    type Input = CreateMigrationInput;
+    type Output = CreateMigrationOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        let MigrationsDirectoryHistory { unapplied, .. } =
+            migrations_directory_history(migrations_directory_path, connector.migration_persistence().as_ref()).await?;
+
+        // The migration is inferred from the database schema, so it would repeat the changes of
+        // migrations that are not applied yet.
+        if !unapplied.is_empty() {
+            let names: Vec<&str> = unapplied.iter().map(|migration| migration.migration_name()).collect();
+
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migrations directory contains migrations that are not applied yet ({}). Apply them before creating a new migration.",
+                names.join(", ")
+            )));
+        }
+
+        let next_datamodel = parse_datamodel(&input.datamodel)?;
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer(&Datamodel::empty(), &next_datamodel, &[])
+            .await?;
+
+        let DestructiveChangeDiagnostics { warnings, .. } = connector
+            .destructive_changes_checker()
+            .check(&database_migration)
+            .await?;
+
+        let script = connector
+            .database_migration_step_applier()
+            .render_script(&database_migration)?;
+
+        if script.is_empty() && !input.draft {
+            tracing::info!("The database is already in sync with the datamodel, no migration was created.");
+
+            return Ok(CreateMigrationOutput {
+                generated_migration_name: None,
+                warnings,
+            });
+        }
+
+        let directory = create_migration_directory(migrations_directory_path, &input.migration_name)
+            .and_then(|directory| directory.write_migration_script(&script).map(|()| directory))
+            .map_err(|err| CommandError::Generic(anyhow::Error::new(err).context("Writing the migration failed.")))?;
+
+        Ok(CreateMigrationOutput {
+            generated_migration_name: Some(directory.migration_name().to_owned()),
+            warnings,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMigrationInput {
+    pub migrations_directory_path: String,
+    #[serde(alias = "dataModel")]
+    pub datamodel: String,
+    pub migration_name: String,
+    /// Create the migration even if the database is already in sync with the datamodel, e.g. to
+    /// write its script by hand.
+    #[serde(default)]
+    pub draft: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMigrationOutput {
+    /// The name of the new migration folder, if one was created.
+    pub generated_migration_name: Option<String>,
+    pub warnings: Vec<MigrationWarning>,
+}
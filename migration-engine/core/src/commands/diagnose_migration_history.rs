@@ -0,0 +1,61 @@
+//! The DiagnoseMigrationHistory RPC method.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::Datamodel;
+use migration_connector::*;
+use serde::Serialize;
+
+/// Compares the database with the schema the applied migrations lead to, to find changes made to
+/// the database outside of migrations.
+pub struct DiagnoseMigrationHistoryCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for DiagnoseMigrationHistoryCommand {
+    type Input = serde_json::Value;
+    type Output = DiagnoseMigrationHistoryOutput;
+
+    async fn execute<C, D>(_input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let last_migration = connector.migration_persistence().last().await?;
+
+        let drift = match &last_migration {
+            // Migrations applied from a migrations directory don't record a datamodel, but the
+            // schema their script produced.
+            Some(migration) if migration.checksum.is_some() => {
+                let database_migration = connector.deserialize_database_migration(migration.database_migration.clone());
+
+                connector.detect_drift_since(&database_migration).await?
+            }
+            Some(migration) => {
+                let expected_datamodel = datamodel::parse_datamodel(&migration.datamodel_string)
+                    .map_err(CommandError::ProducedBadDatamodel)?;
+
+                connector.detect_drift(&expected_datamodel).await?
+            }
+            None => connector.detect_drift(&Datamodel::empty()).await?,
+        };
+
+        if !drift.is_empty() {
+            tracing::warn!("The database schema drifted from the applied migrations.");
+        }
+
+        Ok(DiagnoseMigrationHistoryOutput {
+            last_migration: last_migration.map(|migration| migration.name),
+            drift: if drift.is_empty() { None } else { Some(drift) },
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnoseMigrationHistoryOutput {
+    /// The last applied migration, whose schema the database is expected to match.
+    pub last_migration: Option<String>,
+    /// How the database differs from the schema of the last migration, if it does.
+    pub drift: Option<DatabaseDrift>,
+}
@@ -2,6 +2,7 @@
 
 use super::MigrationStepsResultOutput;
 use crate::commands::command::*;
+use crate::migration::datamodel_differ::DatamodelDiff;
 use crate::migration_engine::MigrationEngine;
 use crate::*;
 use datamodel::ast::{parser::parse, SchemaAst};
@@ -9,6 +10,8 @@ use migration_connector::*;
 use serde::Deserialize;
 use tracing::debug;
 
+pub use crate::migration::datamodel_differ::Rename;
+
 pub struct InferMigrationStepsCommand<'a> {
     input: &'a InferMigrationStepsInput,
 }
@@ -41,6 +44,15 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
         } else {
             SchemaAst::empty()
         };
+
+        let current_datamodel =
+            datamodel::lift_ast(&current_datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
+        // Watch migrations are inferred on every change to the datamodel, replaying the whole
+        // history each time would be too slow.
+        if !cmd.input.is_watch_migration() {
+            engine.validate_migration_history(&current_datamodel).await?;
+        }
+
         let assumed_datamodel_ast = engine
             .datamodel_calculator()
             .infer(&current_datamodel_ast, assume_to_be_applied.as_slice())?;
@@ -50,9 +62,14 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
         let next_datamodel = parse_datamodel(&cmd.input.datamodel)?;
         let next_datamodel_ast = parse(&cmd.input.datamodel).map_err(CommandError::ProducedBadDatamodel)?;
 
-        let model_migration_steps = engine
-            .datamodel_migration_steps_inferrer()
-            .infer(&assumed_datamodel_ast, &next_datamodel_ast);
+        let DatamodelDiff {
+            steps: model_migration_steps,
+            rename_candidates,
+        } = engine.datamodel_migration_steps_inferrer().infer_with_renames(
+            &assumed_datamodel_ast,
+            &next_datamodel_ast,
+            cmd.confirmed_renames(),
+        );
 
         let database_migration = database_migration_inferrer
             .infer(&assumed_datamodel, &next_datamodel, &model_migration_steps)
@@ -80,7 +97,12 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
                     .unwrap_or_else(Datamodel::empty);
                 let datamodel_steps = engine
                     .datamodel_migration_steps_inferrer()
-                    .infer(&last_non_watch_datamodel_ast, &next_datamodel_ast);
+                    .infer_with_renames(
+                        &last_non_watch_datamodel_ast,
+                        &next_datamodel_ast,
+                        cmd.confirmed_renames(),
+                    )
+                    .steps;
 
                 // The database migration since the last non-watch migration, so we can render all the steps applied
                 // in watch mode to the migrations folder.
@@ -107,11 +129,16 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             warnings,
             general_errors: vec![],
             unexecutable_migrations,
+            rename_candidates,
         })
     }
 }
 
 impl InferMigrationStepsCommand<'_> {
+    fn confirmed_renames(&self) -> &[Rename] {
+        self.input.confirmed_renames.as_deref().unwrap_or(&[])
+    }
+
     fn assume_to_be_applied(&self) -> Vec<MigrationStep> {
         self.input
             .assume_to_be_applied
@@ -163,6 +190,9 @@ pub struct InferMigrationStepsInput {
     /// These steps must be provided and correct for migration inferrence to work.
     pub assume_to_be_applied: Option<Vec<MigrationStep>>,
     pub assume_applied_migrations: Option<Vec<AppliedMigration>>,
+    /// Models and fields to rename rather than delete and create again, usually picked by the
+    /// user among the rename candidates of a previous call.
+    pub confirmed_renames: Option<Vec<Rename>>,
 }
 
 #[derive(Debug, Deserialize)]
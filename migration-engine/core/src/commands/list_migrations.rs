@@ -22,6 +22,8 @@ impl<'a> MigrationCommand for ListMigrationsCommand {
             .load_all()
             .await?
             .into_iter()
+            // Migrations applied from a migrations directory have no steps to list.
+            .filter(|migration| migration.checksum.is_none())
             .map(|migration| convert_migration_to_list_migration_steps_output(&engine, migration))
             .collect();
 
@@ -19,7 +19,8 @@ impl<'a> MigrationCommand for MigrationProgressCommand<'a> {
         D: DatabaseMigrationMarker + 'static,
     {
         let cmd = MigrationProgressCommand { input };
-        let migration_persistence = engine.connector().migration_persistence();
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
 
         let migration = migration_persistence
             .by_name(&cmd.input.migration_id)
@@ -33,9 +34,21 @@ impl<'a> MigrationCommand for MigrationProgressCommand<'a> {
                 CommandError::Input(error)
             })?;
 
+        // `applied` and `rolled_back` count database steps, and migrations applied from a
+        // migrations directory run as a single script.
+        let steps = if migration.checksum.is_some() {
+            1
+        } else {
+            let database_migration = connector.deserialize_database_migration(migration.database_migration.clone());
+
+            connector
+                .database_migration_step_applier()
+                .step_count(&database_migration)
+        };
+
         Ok(MigrationProgressOutput {
             status: migration.status,
-            steps: migration.datamodel_steps.len(),
+            steps,
             applied: migration.applied,
             rolled_back: migration.rolled_back,
             errors: migration.errors,
@@ -54,11 +67,12 @@ pub struct MigrationProgressInput {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MigrationProgressOutput {
-    status: MigrationStatus,
-    steps: usize,
-    applied: usize,
-    rolled_back: usize,
-    errors: Vec<String>,
-    started_at: DateTime<Utc>,
-    finished_at: Option<DateTime<Utc>>,
+    pub status: MigrationStatus,
+    /// The number of database steps of the migration.
+    pub steps: usize,
+    pub applied: usize,
+    pub rolled_back: usize,
+    pub errors: Vec<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
 }
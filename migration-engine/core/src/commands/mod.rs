@@ -1,21 +1,31 @@
 mod apply_migration;
+mod apply_migrations;
+mod baseline;
 mod calculate_database_steps;
 mod calculate_datamodel;
 mod command;
+mod create_migration;
+mod diagnose_migration_history;
 mod infer_migration_steps;
 mod list_migrations;
 mod migration_progress;
 mod reset;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply_migration::*;
+pub use apply_migrations::{ApplyMigrationsCommand, ApplyMigrationsInput, ApplyMigrationsOutput};
+pub use baseline::*;
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
 pub use command::*;
+pub use create_migration::*;
+pub use diagnose_migration_history::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
 pub use migration_progress::*;
 pub use reset::*;
+pub use squash_migrations::*;
 pub use unapply_migration::*;
 
 use migration_connector::{MigrationError, MigrationStep, MigrationWarning, UnexecutableMigration};
@@ -39,6 +49,10 @@ pub struct MigrationStepsResultOutput {
     pub warnings: Vec<MigrationWarning>,
     pub errors: Vec<MigrationError>,
     pub general_errors: Vec<String>,
-    #[serde(skip)]
+    #[serde(skip_deserializing, skip_serializing_if = "Vec::is_empty")]
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
+    /// Models and fields that were deleted and created again, but could have been renamed. They
+    /// are renamed when passed back as confirmed renames.
+    #[serde(default)]
+    pub rename_candidates: Vec<Rename>,
 }
@@ -0,0 +1,139 @@
+//! The SquashMigrations RPC method.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::Datamodel;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+/// Collapses a range of applied migrations into a single migration record. Nothing is executed
+/// against the database: the squashed migration leads to the same schema as the range it replaces.
+pub struct SquashMigrationsCommand<'a> {
+    input: &'a SquashMigrationsInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand<'a> {
+    type Input = SquashMigrationsInput;
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = SquashMigrationsCommand { input };
+        tracing::debug!("{:?}", cmd.input);
+
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let migrations = migration_persistence.load_all().await?;
+
+        let from = cmd.applied_migration_position(&migrations, &cmd.input.from_migration_id)?;
+        let to = cmd.applied_migration_position(&migrations, &cmd.input.to_migration_id)?;
+
+        if from > to {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migration `{}` was applied after `{}`. The first migration of the range must be applied first.",
+                cmd.input.from_migration_id,
+                cmd.input.to_migration_id
+            )));
+        }
+
+        let squashed = &migrations[from..=to];
+
+        if let Some(migration) = squashed.iter().find(|migration| !migration.status.is_success()) {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migration `{}` is not applied (status: {}). Only ranges of applied migrations can be squashed.",
+                migration.name,
+                migration.status.code()
+            )));
+        }
+
+        if let Some(migration) = squashed.iter().find(|migration| migration.checksum.is_some()) {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migration `{}` was applied from a migrations directory. Migrations applied from a migrations directory are squashed in the directory.",
+                migration.name
+            )));
+        }
+
+        let previous_datamodel = migrations[..from]
+            .iter()
+            .rev()
+            .find(|migration| migration.status.is_success())
+            .map(|migration| migration.datamodel())
+            .unwrap_or_else(Datamodel::empty);
+
+        let last = squashed.last().expect("The range contains at least one migration.");
+        let next_datamodel = last.datamodel();
+        let datamodel_steps: Vec<MigrationStep> = squashed
+            .iter()
+            .flat_map(|migration| migration.datamodel_steps.iter().cloned())
+            .collect();
+
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer_from_datamodels(&previous_datamodel, &next_datamodel, &datamodel_steps)
+            .await?;
+        let script = connector
+            .database_migration_step_applier()
+            .render_script(&database_migration)?;
+
+        let mut migration = Migration::new(cmd.input.migration_id.clone());
+        migration.status = MigrationStatus::MigrationSuccess;
+        migration.applied = connector
+            .database_migration_step_applier()
+            .step_count(&database_migration);
+        migration.datamodel_string = last.datamodel_string.clone();
+        migration.datamodel_steps = datamodel_steps;
+        migration.database_migration = database_migration.serialize();
+        migration.started_at = squashed[0].started_at;
+        migration.finished_at = last.finished_at;
+
+        let saved_migration = migration_persistence.squash(squashed, migration).await?;
+
+        Ok(SquashMigrationsOutput {
+            migration_id: saved_migration.name,
+            squashed_migrations: squashed.iter().map(|migration| migration.name.clone()).collect(),
+            datamodel: saved_migration.datamodel_string,
+            script,
+        })
+    }
+}
+
+impl SquashMigrationsCommand<'_> {
+    fn applied_migration_position(&self, migrations: &[Migration], migration_id: &str) -> CommandResult<usize> {
+        migrations
+            .iter()
+            .rposition(|migration| migration.name == migration_id && migration.status.is_success())
+            .ok_or_else(|| {
+                CommandError::Input(anyhow::anyhow!(
+                    "There is no applied migration with id `{}`.",
+                    migration_id
+                ))
+            })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The first migration of the range to squash.
+    pub from_migration_id: String,
+    /// The last migration of the range to squash.
+    pub to_migration_id: String,
+    /// The name of the migration replacing the range.
+    pub migration_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    pub migration_id: String,
+    /// The migrations that were replaced, in the order they were applied.
+    pub squashed_migrations: Vec<String>,
+    /// The datamodel snapshot recorded with the squashed migration.
+    pub datamodel: String,
+    /// The SQL the squashed migration stands for.
+    pub script: String,
+}
@@ -28,6 +28,8 @@ impl<'a> MigrationCommand for UnapplyMigrationCommand<'a> {
                 active: None,
                 errors: vec!["There is no last migration that can be rolled back.".to_string()],
                 warnings: Vec::new(),
+                database_steps: Vec::new(),
+                unexecutable_migrations: Vec::new(),
             },
             Some(migration_to_rollback) => {
                 let database_migration =
@@ -36,24 +38,45 @@ impl<'a> MigrationCommand for UnapplyMigrationCommand<'a> {
                 let destructive_changes_checker = connector.destructive_changes_checker();
 
                 let warnings = destructive_changes_checker.check_unapply(&database_migration).await?;
+                let database_steps = connector
+                    .database_migration_step_applier()
+                    .render_rollback_steps_pretty(&database_migration)?;
 
-                match (warnings.has_warnings(), input.force) {
-                    (false, _) | (true, None) | (true, Some(true)) => {
+                let dry_run = input.dry_run.unwrap_or(false);
+
+                let new_active_migration = match (dry_run, warnings.has_warnings(), input.force) {
+                    (true, _, _) => {
+                        debug!("Dry run, the migration will not be rolled back.");
+
+                        // The migration that would be active after rolling back the last one.
+                        connector
+                            .migration_persistence()
+                            .load_all()
+                            .await?
+                            .into_iter()
+                            .rev()
+                            .filter(|migration| migration.status.is_success())
+                            .nth(1)
+                            .map(|m| m.name)
+                    }
+                    (false, false, _) | (false, true, None) | (false, true, Some(true)) => {
                         connector
                             .migration_applier()
                             .unapply(&migration_to_rollback, &database_migration)
                             .await?;
-                    }
-                    (true, Some(false)) => (),
-                }
 
-                let new_active_migration = connector.migration_persistence().last().await?.map(|m| m.name);
+                        connector.migration_persistence().last().await?.map(|m| m.name)
+                    }
+                    (false, true, Some(false)) => connector.migration_persistence().last().await?.map(|m| m.name),
+                };
 
                 UnapplyMigrationOutput {
                     rolled_back: migration_to_rollback.name,
                     active: new_active_migration,
                     errors: Vec::new(),
                     warnings: warnings.warnings,
+                    database_steps,
+                    unexecutable_migrations: warnings.unexecutable_migrations,
                 }
             }
         };
@@ -66,6 +89,8 @@ impl<'a> MigrationCommand for UnapplyMigrationCommand<'a> {
 #[serde(rename_all = "camelCase")]
 pub struct UnapplyMigrationInput {
     pub force: Option<bool>,
+    /// Check the rollback and render its steps, without rolling back anything.
+    pub dry_run: Option<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -75,4 +100,8 @@ pub struct UnapplyMigrationOutput {
     pub active: Option<String>,
     pub errors: Vec<String>,
     pub warnings: Vec<MigrationWarning>,
+    /// The rendered steps rolling back the migration.
+    pub database_steps: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unexecutable_migrations: Vec<UnexecutableMigration>,
 }
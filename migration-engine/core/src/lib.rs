@@ -14,31 +14,97 @@ use commands::{CommandError, CommandResult};
 use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     dml::Datamodel,
+    SourceFile,
 };
 use error::Error;
 use std::sync::Arc;
 
+/// The environment variable setting how long commands changing the database wait for the
+/// migration lock, in seconds.
+pub const LOCK_TIMEOUT_ENV_VAR: &str = "MIGRATION_ENGINE_LOCK_TIMEOUT";
+
 pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericApi>> {
-    let config = datamodel::parse_configuration(datamodel)?;
+    migration_api_from_files(&[SourceFile::new("schema.prisma", datamodel)]).await
+}
+
+/// Like `migration_api`, for a schema split across several files.
+pub async fn migration_api_from_files(files: &[SourceFile]) -> CoreResult<Arc<dyn api::GenericApi>> {
+    let config = datamodel::parse_configuration_from_files(files)?;
 
     let source = config
         .datasources
         .first()
         .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
 
-    let connector = match source.connector_type() {
+    let api = match source.connector_type() {
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME].contains(&provider) => {
-            sql_migration_connector::SqlMigrationConnector::new(&source.url().value, provider).await?
+            let connector = sql_migration_connector::SqlMigrationConnector::new(&source.url().value, provider)
+                .await?
+                .with_schemas(source.schemas().to_vec());
+            let mut api = api::MigrationApi::new(connector)
+                .await?
+                .with_lock_timeout(lock_timeout_from_env()?);
+
+            if let Some(shadow_database_url) = source.shadow_database_url() {
+                let shadow_database = sql_shadow_database(api.connector(), &shadow_database_url.value, provider)
+                    .await?
+                    .with_schemas(source.schemas().to_vec());
+
+                api = api.with_shadow_database(shadow_database);
+            }
+
+            api
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
 
-    let api = api::MigrationApi::new(connector).await?;
-
     Ok(Arc::new(api))
 }
 
+/// Connects to the shadow database, creating it through the main connection if it does not exist.
+#[cfg(feature = "sql")]
+async fn sql_shadow_database(
+    connector: &sql_migration_connector::SqlMigrationConnector,
+    shadow_database_url: &str,
+    provider: &str,
+) -> CoreResult<sql_migration_connector::SqlMigrationConnector> {
+    use migration_connector::{ConnectorError, ErrorKind, MigrationConnector};
+    use sql_migration_connector::SqlMigrationConnector;
+
+    match SqlMigrationConnector::new(shadow_database_url, provider).await {
+        Err(ConnectorError {
+            kind: ErrorKind::DatabaseDoesNotExist { db_name },
+            ..
+        }) => {
+            connector.create_database(&db_name).await?;
+
+            Ok(SqlMigrationConnector::new(shadow_database_url, provider).await?)
+        }
+        result => Ok(result?),
+    }
+}
+
+#[cfg(feature = "sql")]
+fn lock_timeout_from_env() -> CoreResult<std::time::Duration> {
+    match std::env::var(LOCK_TIMEOUT_ENV_VAR) {
+        Ok(seconds) => seconds
+            .trim()
+            .parse()
+            .map(std::time::Duration::from_secs)
+            .map_err(|err| {
+                CommandError::Generic(anyhow::anyhow!(
+                    "`{}` is not a valid {} ({}). It must be a number of seconds.",
+                    seconds,
+                    LOCK_TIMEOUT_ENV_VAR,
+                    err
+                ))
+                .into()
+            }),
+        Err(_) => Ok(api::DEFAULT_LOCK_TIMEOUT),
+    }
+}
+
 pub(crate) fn parse_datamodel(datamodel: &str) -> CommandResult<Datamodel> {
     datamodel::parse_datamodel(&datamodel)
         .map_err(|err| CommandError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", datamodel)))
@@ -173,6 +173,7 @@ fn apply_create_field(datamodel: &mut ast::SchemaAst, step: &steps::CreateField)
         directives: Vec::new(),
         default_value: None,
         is_commented_out: false,
+        is_legacy_type_syntax: false,
     };
     model.fields.push(field);
 
@@ -195,6 +196,7 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         fields: vec![],
         directives: vec![],
         commented_out: false,
+        is_composite_type: step.is_composite_type,
     };
 
     datamodel.tops.push(ast::Top::Model(model));
@@ -484,6 +486,7 @@ fn apply_create_type_alias(
         directives: vec![],
         field_type: new_ident(step.r#type.clone()),
         is_commented_out: false,
+        is_legacy_type_syntax: false,
     };
 
     datamodel.tops.push(ast::Top::Type(type_alias));
@@ -1,38 +1,91 @@
 #![deny(rust_2018_idioms)]
 
-mod directives;
-mod enum_values;
-mod enums;
-mod fields;
-mod models;
-mod source;
-mod top_level;
-
-use directives::DirectiveDiffer;
-use enum_values::EnumValueDiffer;
-use enums::EnumDiffer;
-use fields::FieldDiffer;
-use models::ModelDiffer;
-use top_level::TopDiffer;
-
-use crate::migration::datamodel_differ::source::SourceArgumentsDiffer;
-use datamodel::ast;
+use datamodel::ast::{
+    self,
+    differ::{
+        directive_is_regular, directive_is_repeated, DirectiveDiffer, EnumDiffer, EnumValueDiffer, FieldDiffer,
+        ModelDiffer, SourceArgumentsDiffer, TopDiffer,
+    },
+};
 use migration_connector::steps::{
     self, ArgumentLocation, DirectiveLocation, DirectivePath, MigrationStep, SourceLocation,
 };
+use serde::{Deserialize, Serialize};
 
 /// Diff two datamodels, returning the [MigrationStep](/struct.MigrationStep.html)s from `previous`
 /// to `next`.
 pub(crate) fn diff(previous: &ast::SchemaAst, next: &ast::SchemaAst) -> Vec<MigrationStep> {
+    diff_with_renames(previous, next, &[]).steps
+}
+
+/// Like `diff`, but also renaming the models and fields in `confirmed_renames`.
+///
+/// Models and fields keeping the same database name under a new name are always renamed. Other
+/// deleted and created models or fields that look alike are only renamed when the rename is
+/// confirmed, and are otherwise returned as rename candidates.
+pub(crate) fn diff_with_renames(
+    previous: &ast::SchemaAst,
+    next: &ast::SchemaAst,
+    confirmed_renames: &[Rename],
+) -> DatamodelDiff {
     let mut steps = Vec::new();
+    let mut renames = Renames {
+        confirmed: confirmed_renames,
+        candidates: Vec::new(),
+    };
     let differ = TopDiffer { previous, next };
 
     push_type_aliases(&mut steps, &differ);
     push_enums(&mut steps, &differ);
     push_datasources(&mut steps, &differ);
-    push_models(&mut steps, &differ);
+    push_models(&mut steps, &mut renames, &differ);
+
+    DatamodelDiff {
+        steps,
+        rename_candidates: renames.candidates,
+    }
+}
+
+/// The steps between two datamodels.
+#[derive(Debug)]
+pub struct DatamodelDiff {
+    pub steps: Vec<MigrationStep>,
+    /// The models and fields that were deleted and created again, but could have been renamed.
+    pub rename_candidates: Vec<Rename>,
+}
+
+/// The rename of a model or field, from the previous to the next datamodel.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rename {
+    /// The name of the model in the previous datamodel.
+    pub model: String,
+    /// The name of the field in the previous datamodel, when a field is renamed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// The name of the model or field in the next datamodel.
+    pub new_name: String,
+}
+
+struct Renames<'a> {
+    confirmed: &'a [Rename],
+    candidates: Vec<Rename>,
+}
+
+impl Renames<'_> {
+    fn is_confirmed(&self, model: &str, field: Option<&str>, new_name: &str) -> bool {
+        self.confirmed
+            .iter()
+            .any(|rename| rename.model == model && rename.field.as_deref() == field && rename.new_name == new_name)
+    }
 
-    steps
+    fn push_candidate(&mut self, model: &str, field: Option<&str>, new_name: &str) {
+        self.candidates.push(Rename {
+            model: model.to_owned(),
+            field: field.map(String::from),
+            new_name: new_name.to_owned(),
+        })
+    }
 }
 
 type Steps = Vec<MigrationStep>;
@@ -246,10 +299,72 @@ fn push_deleted_sources<'a>(steps: &mut Steps, sources: impl Iterator<Item = &'a
     steps.extend(delete_source_steps);
 }
 
-fn push_models(steps: &mut Steps, differ: &TopDiffer<'_>) {
-    push_created_models(steps, differ.created_models());
-    push_deleted_models(steps, differ.deleted_models());
-    push_updated_models(steps, differ.model_pairs());
+fn push_models(steps: &mut Steps, renames: &mut Renames<'_>, differ: &TopDiffer<'_>) {
+    let mut renamed_models: Vec<ModelDiffer<'_>> = Vec::new();
+
+    for previous in differ.deleted_models() {
+        let renamed = differ
+            .created_models()
+            .filter(|next| !renamed_models.iter().any(|pair| pair.next.name.name == next.name.name))
+            .find(|next| {
+                database_name(&previous.name, &previous.directives) == database_name(&next.name, &next.directives)
+                    || renames.is_confirmed(&previous.name.name, None, &next.name.name)
+            });
+
+        match renamed {
+            Some(next) => renamed_models.push(ModelDiffer { previous, next }),
+            None => {
+                for next in differ.created_models().filter(|next| models_look_alike(previous, next)) {
+                    renames.push_candidate(&previous.name.name, None, &next.name.name);
+                }
+            }
+        }
+    }
+
+    push_created_models(
+        steps,
+        differ
+            .created_models()
+            .filter(|model| !renamed_models.iter().any(|pair| pair.next.name.name == model.name.name)),
+    );
+    push_deleted_models(
+        steps,
+        differ.deleted_models().filter(|model| {
+            !renamed_models
+                .iter()
+                .any(|pair| pair.previous.name.name == model.name.name)
+        }),
+    );
+    push_updated_models(steps, renames, differ.model_pairs().chain(renamed_models.into_iter()));
+}
+
+/// The name of the table or column backing a model or field: its `@map` or `@@map` argument, or
+/// its name.
+fn database_name<'a>(name: &'a ast::Identifier, directives: &'a [ast::Directive]) -> &'a str {
+    directives
+        .iter()
+        .find(|directive| directive.name.name == "map")
+        .and_then(|directive| directive.arguments.first())
+        .and_then(|argument| match &argument.value {
+            ast::Expression::StringValue(value, _) => Some(value.as_str()),
+            _ => None,
+        })
+        .unwrap_or(&name.name)
+}
+
+/// Models with the same fields, which could be the same model renamed.
+fn models_look_alike(previous: &ast::Model, next: &ast::Model) -> bool {
+    previous.fields.len() == next.fields.len()
+        && previous.fields.iter().all(|previous_field| {
+            next.fields.iter().any(|next_field| {
+                next_field.name.name == previous_field.name.name && fields_look_alike(previous_field, next_field)
+            })
+        })
+}
+
+/// Fields with the same type and arity, which could be the same field renamed.
+fn fields_look_alike(previous: &ast::Field, next: &ast::Field) -> bool {
+    previous.field_type.name == next.field_type.name && previous.arity == next.arity
 }
 
 fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a ast::Model>) {
@@ -261,6 +376,7 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
 
         let create_model_step = steps::CreateModel {
             model: created_model.name.name.clone(),
+            is_composite_type: created_model.is_composite_type,
         };
 
         steps.push(MigrationStep::CreateModel(create_model_step));
@@ -270,12 +386,12 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
         push_created_directives(
             steps,
             &directive_path,
-            created_model.directives.iter().filter(models::directive_is_regular),
+            created_model.directives.iter().filter(directive_is_regular),
         );
         push_created_directives_with_arguments(
             steps,
             &directive_path,
-            created_model.directives.iter().filter(models::directive_is_repeated),
+            created_model.directives.iter().filter(directive_is_repeated),
         );
     }
 }
@@ -290,13 +406,42 @@ fn push_deleted_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
     steps.extend(delete_model_steps);
 }
 
-fn push_updated_models<'a>(steps: &mut Steps, models: impl Iterator<Item = ModelDiffer<'a>>) {
+fn push_updated_models<'a>(
+    steps: &mut Steps,
+    renames: &mut Renames<'_>,
+    models: impl Iterator<Item = ModelDiffer<'a>>,
+) {
     models.for_each(|model| {
-        let model_name = &model.previous.name.name;
+        let update_model_step = steps::UpdateModel {
+            model: model.previous.name.name.clone(),
+            new_name: diff_value(&model.previous.name.name, &model.next.name.name),
+        };
+
+        if update_model_step.is_any_option_set() {
+            steps.push(MigrationStep::UpdateModel(update_model_step));
+        }
+
+        // The steps after the rename refer to the model by its new name.
+        let model_name = &model.next.name.name;
+        let renamed_fields = renamed_fields(renames, &model);
 
-        push_created_fields(steps, model_name, model.created_fields());
-        push_deleted_fields(steps, model_name, model.deleted_fields());
-        push_updated_fields(steps, model_name, model.field_pairs());
+        push_created_fields(
+            steps,
+            model_name,
+            model
+                .created_fields()
+                .filter(|field| !renamed_fields.iter().any(|pair| pair.next.name.name == field.name.name)),
+        );
+        push_deleted_fields(
+            steps,
+            model_name,
+            model.deleted_fields().filter(|field| {
+                !renamed_fields
+                    .iter()
+                    .any(|pair| pair.previous.name.name == field.name.name)
+            }),
+        );
+        push_updated_fields(steps, model_name, model.field_pairs().chain(renamed_fields.into_iter()));
 
         let directive_path = DirectivePath::Model {
             model: model_name.clone(),
@@ -317,6 +462,35 @@ fn push_updated_models<'a>(steps: &mut Steps, models: impl Iterator<Item = Model
     });
 }
 
+/// The deleted and created fields of a model that are the same field renamed.
+fn renamed_fields<'a>(renames: &mut Renames<'_>, model: &'a ModelDiffer<'_>) -> Vec<FieldDiffer<'a>> {
+    let mut renamed_fields: Vec<FieldDiffer<'a>> = Vec::new();
+    let model_name = &model.previous.name.name;
+
+    for previous in model.deleted_fields() {
+        let renamed = model
+            .created_fields()
+            .filter(|next| !renamed_fields.iter().any(|pair| pair.next.name.name == next.name.name))
+            .find(|next| {
+                (fields_look_alike(previous, next)
+                    && database_name(&previous.name, &previous.directives)
+                        == database_name(&next.name, &next.directives))
+                    || renames.is_confirmed(model_name, Some(&previous.name.name), &next.name.name)
+            });
+
+        match renamed {
+            Some(next) => renamed_fields.push(FieldDiffer { previous, next }),
+            None => {
+                for next in model.created_fields().filter(|next| fields_look_alike(previous, next)) {
+                    renames.push_candidate(model_name, Some(&previous.name.name), &next.name.name);
+                }
+            }
+        }
+    }
+
+    renamed_fields
+}
+
 fn push_created_fields<'a>(steps: &mut Steps, model_name: &'a str, fields: impl Iterator<Item = &'a ast::Field>) {
     for field in fields {
         let create_field_step = steps::CreateField {
@@ -364,7 +538,7 @@ fn push_updated_fields<'a>(steps: &mut Steps, model_name: &'a str, fields: impl
 
         let directive_path = steps::DirectivePath::Field {
             model: model_name.to_owned(),
-            field: field.previous.name.name.clone(),
+            field: field.next.name.name.clone(),
         };
 
         push_created_directives(steps, &directive_path, field.created_directives());
@@ -1,8 +1,14 @@
+use crate::migration::datamodel_differ::{DatamodelDiff, Rename};
 use datamodel::ast::SchemaAst;
 use migration_connector::steps::MigrationStep;
 
 pub trait DataModelMigrationStepsInferrer: Send + Sync + 'static {
     fn infer(&self, previous: &SchemaAst, next: &SchemaAst) -> Vec<MigrationStep>;
+
+    /// Infer the steps, renaming the models and fields in `confirmed_renames` instead of deleting
+    /// and creating them again.
+    fn infer_with_renames(&self, previous: &SchemaAst, next: &SchemaAst, confirmed_renames: &[Rename])
+        -> DatamodelDiff;
 }
 
 pub struct DataModelMigrationStepsInferrerImplWrapper {}
@@ -12,6 +18,15 @@ impl DataModelMigrationStepsInferrer for DataModelMigrationStepsInferrerImplWrap
         let inferrer = DataModelMigrationStepsInferrerImpl { previous, next };
         crate::migration::datamodel_differ::diff(inferrer.previous, inferrer.next)
     }
+
+    fn infer_with_renames(
+        &self,
+        previous: &SchemaAst,
+        next: &SchemaAst,
+        confirmed_renames: &[Rename],
+    ) -> DatamodelDiff {
+        crate::migration::datamodel_differ::diff_with_renames(previous, next, confirmed_renames)
+    }
 }
 
 #[allow(dead_code)]
@@ -1,7 +1,10 @@
 use crate::migration::datamodel_calculator::*;
 use crate::migration::datamodel_migration_steps_inferrer::*;
-use crate::{commands::CommandResult, CoreResult};
-use datamodel::ast::SchemaAst;
+use crate::{
+    commands::{CommandError, CommandResult},
+    CoreResult,
+};
+use datamodel::{ast::SchemaAst, Datamodel};
 use migration_connector::*;
 use std::sync::Arc;
 
@@ -13,6 +16,8 @@ where
     datamodel_migration_steps_inferrer: Arc<dyn DataModelMigrationStepsInferrer>,
     datamodel_calculator: Arc<dyn DataModelCalculator>,
     connector: C,
+    /// The database the migration history is replayed into, to validate it.
+    shadow_database: Option<C>,
 }
 
 impl<C, D> MigrationEngine<C, D>
@@ -25,6 +30,7 @@ where
             datamodel_migration_steps_inferrer: Arc::new(DataModelMigrationStepsInferrerImplWrapper {}),
             datamodel_calculator: Arc::new(DataModelCalculatorImpl),
             connector,
+            shadow_database: None,
         };
 
         engine.init().await?;
@@ -46,6 +52,79 @@ where
         &self.connector
     }
 
+    pub fn set_shadow_database(&mut self, shadow_database: C) {
+        self.shadow_database = Some(shadow_database);
+    }
+
+    /// Replays the applied migrations into the shadow database, when there is one, and checks
+    /// that they reproduce the `expected` datamodel. This catches migrations that were edited by
+    /// hand or that can't be reproduced. The shadow database is reset afterwards.
+    pub async fn validate_migration_history(&self, expected: &Datamodel) -> CommandResult<()> {
+        let shadow_database = match &self.shadow_database {
+            Some(shadow_database) => shadow_database,
+            None => return Ok(()),
+        };
+
+        // Clean up after a previous validation that was interrupted.
+        shadow_database.initialize().await?;
+        shadow_database.reset().await?;
+        shadow_database.initialize().await?;
+
+        let result = self.replay_migration_history(shadow_database, expected).await;
+
+        // Failing to clean up must not hide the outcome of the validation.
+        if let Err(err) = shadow_database.reset().await {
+            tracing::warn!("Resetting the shadow database failed: {}", err);
+        }
+
+        result
+    }
+
+    async fn replay_migration_history(&self, shadow_database: &C, expected: &Datamodel) -> CommandResult<()> {
+        let applied_migrations = self
+            .connector
+            .migration_persistence()
+            .load_all()
+            .await?
+            .into_iter()
+            .filter(|migration| migration.status.is_success());
+        let step_applier = shadow_database.database_migration_step_applier();
+        // Migrations applied from a migrations directory don't record a datamodel to compare with.
+        let mut last_directory_migration = None;
+
+        for migration in applied_migrations {
+            tracing::debug!(
+                migration_name = migration.name.as_str(),
+                "Replaying migration in the shadow database"
+            );
+
+            // Migrations applied from a migrations directory record the statements of their script.
+            let database_migration = shadow_database.deserialize_database_migration(migration.database_migration);
+            let mut step = 0;
+
+            while step_applier.apply_step(&database_migration, step).await? {
+                step += 1;
+            }
+
+            last_directory_migration = if migration.checksum.is_some() {
+                Some(database_migration)
+            } else {
+                None
+            };
+        }
+
+        let drift = match &last_directory_migration {
+            Some(database_migration) => shadow_database.detect_drift_since(database_migration).await?,
+            None => shadow_database.detect_drift(expected).await?,
+        };
+
+        if drift.is_empty() {
+            Ok(())
+        } else {
+            Err(CommandError::NonReproducibleMigrationHistory(drift.summary()))
+        }
+    }
+
     pub fn datamodel_migration_steps_inferrer(&self) -> &Arc<dyn DataModelMigrationStepsInferrer> {
         &self.datamodel_migration_steps_inferrer
     }
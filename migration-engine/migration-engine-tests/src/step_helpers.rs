@@ -31,5 +31,6 @@ pub fn create_id_directive_step(model: &str, field: &str) -> MigrationStep {
 pub fn create_model_step(model: &str) -> MigrationStep {
     MigrationStep::CreateModel(CreateModel {
         model: model.to_string(),
+        is_composite_type: false,
     })
 }
@@ -1,11 +1,15 @@
 mod apply;
+mod apply_migrations;
 mod calculate_database_steps;
+mod create_migration;
 mod infer;
 mod infer_apply;
 mod unapply_migration;
 
 pub use apply::Apply;
+pub use apply_migrations::ApplyMigrations;
 pub use calculate_database_steps::CalculateDatabaseSteps;
+pub use create_migration::CreateMigration;
 pub use infer::Infer;
 pub use infer_apply::InferApply;
 pub use unapply_migration::UnapplyMigration;
@@ -19,11 +23,15 @@ use super::{
 use migration_connector::{MigrationPersistence, MigrationStep};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{
+        ApplyMigrationInput, BaselineInput, BaselineOutput, DiagnoseMigrationHistoryOutput, MigrationProgressInput,
+        MigrationProgressOutput, SquashMigrationsInput, SquashMigrationsOutput,
+    },
 };
 use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
+use sql_migration_connector::SqlMigrationConnector;
 use sql_schema_describer::*;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 use test_setup::*;
 
 /// A handle to all the context needed for end-to-end testing of the migration engine across
@@ -32,8 +40,9 @@ pub struct TestApi {
     /// More precise than SqlFamily.
     connector_name: &'static str,
     database: Arc<dyn Queryable + Send + Sync + 'static>,
-    api: MigrationApi<sql_migration_connector::SqlMigrationConnector, sql_migration_connector::SqlMigration>,
+    api: MigrationApi<SqlMigrationConnector, sql_migration_connector::SqlMigration>,
     connection_info: ConnectionInfo,
+    url: String,
 }
 
 impl TestApi {
@@ -57,6 +66,21 @@ impl TestApi {
         self.sql_family() == SqlFamily::Mysql
     }
 
+    pub fn connector(&self) -> &SqlMigrationConnector {
+        self.api.connector()
+    }
+
+    /// Connects to the same database again, like another migration engine would.
+    pub async fn new_connector(&self) -> anyhow::Result<SqlMigrationConnector> {
+        let provider = match self.sql_family() {
+            SqlFamily::Postgres => "postgresql",
+            SqlFamily::Mysql => "mysql",
+            SqlFamily::Sqlite => "sqlite",
+        };
+
+        Ok(SqlMigrationConnector::new(&self.url, provider).await?)
+    }
+
     pub fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a> {
         self.api.migration_persistence()
     }
@@ -79,6 +103,7 @@ impl TestApi {
             migration_id: migration_id.into(),
             steps,
             force: None,
+            dry_run: None,
         };
 
         let migration_output = self.api.apply_migration(&input).await.expect("ApplyMigration failed");
@@ -122,6 +147,7 @@ impl TestApi {
         UnapplyMigration {
             api: &self.api,
             force: None,
+            dry_run: None,
         }
     }
 
@@ -189,6 +215,59 @@ impl TestApi {
     pub fn calculate_database_steps<'a>(&'a self) -> CalculateDatabaseSteps<'a> {
         CalculateDatabaseSteps::new(&self.api)
     }
+
+    /// Create a temporary directory to serve as a migrations directory.
+    pub fn create_migrations_directory(&self) -> anyhow::Result<tempfile::TempDir> {
+        Ok(tempfile::tempdir()?)
+    }
+
+    pub fn create_migration<'a>(
+        &'a self,
+        migration_name: &'a str,
+        schema: &'a str,
+        migrations_directory: &'a Path,
+    ) -> CreateMigration<'a> {
+        CreateMigration::new(&self.api, migration_name, schema, migrations_directory)
+    }
+
+    pub fn apply_migrations<'a>(&'a self, migrations_directory: &'a Path) -> ApplyMigrations<'a> {
+        ApplyMigrations::new(&self.api, migrations_directory)
+    }
+
+    pub async fn migration_progress(&self, migration_id: &str) -> anyhow::Result<MigrationProgressOutput> {
+        let input = MigrationProgressInput {
+            migration_id: migration_id.to_owned(),
+        };
+
+        Ok(self.api.migration_progress(&input).await?)
+    }
+
+    pub async fn diagnose_migration_history(&self) -> anyhow::Result<DiagnoseMigrationHistoryOutput> {
+        Ok(self.api.diagnose_migration_history(&serde_json::Value::Null).await?)
+    }
+
+    pub async fn baseline(&self, migration_id: &str) -> anyhow::Result<BaselineOutput> {
+        let input = BaselineInput {
+            migration_id: migration_id.to_owned(),
+        };
+
+        Ok(self.api.baseline(&input).await?)
+    }
+
+    pub async fn squash_migrations(
+        &self,
+        from_migration_id: &str,
+        to_migration_id: &str,
+        migration_id: &str,
+    ) -> anyhow::Result<SquashMigrationsOutput> {
+        let input = SquashMigrationsInput {
+            from_migration_id: from_migration_id.to_owned(),
+            to_migration_id: to_migration_id.to_owned(),
+            migration_id: migration_id.to_owned(),
+        };
+
+        Ok(self.api.squash_migrations(&input).await?)
+    }
 }
 
 pub struct SingleRowInsert<'a> {
@@ -241,6 +320,7 @@ pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql_8",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -254,6 +334,7 @@ pub async fn mysql_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -267,6 +348,7 @@ pub async fn mysql_mariadb_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql_mariadb",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -280,6 +362,7 @@ pub async fn postgres9_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres9",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -293,6 +376,7 @@ pub async fn postgres_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -306,6 +390,7 @@ pub async fn postgres11_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres11",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -319,18 +404,21 @@ pub async fn postgres12_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres12",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
 }
 
 pub async fn sqlite_test_api(db_name: &str) -> TestApi {
-    let connection_info = ConnectionInfo::from_url(&sqlite_test_url(db_name)).unwrap();
+    let url = sqlite_test_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
     let connector = sqlite_migration_connector(db_name).await;
 
     TestApi {
         connector_name: "sqlite",
         connection_info,
+        url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
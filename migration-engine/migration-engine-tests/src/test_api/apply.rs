@@ -11,6 +11,7 @@ pub struct Apply<'a> {
     migration_id: Option<String>,
     steps: Option<Vec<MigrationStep>>,
     force: Option<bool>,
+    dry_run: Option<bool>,
 }
 
 impl Apply<'_> {
@@ -20,6 +21,7 @@ impl Apply<'_> {
             migration_id: None,
             steps: None,
             force: None,
+            dry_run: None,
         }
     }
 
@@ -38,6 +40,11 @@ impl Apply<'_> {
         self
     }
 
+    pub fn dry_run(mut self, dry_run: Option<bool>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     pub async fn send(self) -> Result<MigrationStepsResultOutput, anyhow::Error> {
         Ok(self.send_inner().await?)
     }
@@ -53,6 +60,7 @@ impl Apply<'_> {
         let input = ApplyMigrationInput {
             migration_id,
             force: self.force,
+            dry_run: self.dry_run,
             steps: self.steps.unwrap_or_else(Vec::new),
         };
 
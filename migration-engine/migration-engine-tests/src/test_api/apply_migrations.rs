@@ -0,0 +1,27 @@
+use migration_core::{
+    api::GenericApi,
+    commands::{ApplyMigrationsInput, ApplyMigrationsOutput},
+};
+use std::path::Path;
+
+pub struct ApplyMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a Path,
+}
+
+impl<'a> ApplyMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a Path) -> Self {
+        ApplyMigrations {
+            api,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> anyhow::Result<ApplyMigrationsOutput> {
+        let input = ApplyMigrationsInput {
+            migrations_directory_path: self.migrations_directory.to_string_lossy().into_owned(),
+        };
+
+        Ok(self.api.apply_migrations(&input).await?)
+    }
+}
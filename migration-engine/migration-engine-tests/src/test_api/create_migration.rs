@@ -0,0 +1,47 @@
+use migration_core::{
+    api::GenericApi,
+    commands::{CreateMigrationInput, CreateMigrationOutput},
+};
+use std::path::Path;
+
+pub struct CreateMigration<'a> {
+    api: &'a dyn GenericApi,
+    migration_name: &'a str,
+    schema: &'a str,
+    migrations_directory: &'a Path,
+    draft: bool,
+}
+
+impl<'a> CreateMigration<'a> {
+    pub fn new(
+        api: &'a dyn GenericApi,
+        migration_name: &'a str,
+        schema: &'a str,
+        migrations_directory: &'a Path,
+    ) -> Self {
+        CreateMigration {
+            api,
+            migration_name,
+            schema,
+            migrations_directory,
+            draft: false,
+        }
+    }
+
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = draft;
+
+        self
+    }
+
+    pub async fn send(self) -> anyhow::Result<CreateMigrationOutput> {
+        let input = CreateMigrationInput {
+            migrations_directory_path: self.migrations_directory.to_string_lossy().into_owned(),
+            datamodel: self.schema.to_owned(),
+            migration_name: self.migration_name.to_owned(),
+            draft: self.draft,
+        };
+
+        Ok(self.api.create_migration(&input).await?)
+    }
+}
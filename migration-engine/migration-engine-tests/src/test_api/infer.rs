@@ -2,7 +2,7 @@ use super::super::unique_migration_id;
 use migration_connector::MigrationStep;
 use migration_core::{
     api::GenericApi,
-    commands::{AppliedMigration, InferMigrationStepsInput, MigrationStepsResultOutput},
+    commands::{AppliedMigration, InferMigrationStepsInput, MigrationStepsResultOutput, Rename},
 };
 
 pub struct Infer<'a> {
@@ -11,6 +11,7 @@ pub struct Infer<'a> {
     pub(super) assume_applied_migrations: Option<Vec<AppliedMigration>>,
     pub(super) datamodel: String,
     pub(super) migration_id: Option<String>,
+    pub(super) confirmed_renames: Option<Vec<Rename>>,
 }
 
 impl<'a> Infer<'a> {
@@ -21,6 +22,7 @@ impl<'a> Infer<'a> {
             assume_to_be_applied: None,
             assume_applied_migrations: None,
             migration_id: None,
+            confirmed_renames: None,
         }
     }
 
@@ -39,6 +41,11 @@ impl<'a> Infer<'a> {
         self
     }
 
+    pub fn confirmed_renames(mut self, confirmed_renames: Option<Vec<Rename>>) -> Self {
+        self.confirmed_renames = confirmed_renames;
+        self
+    }
+
     pub async fn send(self) -> Result<MigrationStepsResultOutput, anyhow::Error> {
         let migration_id = self.migration_id.unwrap_or_else(unique_migration_id);
 
@@ -47,6 +54,7 @@ impl<'a> Infer<'a> {
             assume_applied_migrations: self.assume_applied_migrations,
             datamodel: self.datamodel,
             migration_id,
+            confirmed_renames: self.confirmed_renames,
         };
 
         let output = self.api.infer_migration_steps(&input).await?;
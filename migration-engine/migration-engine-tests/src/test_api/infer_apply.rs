@@ -1,7 +1,7 @@
 use super::super::{assertions::AssertionResult, unique_migration_id};
 use migration_core::{
     api::GenericApi,
-    commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationStepsResultOutput},
+    commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationStepsResultOutput, Rename},
 };
 
 pub struct InferApply<'a> {
@@ -9,6 +9,8 @@ pub struct InferApply<'a> {
     schema: &'a str,
     migration_id: Option<String>,
     force: Option<bool>,
+    confirmed_renames: Option<Vec<Rename>>,
+    dry_run: Option<bool>,
 }
 
 impl<'a> InferApply<'a> {
@@ -18,6 +20,8 @@ impl<'a> InferApply<'a> {
             schema,
             migration_id: None,
             force: None,
+            confirmed_renames: None,
+            dry_run: None,
         }
     }
 
@@ -31,6 +35,16 @@ impl<'a> InferApply<'a> {
         self
     }
 
+    pub fn confirmed_renames(mut self, confirmed_renames: Option<Vec<Rename>>) -> Self {
+        self.confirmed_renames = confirmed_renames;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: Option<bool>) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     pub async fn send(self) -> Result<MigrationStepsResultOutput, anyhow::Error> {
         Ok(self.send_inner().await?)
     }
@@ -55,6 +69,7 @@ impl<'a> InferApply<'a> {
             datamodel: self.schema.to_owned(),
             assume_to_be_applied: Some(Vec::new()),
             assume_applied_migrations: None,
+            confirmed_renames: self.confirmed_renames,
         };
 
         let steps = self.api.infer_migration_steps(&input).await?.datamodel_steps;
@@ -63,6 +78,7 @@ impl<'a> InferApply<'a> {
             migration_id,
             steps,
             force: self.force,
+            dry_run: self.dry_run,
         };
 
         let migration_output = self.api.apply_migration(&input).await?;
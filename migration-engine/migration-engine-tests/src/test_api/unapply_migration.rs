@@ -7,6 +7,7 @@ use migration_core::{
 pub struct UnapplyMigration<'a> {
     pub(super) api: &'a dyn GenericApi,
     pub(super) force: Option<bool>,
+    pub(super) dry_run: Option<bool>,
 }
 
 impl UnapplyMigration<'_> {
@@ -16,8 +17,17 @@ impl UnapplyMigration<'_> {
         self
     }
 
+    pub fn dry_run(mut self, dry_run: Option<bool>) -> Self {
+        self.dry_run = dry_run;
+
+        self
+    }
+
     pub async fn send(self) -> Result<UnapplyMigrationOutput, anyhow::Error> {
-        let input = UnapplyMigrationInput { force: self.force };
+        let input = UnapplyMigrationInput {
+            force: self.force,
+            dry_run: self.dry_run,
+        };
 
         Ok(self.api.unapply_migration(&input).await?)
     }
@@ -0,0 +1,99 @@
+use barrel::types;
+use migration_connector::MigrationStatus;
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn baselining_records_the_existing_database_without_executing_anything(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Cat", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await?;
+
+    api.insert("Cat").value("name", "Felix").result_raw().await?;
+
+    let output = api.baseline("baseline").await?;
+
+    assert_eq!(output.migration_id, "baseline");
+    assert!(output.datamodel.contains("model Cat"));
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, "baseline");
+    assert_eq!(migrations[0].status, MigrationStatus::MigrationSuccess);
+    assert!(migrations[0].finished_at.is_some());
+    // The recorded migration creates the table from an empty database.
+    assert_eq!(migrations[0].applied, 1);
+
+    let rows = api.select("Cat").column("name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("Felix")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn migrations_after_a_baseline_only_contain_the_new_changes(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Cat", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await?;
+
+    api.insert("Cat").value("name", "Felix").result_raw().await?;
+    api.baseline("baseline").await?;
+
+    let dm = r#"
+        model Cat {
+            id   Int    @id @default(autoincrement())
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send_assert().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.has_table("Cat"));
+    assert!(schema.has_table("Dog"));
+
+    let rows = api.select("Cat").column("name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("Felix")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baselining_a_database_with_a_migration_history_is_an_error(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm)
+        .migration_id(Some("initial"))
+        .send_assert()
+        .await?
+        .assert_green()?;
+
+    let error = api.baseline("baseline").await.unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The database already has a migration history, starting with `initial`."));
+    assert_eq!(api.migration_persistence().load_all().await?.len(), 1);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@
 
 use datamodel::ast::{parser, SchemaAst};
 use migration_connector::steps::*;
-use migration_core::migration::datamodel_migration_steps_inferrer::*;
+use migration_core::migration::{datamodel_differ::Rename, datamodel_migration_steps_inferrer::*};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -20,6 +20,7 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
     let expected = &[
         MigrationStep::CreateModel(CreateModel {
             model: "Test".to_string(),
+            is_composite_type: false,
         }),
         MigrationStep::CreateField(CreateField {
             model: "Test".to_string(),
@@ -312,6 +313,130 @@ fn infer_UpdateField_simple() {
     assert_eq!(steps, expected);
 }
 
+#[test]
+fn infer_UpdateField_for_a_renamed_field_with_the_same_database_name() {
+    let dm1 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            name String @map("full_name")
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            fullName String @map("full_name")
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+    let expected = &[MigrationStep::UpdateField(UpdateField {
+        model: "Test".to_owned(),
+        field: "name".to_owned(),
+        new_name: Some("fullName".to_owned()),
+        tpe: None,
+        arity: None,
+    })];
+
+    assert_eq!(steps, expected);
+}
+
+#[test]
+fn infer_UpdateModel_for_a_renamed_model_with_the_same_database_name() {
+    let dm1 = parse(
+        r#"
+        model Cat {
+            id Int @id
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Pet {
+            id Int @id
+
+            @@map("Cat")
+        }
+    "#,
+    );
+
+    let steps = infer(&dm1, &dm2);
+
+    let directive_location = DirectiveLocation {
+        path: DirectivePath::Model {
+            model: "Pet".to_owned(),
+            arguments: None,
+        },
+        directive: "map".to_owned(),
+    };
+
+    let expected = &[
+        MigrationStep::UpdateModel(UpdateModel {
+            model: "Cat".to_owned(),
+            new_name: Some("Pet".to_owned()),
+        }),
+        MigrationStep::CreateDirective(CreateDirective {
+            location: directive_location.clone(),
+        }),
+        MigrationStep::CreateArgument(CreateArgument {
+            location: ArgumentLocation::Directive(directive_location),
+            argument: "".to_owned(),
+            value: MigrationExpression("\"Cat\"".to_owned()),
+        }),
+    ];
+
+    assert_eq!(steps, expected);
+}
+
+#[test]
+fn renamed_fields_are_rename_candidates_until_confirmed() {
+    let dm1 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            name String
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        model Test {
+            id String @id @default(cuid())
+            fullName String
+        }
+    "#,
+    );
+
+    let rename = Rename {
+        model: "Test".to_owned(),
+        field: Some("name".to_owned()),
+        new_name: "fullName".to_owned(),
+    };
+
+    let inferrer = DataModelMigrationStepsInferrerImplWrapper {};
+    let diff = inferrer.infer_with_renames(&dm1, &dm2, &[]);
+
+    assert_eq!(diff.rename_candidates, &[rename.clone()]);
+    assert_eq!(diff.steps.len(), 2);
+
+    let diff = inferrer.infer_with_renames(&dm1, &dm2, &[rename]);
+
+    assert!(diff.rename_candidates.is_empty());
+    assert_eq!(
+        diff.steps,
+        &[MigrationStep::UpdateField(UpdateField {
+            model: "Test".to_owned(),
+            field: "name".to_owned(),
+            new_name: Some("fullName".to_owned()),
+            tpe: None,
+            arity: None,
+        })]
+    );
+}
+
 #[test]
 fn infer_CreateEnum() {
     let dm1 = SchemaAst::empty();
@@ -1461,6 +1586,7 @@ fn infer_CreateTypeAlias() {
         }),
         MigrationStep::CreateModel(CreateModel {
             model: "User".to_string(),
+            is_composite_type: false,
         }),
         MigrationStep::CreateField(CreateField {
             model: "User".to_string(),
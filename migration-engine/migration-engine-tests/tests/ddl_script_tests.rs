@@ -0,0 +1,85 @@
+use pretty_assertions::assert_eq;
+use sql_migration_connector::render_ddl_script;
+
+const DATAMODEL: &str = r#"
+    model User {
+        id    Int    @id
+        email String @unique
+        role  Role
+        posts Post[]
+    }
+
+    model Post {
+        id     Int  @id
+        author User
+    }
+
+    enum Role {
+        USER
+        ADMIN
+    }
+"#;
+
+#[test]
+fn postgres_ddl_scripts_create_the_schema_enums_and_tables() {
+    let datamodel = datamodel::parse_datamodel(DATAMODEL).unwrap();
+    let script = render_ddl_script(&datamodel, "postgresql://localhost:5432/prisma?schema=app").unwrap();
+
+    assert!(script.starts_with("CREATE SCHEMA IF NOT EXISTS \"app\";\n"));
+    assert!(script.contains("CREATE TYPE \"Role\" AS ENUM ('USER', 'ADMIN');\n"));
+    assert!(script.contains("CREATE TABLE \"app\".\"User\""));
+    assert!(script.contains("CREATE TABLE \"app\".\"Post\""));
+    assert!(script.contains("CREATE UNIQUE INDEX"));
+    assert!(!script.contains(";;"));
+}
+
+#[test]
+fn postgres_ddl_scripts_create_the_schemas_of_models_outside_the_connection_schema() {
+    let datamodel = datamodel::parse_datamodel(
+        r#"
+            datasource db {
+              provider = "postgresql"
+              url      = "postgresql://localhost:5432/prisma?schema=app"
+              schemas  = ["app", "billing"]
+            }
+
+            model User {
+                id Int @id
+
+                @@schema("app")
+            }
+
+            model Invoice {
+                id Int @id
+
+                @@schema("billing")
+            }
+        "#,
+    )
+    .unwrap();
+    let script = render_ddl_script(&datamodel, "postgresql://localhost:5432/prisma?schema=app").unwrap();
+
+    assert!(script.starts_with("CREATE SCHEMA IF NOT EXISTS \"app\";\nCREATE SCHEMA IF NOT EXISTS \"billing\";\n"));
+    assert!(script.contains("CREATE TABLE \"billing\".\"Invoice\""));
+    assert_eq!(script.matches("CREATE SCHEMA").count(), 2);
+}
+
+#[test]
+fn mysql_ddl_scripts_use_the_database_as_schema() {
+    let datamodel = datamodel::parse_datamodel(DATAMODEL).unwrap();
+    let script = render_ddl_script(&datamodel, "mysql://localhost:3306/app").unwrap();
+
+    assert!(script.starts_with("CREATE SCHEMA IF NOT EXISTS `app` DEFAULT CHARACTER SET latin1;\n"));
+    assert!(script.contains("CREATE TABLE `app`.`User`"));
+}
+
+#[test]
+fn ddl_scripts_are_deterministic() {
+    let datamodel = datamodel::parse_datamodel(DATAMODEL).unwrap();
+    let url = "postgresql://localhost:5432/prisma?schema=app";
+
+    assert_eq!(
+        render_ddl_script(&datamodel, url).unwrap(),
+        render_ddl_script(&datamodel, url).unwrap()
+    );
+}
@@ -0,0 +1,130 @@
+use barrel::types;
+use migration_connector::IndexDrift;
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use quaint::prelude::SqlFamily;
+
+const PETS_DM: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+
+    model Dog {
+        id Int @id
+    }
+"#;
+
+#[test_each_connector]
+async fn diagnose_migration_history_reports_no_drift_after_migrating(api: &TestApi) -> TestResult {
+    let output = api.diagnose_migration_history().await?;
+
+    assert_eq!(output.last_migration, None);
+    assert_eq!(output.drift, None);
+
+    api.infer_apply(PETS_DM)
+        .migration_id(Some("initial"))
+        .send_assert()
+        .await?
+        .assert_green()?;
+
+    let output = api.diagnose_migration_history().await?;
+
+    assert_eq!(output.last_migration.as_deref(), Some("initial"));
+    assert_eq!(output.drift, None);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_migration_history_reports_tables_and_columns_changed_by_hand(api: &TestApi) -> TestResult {
+    api.infer_apply(PETS_DM).send_assert().await?.assert_green()?;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Bird", |t| {
+                t.add_column("id", types::primary());
+            });
+            migration.change_table("Cat", |t| {
+                t.add_column("age", types::integer().nullable(true));
+            });
+            migration.drop_table("Dog");
+        })
+        .await?;
+
+    let drift = api.diagnose_migration_history().await?.drift.unwrap();
+
+    assert_eq!(drift.added_tables, vec!["Bird"]);
+    assert_eq!(drift.removed_tables, vec!["Dog"]);
+    assert_eq!(drift.changed_tables.len(), 1);
+    assert_eq!(drift.changed_tables[0].table, "Cat");
+    assert_eq!(drift.changed_tables[0].added_columns, vec!["age"]);
+    assert!(drift.changed_tables[0].removed_columns.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_migration_history_reports_indexes_created_by_hand(api: &TestApi) -> TestResult {
+    api.infer_apply(PETS_DM).send_assert().await?.assert_green()?;
+
+    let create_index = match api.sql_family() {
+        SqlFamily::Mysql => format!("CREATE INDEX `name_idx` ON `{}`.`Cat` (`name`)", api.schema_name()),
+        SqlFamily::Sqlite => format!(
+            "CREATE INDEX \"{}\".\"name_idx\" ON \"Cat\" (\"name\")",
+            api.schema_name()
+        ),
+        _ => format!(
+            "CREATE INDEX \"name_idx\" ON \"{}\".\"Cat\" (\"name\")",
+            api.schema_name()
+        ),
+    };
+
+    api.database().query_raw(&create_index, &[]).await?;
+
+    let drift = api.diagnose_migration_history().await?.drift.unwrap();
+
+    assert!(drift.added_tables.is_empty());
+    assert_eq!(drift.changed_tables.len(), 1);
+    assert_eq!(
+        drift.changed_tables[0].added_indexes,
+        vec![IndexDrift {
+            name: "name_idx".to_owned(),
+            columns: vec!["name".to_owned()],
+            unique: false,
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_migration_history_works_with_migrations_applied_from_a_directory(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", PETS_DM, directory.path())
+        .send()
+        .await?;
+    let applied = api.apply_migrations(directory.path()).send().await?;
+
+    let output = api.diagnose_migration_history().await?;
+
+    assert_eq!(output.last_migration.as_ref(), applied.applied_migration_names.last());
+    assert_eq!(output.drift, None);
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Bird", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let drift = api.diagnose_migration_history().await?.drift.unwrap();
+
+    assert_eq!(drift.added_tables, vec!["Bird"]);
+    assert!(drift.removed_tables.is_empty());
+    assert!(drift.changed_tables.is_empty());
+
+    Ok(())
+}
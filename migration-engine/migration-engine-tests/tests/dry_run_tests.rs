@@ -0,0 +1,133 @@
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+fn raw_sql(database_steps: &[serde_json::Value]) -> Vec<&str> {
+    database_steps
+        .iter()
+        .filter_map(|step| step.get("raw").and_then(|raw| raw.as_str()))
+        .collect()
+}
+
+#[test_each_connector]
+async fn dry_run_apply_renders_the_migration_without_applying_it(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let output = api.infer_apply(dm).dry_run(Some(true)).send().await?;
+    let database_steps = output.database_steps.as_array().unwrap();
+    let sql = raw_sql(database_steps);
+
+    assert_eq!(sql.len(), 1);
+    assert!(sql[0].starts_with("CREATE TABLE"));
+    assert!(output.warnings.is_empty());
+
+    assert!(!api.describe_database().await?.has_table("Cat"));
+    assert!(api.migration_persistence().load_all().await?.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn dry_run_apply_reports_warnings_without_applying_the_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("initial"))
+        .send_assert()
+        .await?
+        .assert_green()?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let output = api
+        .infer_apply(dm2)
+        .dry_run(Some(true))
+        .force(Some(true))
+        .send()
+        .await?;
+
+    assert_eq!(output.warnings.len(), 1);
+    assert!(!raw_sql(output.database_steps.as_array().unwrap()).is_empty());
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, "initial");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn dry_run_unapply_renders_the_rollback_without_rolling_back(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("initial"))
+        .send_assert()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("dogs"))
+        .send_assert()
+        .await?
+        .assert_green()?;
+
+    let output = api.unapply_migration().dry_run(Some(true)).send().await?;
+
+    assert_eq!(output.rolled_back, "dogs");
+    assert_eq!(output.active.as_deref(), Some("initial"));
+
+    let sql = raw_sql(&output.database_steps);
+
+    assert_eq!(sql.len(), 1);
+    assert!(sql[0].starts_with("DROP TABLE"));
+
+    assert!(api.describe_database().await?.has_table("Dog"));
+    assert_eq!(
+        api.migration_persistence()
+            .last()
+            .await?
+            .map(|migration| migration.name)
+            .as_deref(),
+        Some("dogs")
+    );
+
+    Ok(())
+}
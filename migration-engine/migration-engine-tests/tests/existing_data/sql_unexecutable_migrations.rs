@@ -1,3 +1,5 @@
 mod added_required_field_to_table;
 mod added_unimplementable_unique_constraint;
+mod deleted_used_enum_value;
 mod made_optional_field_required;
+mod primary_key_changed;
@@ -0,0 +1,58 @@
+use migration_engine_tests::sql::*;
+use quaint::ast::ParameterizedValue;
+
+#[test_each_connector(capabilities("enums"))]
+async fn removing_an_enum_value_that_is_still_used_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+            HUNGRY
+        }
+    "#;
+
+    api.infer_apply(dm1).send_assert().await?.assert_green()?;
+
+    let cat_inserts = quaint::ast::Insert::multi_into(api.render_table_name("Cat"), vec!["id", "mood"])
+        .values((
+            ParameterizedValue::Text("felix".into()),
+            ParameterizedValue::Enum("HUNGRY".into()),
+        ))
+        .values((
+            ParameterizedValue::Text("mittens".into()),
+            ParameterizedValue::Enum("HUNGRY".into()),
+        ));
+
+    api.database().query(cat_inserts.into()).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            mood Mood
+        }
+
+        enum Mood {
+            HAPPY
+        }
+    "#;
+
+    let output = api.infer(dm2).send().await?;
+
+    // On MySQL, each enum column has its own enum.
+    let enum_name = if api.is_mysql() { "Cat_mood" } else { "Mood" };
+
+    assert_eq!(output.unexecutable_migrations.len(), 1);
+    assert_eq!(
+        output.unexecutable_migrations[0].description,
+        format!(
+            "Removed the value `HUNGRY` from the `{}` enum, but it is still used 2 times in the column `mood` of the `Cat` table.",
+            enum_name
+        )
+    );
+
+    Ok(())
+}
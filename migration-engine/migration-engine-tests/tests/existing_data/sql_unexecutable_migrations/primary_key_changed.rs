@@ -0,0 +1,61 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector]
+async fn changing_the_primary_key_of_a_table_with_data_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(&dm1).send_assert().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "george")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String
+            name String @id
+        }
+    "#;
+
+    let output = api.infer(dm2).send().await?;
+
+    assert_eq!(output.unexecutable_migrations.len(), 1);
+    assert_eq!(
+        output.unexecutable_migrations[0].description,
+        "Changed the primary key of the `Test` table to (`name`), but there are 1 rows in this table, it is not possible."
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn changing_the_primary_key_of_an_empty_table_works(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(&dm1).send_assert().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Test {
+            id String
+            name String @id
+        }
+    "#;
+
+    let output = api.infer(dm2).send().await?;
+
+    assert!(output.unexecutable_migrations.is_empty());
+
+    Ok(())
+}
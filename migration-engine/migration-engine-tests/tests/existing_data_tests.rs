@@ -224,7 +224,7 @@ async fn altering_a_column_with_non_null_values_should_warn(api: &TestApi) -> Te
     let dm2 = r#"
         model Test {
             id String @id @default(cuid())
-            age Float?
+            age DateTime?
         }
     "#;
 
@@ -668,9 +668,7 @@ async fn making_a_column_required_in_an_empty_table_should_not_warn(api: &TestAp
 }
 
 #[test_each_connector]
-async fn altering_the_type_of_a_column_in_a_non_empty_table_always_warns(
-    api: &TestApi,
-) -> TestResult {
+async fn altering_the_type_of_a_column_with_a_safe_cast_preserves_the_data(api: &TestApi) -> TestResult {
     let dm1 = r#"
         model User {
             id String @id @default(cuid())
@@ -698,30 +696,21 @@ async fn altering_the_type_of_a_column_in_a_non_empty_table_always_warns(
 
     let response = api.infer_apply(dm2).send().await?;
 
-    assert_eq!(
-        response.warnings,
-        &[MigrationWarning {
-            // TODO: the message should say that altering the type of a column is not guaranteed to preserve the data, but the database is going to do its best.
-            // Also think about timeouts.
-            description: "You are about to alter the column `dogs` on the `User` table, which still contains 1 non-null values. The data in that column will be lost.".to_owned()
-        }]
-    );
+    assert!(response.warnings.is_empty(), "{:?}", response.warnings);
 
     let rows = api.select("User").column("dogs").send_debug().await?;
-    assert_eq!(rows, &[["Integer(7)"]]);
+    assert_eq!(rows, &[[r#"Text("7")"#]]);
 
     api.assert_schema()
         .await?
         .assert_table("User", |table| {
-            table.assert_column("dogs", |col| col.assert_type_is_int()?.assert_is_required())
+            table.assert_column("dogs", |col| col.assert_type_is_string()?.assert_is_required())
         })
         .map(drop)
 }
 
-#[test_each_connector(ignore("mysql"))]
-async fn migrating_a_required_column_from_int_to_string_should_warn_and_cast(
-    api: &TestApi,
-) -> TestResult {
+#[test_each_connector]
+async fn migrating_a_required_column_from_int_to_string_should_warn_and_cast(api: &TestApi) -> TestResult {
     let dm1 = r#"
         model Test {
             id String @id
@@ -740,57 +729,122 @@ async fn migrating_a_required_column_from_int_to_string_should_warn_and_cast(
     let test = api.dump_table("Test").await?;
     let first_row = test.get(0).unwrap();
     assert_eq!(
-        format!(
-            "{:?} {:?}",
-            first_row.get("id"),
-            first_row.get("serialNumber")
-        ),
+        format!("{:?} {:?}", first_row.get("id"), first_row.get("serialNumber")),
         r#"Some(Text("abcd")) Some(Integer(47))"#
     );
 
-    let original_schema = api.assert_schema().await?.into_schema();
+    let dm2 = r#"
+        model Test {
+            id String @id
+            serialNumber String
+        }
+    "#;
+
+    // Every int has a string representation, so nothing is lost.
+    let result = api.infer_apply(dm2).send().await?;
+
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column("serialNumber", |col| col.assert_type_is_string())
+    })?;
+
+    let test = api.dump_table("Test").await?;
+    let first_row = test.get(0).unwrap();
+    assert_eq!(
+        format!("{:?} {:?}", first_row.get("id"), first_row.get("serialNumber")),
+        r#"Some(Text("abcd")) Some(Text("47"))"#
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn migrating_a_required_column_from_string_to_int_casts_the_values(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            serialNumber String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?;
+
+    api.insert("Test")
+        .value("id", "abcd")
+        .value("serialNumber", "47")
+        .result_raw()
+        .await?;
 
     let dm2 = r#"
+        model Test {
+            id String @id
+            serialNumber Int
+        }
+    "#;
+
+    let result = api.infer_apply(dm2).send().await?;
+
+    assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column("serialNumber", |col| col.assert_type_is_int())
+    })?;
+
+    let test = api.dump_table("Test").await?;
+    let first_row = test.get(0).unwrap();
+    assert_eq!(
+        format!("{:?} {:?}", first_row.get("id"), first_row.get("serialNumber")),
+        r#"Some(Text("abcd")) Some(Integer(47))"#
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn migrating_a_required_column_from_string_to_int_warns_about_values_that_cannot_be_cast(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
         model Test {
             id String @id
             serialNumber String
         }
     "#;
 
-    let expected_warning = MigrationWarning {
-        description: "You are about to alter the column `serialNumber` on the `Test` table, which still contains 1 non-null values. The data in that column will be lost.".to_owned(),
-    };
+    api.infer_apply(dm1).send().await?;
 
-    // Apply once without forcing
-    {
-        let result = api.infer_apply(dm2).send().await?;
+    api.insert("Test")
+        .value("id", "abcd")
+        .value("serialNumber", "47")
+        .result_raw()
+        .await?;
 
-        assert_eq!(result.warnings, &[expected_warning.clone()]);
+    api.insert("Test")
+        .value("id", "efgh")
+        .value("serialNumber", "forty-eight")
+        .result_raw()
+        .await?;
 
-        api.assert_schema().await?.assert_equals(&original_schema)?;
-    }
+    let original_schema = api.assert_schema().await?.into_schema();
 
-    // Force apply
-    {
-        let result = api.infer_apply(dm2).force(Some(true)).send().await?;
+    let dm2 = r#"
+        model Test {
+            id String @id
+            serialNumber Int
+        }
+    "#;
 
-        assert_eq!(result.warnings, &[expected_warning]);
+    let result = api.infer_apply(dm2).send().await?;
 
-        api.assert_schema().await?.assert_table("Test", |table| {
-            table.assert_column("serialNumber", |col| col.assert_type_is_string())
-        })?;
+    assert_eq!(
+        result.warnings,
+        &[MigrationWarning {
+            description: "You are about to change the type of the column `serialNumber` on the `Test` table from string to int. 1 of the values in that column cannot be cast to the new type without failing or losing information.".to_owned(),
+        }]
+    );
 
-        let test = api.dump_table("Test").await?;
-        let first_row = test.get(0).unwrap();
-        assert_eq!(
-            format!(
-                "{:?} {:?}",
-                first_row.get("id"),
-                first_row.get("serialNumber")
-            ),
-            r#"Some(Text("abcd")) Some(Text("47"))"#
-        );
-    }
+    api.assert_schema().await?.assert_equals(&original_schema)?;
 
     Ok(())
 }
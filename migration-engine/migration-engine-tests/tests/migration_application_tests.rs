@@ -0,0 +1,80 @@
+use migration_connector::MigrationStatus;
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+const CATS_DM: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+#[test_each_connector]
+async fn migration_progress_reports_the_applied_database_steps(api: &TestApi) -> TestResult {
+    api.infer_apply(CATS_DM)
+        .migration_id(Some("cats"))
+        .send_assert()
+        .await?
+        .assert_green()?;
+
+    let progress = api.migration_progress("cats").await?;
+
+    assert_eq!(progress.status, MigrationStatus::MigrationSuccess);
+    assert!(progress.steps > 0);
+    assert_eq!(progress.applied, progress.steps);
+    assert!(progress.errors.is_empty());
+    assert!(progress.finished_at.is_some());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn failed_migrations_are_rolled_back_on_databases_with_transactional_ddl(api: &TestApi) -> TestResult {
+    api.infer_apply(CATS_DM).send_assert().await?.assert_green()?;
+
+    for id in 1..=2 {
+        api.insert("Cat")
+            .value("id", id)
+            .value("name", "Felix")
+            .result_raw()
+            .await?;
+    }
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @unique
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("unique-names"))
+        .force(Some(true))
+        .send()
+        .await
+        .unwrap_err();
+
+    let progress = api.migration_progress("unique-names").await?;
+
+    assert_eq!(progress.status, MigrationStatus::MigrationFailure);
+    assert_eq!(progress.errors.len(), 1);
+    assert_eq!(progress.finished_at, None);
+
+    let schema = api.describe_database().await?;
+
+    if api.is_mysql() {
+        // The steps before the unique index stay applied, and the record says how many they are.
+        assert!(schema.has_table("Dog"));
+        assert!(progress.applied > 0 && progress.applied < progress.steps);
+        assert!(progress.errors[0].starts_with(&format!("Step {} of {} failed", progress.applied + 1, progress.steps)));
+    } else {
+        assert!(!schema.has_table("Dog"));
+        assert_eq!(progress.applied, 0);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use migration_connector::MigrationConnector;
+use migration_core::{
+    api::{render_error, GenericApi, MigrationApi},
+    commands::UnapplyMigrationInput,
+};
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+#[test_each_connector]
+async fn the_migration_lock_is_held_by_one_connection_at_a_time(api: &TestApi) -> TestResult {
+    let other_connector = api.new_connector().await?;
+
+    api.connector().acquire_lock(Duration::from_secs(1)).await?;
+
+    let error = other_connector
+        .acquire_lock(Duration::from_millis(500))
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.user_facing_error.map(|error| error.error_code), Some("P3003"));
+
+    api.connector().release_lock().await?;
+
+    other_connector.acquire_lock(Duration::from_secs(1)).await?;
+    other_connector.release_lock().await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn commands_changing_the_database_time_out_while_another_engine_holds_the_lock(api: &TestApi) -> TestResult {
+    let other_api = MigrationApi::new(api.new_connector().await?)
+        .await?
+        .with_lock_timeout(Duration::from_millis(500));
+    let input = UnapplyMigrationInput {
+        force: None,
+        dry_run: None,
+    };
+
+    api.connector().acquire_lock(Duration::from_secs(1)).await?;
+
+    let error = other_api.unapply_migration(&input).await.unwrap_err();
+    let json_error = serde_json::to_value(&render_error(error))?;
+
+    api.connector().release_lock().await?;
+
+    assert_eq!(json_error["error_code"], "P3003");
+    assert_eq!(json_error["meta"]["timeout_seconds"], 1);
+
+    // Once the lock is released, the command goes through.
+    other_api.unapply_migration(&input).await?;
+
+    Ok(())
+}
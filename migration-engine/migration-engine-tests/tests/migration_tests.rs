@@ -244,6 +244,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -272,6 +273,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -326,6 +328,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 },
                 columns: vec!["b".to_owned()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             })
@@ -396,6 +399,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -467,6 +471,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 },
                 columns: vec![a_column.name.clone()],
                 referenced_table: "A".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             },
@@ -478,6 +483,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             }
@@ -532,6 +538,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
             },
@@ -543,6 +550,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![c_column.name.clone()],
                 referenced_table: "C".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
             }
@@ -576,6 +584,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -608,6 +617,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -677,6 +687,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["b".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -704,6 +715,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["a".to_string()],
             referenced_table: "A".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -953,6 +965,7 @@ async fn index_renaming_must_work(api: &TestApi) -> TestResult {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_new_name: "customNameA".into(),
             index_name: "customName".into(),
         })];
@@ -1006,6 +1019,7 @@ async fn index_renaming_must_work_when_renaming_to_default(api: &TestApi) {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_new_name: "A.field_secondField".into(),
             index_name: "customName".into(),
         })];
@@ -1056,6 +1070,7 @@ async fn index_renaming_must_work_when_renaming_to_custom(api: &TestApi) -> Test
     if !api.is_sqlite() {
         let expected_steps = &[SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_name: "A.field_secondField".into(),
             index_new_name: "somethingCustom".into(),
         })];
@@ -1109,14 +1124,20 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
         let expected_steps = vec![
             SqlMigrationStep::DropIndex(DropIndex {
                 table: "A".into(),
+                schema: None,
                 name: "customName".into(),
             }),
             SqlMigrationStep::CreateIndex(CreateIndex {
                 table: "A".into(),
+                schema: None,
                 index: Index {
                     name: "customNameA".into(),
                     columns: vec!["field".into(), "id".into()],
                     tpe: IndexType::Unique,
+                    sort_orders: vec![],
+                    expressions: vec![],
+                    predicate: None,
+                    method: None,
                 },
             }),
         ];
@@ -1175,6 +1196,7 @@ async fn reserved_sql_key_words_must_work(api: &TestApi) {
             },
             columns: vec!["parent".to_string()],
             referenced_table: "Group".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::SetNull,
         }]
@@ -1337,6 +1359,36 @@ async fn model_with_multiple_indexes_works(api: &TestApi) -> TestResult {
     Ok(())
 }
 
+#[test_each_connector]
+async fn indexes_on_compound_relation_fields_work(api: &TestApi) -> TestResult {
+    let dm = r#"
+    model User {
+      id        Int       @id
+      firstName String
+      lastName  String
+
+      @@unique([firstName, lastName])
+    }
+
+    model Post {
+      id        Int       @id
+      createdAt DateTime
+      author    User      @relation(references: [firstName, lastName])
+
+      @@index([author, createdAt])
+    }
+    "#;
+
+    api.infer_apply(dm).send_assert().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table.assert_index_on_columns(&["author_firstName", "author_lastName", "createdAt"], |idx| {
+            idx.assert_is_not_unique()
+        })
+    })?;
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(api: &TestApi) {
     let dm = r#"
@@ -1359,6 +1411,10 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat".into(),
         columns: vec!["cat".into()],
         tpe: IndexType::Unique,
+        sort_orders: vec![],
+        expressions: vec![],
+        predicate: None,
+        method: None,
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
@@ -72,3 +72,64 @@ async fn index_settings_must_be_migrated(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn partial_and_expression_indexes_are_not_dropped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?;
+
+    let partial_index = format!(
+        "CREATE INDEX \"named_cats\" ON \"{}\".\"Cat\" (\"name\") WHERE \"name\" <> ''",
+        api.schema_name()
+    );
+    let expression_index = format!(
+        "CREATE INDEX \"lower_name\" ON \"{}\".\"Cat\" (lower(\"name\"))",
+        api.schema_name()
+    );
+
+    api.database().query_raw(&partial_index, &[]).await?;
+    api.database().query_raw(&expression_index, &[]).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("age")?.assert_indexes_count(2))?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn descending_indexes_are_rejected_on_mysql_5_7() -> TestResult {
+    let api = mysql_test_api("descending_indexes_are_rejected_on_mysql_5_7").await;
+
+    let dm = r#"
+        model Cat {
+            id String @id
+            name String
+            age Int
+
+            @@index([name, age], sort: [Asc, Desc])
+        }
+    "#;
+
+    let error = api.infer_apply(dm).send().await.unwrap_err();
+
+    assert!(format!("{:#}", error).contains("descending indexes are not supported by this database version"));
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use migration_engine_tests::sql::*;
+use sql_schema_describer::GeneratedColumnKind;
 
 /// We need to test this specifically for mysql, because foreign keys are indexes, and they are
 /// inferred as both foreign key and index by the sql-schema-describer. We do not want to
@@ -75,3 +76,41 @@ async fn enum_creation_is_idempotent(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn computed_fields_are_created_as_generated_columns(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Order {
+            id          Int     @id
+            price       Int
+            quantity    Int
+            total       Int     @computed("price * quantity")
+            totalView   Int     @computed("price * quantity", stored: false)
+        }
+    "#;
+
+    api.infer_apply(dm).send_assert().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Order");
+
+    let total = table.column_bang("total").generated.as_ref().expect("total is generated");
+    assert_eq!(total.kind, GeneratedColumnKind::Stored);
+
+    let total_view = table
+        .column_bang("totalView")
+        .generated
+        .as_ref()
+        .expect("totalView is generated");
+    assert_eq!(total_view.kind, GeneratedColumnKind::Virtual);
+
+    let output = api.infer_apply(dm).send().await?;
+
+    anyhow::ensure!(
+        output.database_steps.as_array().map(|steps| steps.is_empty()).unwrap_or(false),
+        "Expected no database steps, found {:?}",
+        output.database_steps
+    );
+
+    Ok(())
+}
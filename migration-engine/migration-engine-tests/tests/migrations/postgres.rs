@@ -65,3 +65,32 @@ async fn adding_a_scalar_list_for_a_model_with_id_type_int_must_work(api: &TestA
     assert_eq!(enum_column.tpe.family, ColumnTypeFamily::Enum("Status".to_owned()));
     assert_eq!(enum_column.tpe.arity, ColumnArity::List);
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn dbgenerated_defaults_are_migrated_verbatim_and_do_not_cause_spurious_diffs(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id          Int         @id
+            birthday    DateTime    @default(dbgenerated("CURRENT_DATE"))
+            litterSize  Int         @default(dbgenerated("(1 + 2)"))
+        }
+    "#;
+
+    api.infer_apply(dm).send_assert().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("birthday", |col| col.assert_default(Some("CURRENT_DATE")))?
+            .assert_column("litterSize", |col| col.assert_default(Some("(1 + 2)")))
+    })?;
+
+    let output = api.infer_apply(dm).send().await?;
+
+    anyhow::ensure!(
+        output.database_steps.as_array().map(|steps| steps.is_empty()).unwrap_or(false),
+        "Expected no database steps, found {:?}",
+        output.database_steps
+    );
+
+    Ok(())
+}
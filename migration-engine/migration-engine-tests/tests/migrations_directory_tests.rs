@@ -0,0 +1,271 @@
+use migration_connector::{migrations_directory::list_migrations, MigrationStatus};
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use quaint::prelude::SqlFamily;
+
+const CATS_DM: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"#;
+
+#[test_each_connector]
+async fn create_migration_writes_a_script_without_applying_it(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let output = api
+        .create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+    let migration_name = output.generated_migration_name.unwrap();
+
+    assert!(migration_name.ends_with("_initial"));
+
+    let migrations = list_migrations(directory.path())?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].migration_name(), migration_name);
+    assert!(migrations[0].read_migration_script()?.contains("CREATE TABLE"));
+    assert!(!api.describe_database().await?.has_table("Cat"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn apply_migrations_applies_the_unapplied_migrations_in_order(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    let first = api
+        .create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+    let output = api.apply_migrations(directory.path()).send().await?;
+
+    assert_eq!(
+        output.applied_migration_names,
+        vec![first.generated_migration_name.unwrap()]
+    );
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let second = api.create_migration("dogs", dm2, directory.path()).send().await?;
+    let output = api.apply_migrations(directory.path()).send().await?;
+
+    assert_eq!(
+        output.applied_migration_names,
+        vec![second.generated_migration_name.unwrap()]
+    );
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?
+        .assert_table("Dog", |table| table.assert_has_column("id"))?;
+
+    let output = api.apply_migrations(directory.path()).send().await?;
+
+    assert!(output.applied_migration_names.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_does_nothing_when_the_database_is_in_sync(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(directory.path()).send().await?;
+
+    let output = api.create_migration("again", CATS_DM, directory.path()).send().await?;
+
+    assert_eq!(output.generated_migration_name, None);
+    assert_eq!(list_migrations(directory.path())?.len(), 1);
+
+    let output = api
+        .create_migration("draft", CATS_DM, directory.path())
+        .draft(true)
+        .send()
+        .await?;
+
+    let migrations = list_migrations(directory.path())?;
+
+    assert_eq!(migrations.len(), 2);
+    assert_eq!(migrations[1].migration_name(), output.generated_migration_name.unwrap());
+    assert_eq!(migrations[1].read_migration_script()?, "");
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_refuses_to_run_with_unapplied_migrations(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+
+    let error = api
+        .create_migration("second", CATS_DM, directory.path())
+        .send()
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("not applied yet"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn hand_edited_scripts_are_applied_as_edited(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+
+    let migration = list_migrations(directory.path())?.remove(0);
+    let extra_table = match api.sql_family() {
+        SqlFamily::Mysql => format!("CREATE TABLE `{}`.`Extra` (id INTEGER PRIMARY KEY);", api.schema_name()),
+        _ => format!(
+            "CREATE TABLE \"{}\".\"Extra\" (id INTEGER PRIMARY KEY);",
+            api.schema_name()
+        ),
+    };
+    let script = format!(
+        "{}\n-- Added by hand; not generated.\n{}\n",
+        migration.read_migration_script()?,
+        extra_table
+    );
+
+    migration.write_migration_script(&script)?;
+    api.apply_migrations(directory.path()).send().await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?
+        .assert_table("Extra", |table| table.assert_has_column("id"))?;
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mysql"))]
+async fn migrations_failing_partway_are_rolled_back(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+
+    // The script creates the Cat table, then fails on the duplicate.
+    let migration = list_migrations(directory.path())?.remove(0);
+    let script = format!(
+        "{}\nCREATE TABLE \"{}\".\"Cat\" (id INTEGER PRIMARY KEY);\n",
+        migration.read_migration_script()?,
+        api.schema_name()
+    );
+
+    migration.write_migration_script(&script)?;
+
+    api.apply_migrations(directory.path()).send().await.unwrap_err();
+
+    assert!(!api.describe_database().await?.has_table("Cat"));
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].status, MigrationStatus::MigrationFailure);
+    assert_eq!(migrations[0].applied, 0);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn migrations_edited_after_they_were_applied_are_detected(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(directory.path()).send().await?;
+
+    let migration = list_migrations(directory.path())?.remove(0);
+    let script = migration.read_migration_script()?;
+
+    migration.write_migration_script(&format!("{}\n-- edited\n", script))?;
+
+    let error = api.apply_migrations(directory.path()).send().await.unwrap_err();
+
+    assert!(error.to_string().contains(&format!(
+        "The migration `{}` was modified after it was applied.",
+        migration.migration_name()
+    )));
+
+    // Line endings don't count as edits.
+    migration.write_migration_script(&script.replace("\n", "\r\n"))?;
+    api.apply_migrations(directory.path()).send().await?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn migrations_missing_from_the_directory_are_detected(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("initial", CATS_DM, directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(directory.path()).send().await?;
+
+    let migration = list_migrations(directory.path())?.remove(0);
+    std::fs::remove_dir_all(migration.path())?;
+
+    let error = api.apply_migrations(directory.path()).send().await.unwrap_err();
+
+    assert!(error.to_string().contains(&format!(
+        "The migration `{}` was applied, but it is missing from the migrations directory.",
+        migration.migration_name()
+    )));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn failed_migrations_are_recorded_and_block_further_migrations(api: &TestApi) -> TestResult {
+    let directory = api.create_migrations_directory()?;
+
+    api.create_migration("broken", CATS_DM, directory.path()).send().await?;
+
+    let migration = list_migrations(directory.path())?.remove(0);
+    migration.write_migration_script("SELECT * FROM this_table_does_not_exist;\n")?;
+
+    api.apply_migrations(directory.path()).send().await.unwrap_err();
+
+    let recorded = api
+        .migration_persistence()
+        .by_name(migration.migration_name())
+        .await?
+        .unwrap();
+
+    assert_eq!(recorded.status, MigrationStatus::MigrationFailure);
+    assert!(recorded.checksum.is_some());
+
+    let error = api.apply_migrations(directory.path()).send().await.unwrap_err();
+
+    assert!(error.to_string().contains(&format!(
+        "The migration `{}` failed to apply",
+        migration.migration_name()
+    )));
+
+    Ok(())
+}
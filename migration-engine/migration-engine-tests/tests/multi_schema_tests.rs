@@ -0,0 +1,129 @@
+use migration_core::{
+    api::GenericApi,
+    commands::{ApplyMigrationInput, MigrationStepsResultOutput},
+    InferMigrationStepsInput,
+};
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use quaint::prelude::Queryable;
+use std::sync::Arc;
+
+fn postgres_schema_with_billing_schema(db_name: &str, datamodel: &str) -> String {
+    format!(
+        r#"
+            datasource db {{
+              provider = "postgresql"
+              url      = "{}"
+              schemas  = ["prisma-tests", "billing"]
+            }}
+
+            {}
+        "#,
+        postgres_10_url(db_name),
+        datamodel
+    )
+}
+
+async fn infer(
+    api: &Arc<dyn GenericApi>,
+    migration_id: &str,
+    datamodel: &str,
+) -> anyhow::Result<MigrationStepsResultOutput> {
+    Ok(api
+        .infer_migration_steps(&InferMigrationStepsInput {
+            migration_id: migration_id.to_owned(),
+            datamodel: datamodel.to_owned(),
+            assume_to_be_applied: None,
+            assume_applied_migrations: None,
+            confirmed_renames: None,
+        })
+        .await?)
+}
+
+async fn infer_and_apply(api: &Arc<dyn GenericApi>, migration_id: &str, datamodel: &str) -> anyhow::Result<()> {
+    let output = infer(api, migration_id, datamodel).await?;
+
+    api.apply_migration(&ApplyMigrationInput {
+        migration_id: migration_id.to_owned(),
+        steps: output.datamodel_steps,
+        force: None,
+        dry_run: None,
+    })
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tables_with_the_same_name_in_different_schemas_are_migrated_separately() -> TestResult {
+    let db_name = "tables_with_the_same_name_in_different_schemas_are_migrated_separately";
+    let url = postgres_10_url(db_name);
+    let connection = create_postgres_database(&url.parse()?).await?;
+    connection.query_raw("CREATE SCHEMA \"billing\"", &[]).await?;
+
+    let dm1 = postgres_schema_with_billing_schema(
+        db_name,
+        r#"
+            model Cat {
+                id   Int    @id
+                name String @unique
+
+                @@schema("prisma-tests")
+            }
+
+            model BillingCat {
+                id   Int    @id
+                name String @unique
+
+                @@map("Cat")
+                @@schema("billing")
+            }
+        "#,
+    );
+
+    let api = migration_core::migration_api(&dm1).await?;
+    api.reset(&serde_json::Value::Null).await?;
+
+    infer_and_apply(&api, "initial", &dm1).await?;
+
+    // Describing the default schema gives back unqualified tables, so nothing is left to migrate.
+    let output = infer(&api, "noop", &dm1).await?;
+    assert_eq!(output.database_steps, serde_json::json!([]));
+
+    let dm2 = postgres_schema_with_billing_schema(
+        db_name,
+        r#"
+            model Cat {
+                id   Int    @id
+                name String @unique
+
+                @@schema("prisma-tests")
+            }
+
+            model BillingCat {
+                id   Int    @id
+                name String
+
+                @@map("Cat")
+                @@schema("billing")
+            }
+        "#,
+    );
+
+    infer_and_apply(&api, "drop-billing-unique", &dm2).await?;
+
+    let rows = connection
+        .query_raw(
+            "SELECT schemaname FROM pg_indexes WHERE tablename = 'Cat' AND indexdef LIKE 'CREATE UNIQUE INDEX%(name)' ORDER BY schemaname",
+            &[],
+        )
+        .await?;
+    let schemas: Vec<String> = rows
+        .into_iter()
+        .map(|row| row.get("schemaname").unwrap().as_str().unwrap().to_owned())
+        .collect();
+
+    assert_eq!(schemas, &["prisma-tests"]);
+
+    Ok(())
+}
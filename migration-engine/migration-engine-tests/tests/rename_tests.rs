@@ -0,0 +1,157 @@
+use migration_core::commands::Rename;
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn changing_the_mapped_name_of_a_field_renames_the_column(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send_assert().await?.assert_green()?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @map("full_name")
+        }
+    "#;
+
+    api.infer_apply(dm2).send_assert().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_does_not_have_column("name")?
+            .assert_has_column("full_name")
+    })?;
+
+    let rows = api.select("Cat").column("full_name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("Felix")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn changing_the_mapped_name_of_a_model_renames_the_table(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send_assert().await?.assert_green()?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+    "#;
+
+    api.infer_apply(dm2).send_assert().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(!schema.has_table("Cat"));
+    assert!(schema.has_table("cats"));
+
+    let rows = api.select("cats").column("name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("Felix")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn renamed_fields_are_only_renamed_once_confirmed(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send_assert().await?.assert_green()?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id       Int    @id
+            fullName String
+        }
+    "#;
+
+    let rename = Rename {
+        model: "Cat".to_owned(),
+        field: Some("name".to_owned()),
+        new_name: "fullName".to_owned(),
+    };
+
+    let output = api.infer(dm2).send().await?;
+
+    assert_eq!(output.rename_candidates, &[rename.clone()]);
+
+    api.infer_apply(dm2)
+        .confirmed_renames(Some(vec![rename]))
+        .send_assert()
+        .await?
+        .assert_green()?;
+
+    let rows = api.select("Cat").column("fullName").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("Felix")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn renames_are_undone_when_unapplying_the_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send_assert().await?.assert_green()?;
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Felix")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String @map("full_name")
+
+            @@map("cats")
+        }
+    "#;
+
+    api.infer_apply(dm2).send_assert().await?.assert_green()?;
+    api.unapply_migration().send().await?;
+
+    let rows = api.select("Cat").column("name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("Felix")"#]]);
+
+    Ok(())
+}
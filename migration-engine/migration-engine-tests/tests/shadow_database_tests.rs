@@ -0,0 +1,190 @@
+use migration_core::{
+    api::GenericApi,
+    commands::{ApplyMigrationInput, ApplyMigrationsInput, CreateMigrationInput},
+    InferMigrationStepsInput,
+};
+use migration_engine_tests::sql::*;
+use quaint::{ast::ParameterizedValue, prelude::Queryable, single::Quaint};
+use std::sync::Arc;
+
+fn sqlite_schema_with_shadow_database(db_name: &str, datamodel: &str) -> String {
+    format!(
+        r#"
+            datasource db {{
+              provider          = "sqlite"
+              url               = "{}"
+              shadowDatabaseUrl = "{}"
+            }}
+
+            {}
+        "#,
+        sqlite_test_url(db_name),
+        sqlite_test_url(&format!("{}_shadow", db_name)),
+        datamodel
+    )
+}
+
+async fn infer_and_apply(api: &Arc<dyn GenericApi>, migration_id: &str, datamodel: &str) -> anyhow::Result<()> {
+    let output = api
+        .infer_migration_steps(&InferMigrationStepsInput {
+            migration_id: migration_id.to_owned(),
+            datamodel: datamodel.to_owned(),
+            assume_to_be_applied: None,
+            assume_applied_migrations: None,
+            confirmed_renames: None,
+        })
+        .await?;
+
+    api.apply_migration(&ApplyMigrationInput {
+        migration_id: migration_id.to_owned(),
+        steps: output.datamodel_steps,
+        force: None,
+        dry_run: None,
+    })
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn the_migration_history_is_replayed_in_the_shadow_database() -> TestResult {
+    let db_name = "the_migration_history_is_replayed_in_the_shadow_database";
+    let cats = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+    let cats_and_dogs = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let api = migration_core::migration_api(&sqlite_schema_with_shadow_database(db_name, cats)).await?;
+    api.reset(&serde_json::Value::Null).await?;
+
+    infer_and_apply(&api, "initial", cats).await?;
+    infer_and_apply(&api, "dogs", cats_and_dogs).await?;
+
+    // Pretend the first migration was edited by hand after it was applied.
+    let connection = Quaint::new(&sqlite_test_url(db_name)).await?;
+    connection
+        .query_raw(
+            "UPDATE \"_Migration\" SET datamodel = ? WHERE name = ?",
+            &[
+                ParameterizedValue::from(cats_and_dogs),
+                ParameterizedValue::from("initial"),
+            ],
+        )
+        .await?;
+    connection
+        .query_raw(
+            "DELETE FROM \"_Migration\" WHERE name = ?",
+            &[ParameterizedValue::from("dogs")],
+        )
+        .await?;
+
+    let error = infer_and_apply(&api, "next", cats_and_dogs).await.unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .contains("The migration history does not reproduce the schema of the last migration"),
+        "{}",
+        error
+    );
+    assert!(error.to_string().contains("Removed tables: Dog."), "{}", error);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn migrations_applied_from_a_directory_are_replayed_in_the_shadow_database() -> TestResult {
+    let db_name = "migrations_applied_from_a_directory_are_replayed_in_the_shadow_database";
+    let cats = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+    let cats_and_dogs = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let api = migration_core::migration_api(&sqlite_schema_with_shadow_database(db_name, cats)).await?;
+    api.reset(&serde_json::Value::Null).await?;
+
+    let migrations_directory = tempfile::tempdir()?;
+    let migrations_directory_path = migrations_directory.path().to_string_lossy().into_owned();
+
+    api.create_migration(&CreateMigrationInput {
+        migrations_directory_path: migrations_directory_path.clone(),
+        datamodel: cats.to_owned(),
+        migration_name: "initial".to_owned(),
+        draft: false,
+    })
+    .await?;
+    api.apply_migrations(&ApplyMigrationsInput {
+        migrations_directory_path,
+    })
+    .await?;
+
+    infer_and_apply(&api, "dogs", cats_and_dogs).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn the_migration_history_is_not_replayed_for_watch_migrations() -> TestResult {
+    let db_name = "the_migration_history_is_not_replayed_for_watch_migrations";
+    let cats = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+    let cats_and_dogs = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    let api = migration_core::migration_api(&sqlite_schema_with_shadow_database(db_name, cats)).await?;
+    api.reset(&serde_json::Value::Null).await?;
+
+    infer_and_apply(&api, "initial", cats).await?;
+
+    // The history does not reproduce the datamodel anymore.
+    let connection = Quaint::new(&sqlite_test_url(db_name)).await?;
+    connection
+        .query_raw(
+            "UPDATE \"_Migration\" SET datamodel = ? WHERE name = ?",
+            &[
+                ParameterizedValue::from(cats_and_dogs),
+                ParameterizedValue::from("initial"),
+            ],
+        )
+        .await?;
+
+    infer_and_apply(&api, "watch-cats-and-dogs", cats_and_dogs).await?;
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use migration_connector::MigrationStatus;
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+const CAT_DM: &str = r#"
+    model Cat {
+        id Int @id
+    }
+"#;
+
+const CAT_AND_DOG_DM: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+
+    model Dog {
+        id Int @id
+    }
+"#;
+
+const CAT_DOG_AND_BIRD_DM: &str = r#"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+
+    model Dog {
+        id Int @id
+    }
+
+    model Bird {
+        id Int @id
+    }
+"#;
+
+async fn apply_three_migrations(api: &TestApi) -> TestResult {
+    for &(migration_id, dm) in &[
+        ("cats", CAT_DM),
+        ("dogs", CAT_AND_DOG_DM),
+        ("birds", CAT_DOG_AND_BIRD_DM),
+    ] {
+        api.infer_apply(dm)
+            .migration_id(Some(migration_id))
+            .send_assert()
+            .await?
+            .assert_green()?;
+    }
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_replaces_a_range_of_migrations_with_one_record(api: &TestApi) -> TestResult {
+    apply_three_migrations(api).await?;
+
+    let output = api.squash_migrations("cats", "dogs", "cats_and_dogs").await?;
+
+    assert_eq!(output.migration_id, "cats_and_dogs");
+    assert_eq!(output.squashed_migrations, &["cats", "dogs"]);
+    assert!(output.datamodel.contains("model Dog"));
+    assert!(!output.datamodel.contains("model Bird"));
+    assert!(output.script.contains("Cat"));
+    assert!(output.script.contains("Dog"));
+
+    let migrations = api.migration_persistence().load_all().await?;
+    let names: Vec<&str> = migrations.iter().map(|migration| migration.name.as_str()).collect();
+
+    assert_eq!(names, &["cats_and_dogs", "birds"]);
+    // The squashed migration creates both tables at once.
+    assert_eq!(migrations[0].applied, 2);
+    assert!(migrations
+        .iter()
+        .all(|migration| migration.status == MigrationStatus::MigrationSuccess));
+
+    let output = api.diagnose_migration_history().await?;
+
+    assert_eq!(output.last_migration.as_deref(), Some("birds"));
+    assert_eq!(output.drift, None);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn unapplying_after_a_squash_goes_back_to_the_squashed_migration(api: &TestApi) -> TestResult {
+    apply_three_migrations(api).await?;
+
+    api.squash_migrations("cats", "dogs", "cats_and_dogs").await?;
+    api.unapply_migration().send().await?;
+
+    let last_migration = api.migration_persistence().last().await?.unwrap();
+
+    assert_eq!(last_migration.name, "cats_and_dogs");
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.has_table("Cat"));
+    assert!(schema.has_table("Dog"));
+    assert!(!schema.has_table("Bird"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_a_range_in_the_wrong_order_is_an_error(api: &TestApi) -> TestResult {
+    apply_three_migrations(api).await?;
+
+    let error = api
+        .squash_migrations("dogs", "cats", "cats_and_dogs")
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The migration `dogs` was applied after `cats`."));
+    assert_eq!(api.migration_persistence().load_all().await?.len(), 3);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_unknown_migrations_is_an_error(api: &TestApi) -> TestResult {
+    apply_three_migrations(api).await?;
+
+    let error = api
+        .squash_migrations("cats", "fish", "cats_and_fish")
+        .await
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("There is no applied migration with id `fish`."));
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use datamodel::SourceFile;
+use std::path::Path;
+
+use crate::DiffOpts;
+
+pub fn run(opts: DiffOpts) {
+    let previous = load_schema_ast(&opts.previous);
+    let next = load_schema_ast(&opts.next);
+    let diff = datamodel::diff(&previous, &next);
+
+    if opts.json {
+        print!("{}", diff.render_to_json())
+    } else if diff.is_empty() {
+        println!("No changes.")
+    } else {
+        print!("{}", diff)
+    }
+}
+
+/// Only the syntax of the schemas is checked, so that diffs can be shown for invalid schemas too.
+fn load_schema_ast(path: &Path) -> datamodel::ast::SchemaAst {
+    let files = SourceFile::load_all(path).expect(&format!("Unable to read {}", path.display()));
+
+    match datamodel::parse_schema_ast_from_files(&files) {
+        Ok(ast) => ast,
+        Err(errors) => {
+            eprint!("{}", errors.to_pretty_string_in_files(&files));
+            std::process::exit(1);
+        }
+    }
+}
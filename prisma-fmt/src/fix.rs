@@ -0,0 +1,110 @@
+use datamodel::{ast::reformat::Reformatter, error::TextEdit, SourceFile};
+use std::{
+    fs,
+    io::{self, Read, Write},
+};
+
+use crate::FixOpts;
+
+/// Fixes are applied in rounds, because fixing one diagnostic can make fixes for others
+/// available. A schema with a reserved model name only validates, and gets the fixes for its
+/// relations, once the model is renamed.
+const MAX_ROUNDS: usize = 10;
+
+pub fn run(opts: FixOpts) {
+    let mut files = match &opts.input {
+        Some(path) => SourceFile::load_all(path).expect(&format!("Unable to read {}", path.display())),
+        None => {
+            let mut datamodel_string = String::new();
+
+            io::stdin()
+                .read_to_string(&mut datamodel_string)
+                .expect("Unable to read from stdin.");
+
+            vec![SourceFile::new("stdin", &datamodel_string)]
+        }
+    };
+
+    apply_quick_fixes(&mut files);
+
+    let mut failed = false;
+
+    for file in &files {
+        let mut formatted = Vec::new();
+
+        if let Err(errors) = Reformatter::reformat_ast_to(&file.contents, &mut formatted, opts.tabwidth) {
+            eprint!("{}", errors.to_pretty_string(&file.name, &file.contents));
+            failed = true;
+            continue;
+        }
+
+        match opts.input {
+            Some(_) => fs::write(&file.name, formatted).expect(&format!("Unable to write file {}", file.name)),
+            None => io::stdout().write_all(&formatted).expect("Unable to write to stdout."),
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Applies the quick fixes for all diagnostics of the schema.
+fn apply_quick_fixes(files: &mut [SourceFile]) {
+    for _ in 0..MAX_ROUNDS {
+        let mut edits: Vec<TextEdit> = Vec::new();
+
+        for fix in datamodel::quick_fixes_for_files(files, true) {
+            // Fixes overlapping with ones taken already are left for the next round, when their
+            // spans are computed on the edited files.
+            let overlaps = fix
+                .edits
+                .iter()
+                .any(|edit| edits.iter().any(|taken| taken.overlaps(edit)));
+
+            if !overlaps {
+                edits.extend(fix.edits);
+            }
+        }
+
+        if edits.is_empty() {
+            return;
+        }
+
+        TextEdit::apply_all(&edits, files);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixes_are_applied_until_none_are_left() {
+        let mut files = vec![SourceFile::new(
+            "schema.prisma",
+            "model Boolean {\n  id    Int    @id\n  owner User\n}\n\nmodel User {\n  id Int @id\n}\n",
+        )];
+
+        apply_quick_fixes(&mut files);
+
+        assert_eq!(
+            files[0].contents,
+            "model BooleanModel {\n  id    Int    @id\n  owner User\n  @@map(\"Boolean\")\n}\n\nmodel User {\n  id Int @id\n  booleanModels BooleanModel[]\n}\n"
+        );
+    }
+
+    #[test]
+    fn fixes_in_the_same_place_are_applied_one_after_another() {
+        let mut files = vec![SourceFile::new(
+            "schema.prisma",
+            "model User {\n  id Int @id\n}\n\nmodel Post {\n  id     Int  @id\n  author User\n}\n\nmodel Comment {\n  id     Int  @id\n  author User\n}\n",
+        )];
+
+        apply_quick_fixes(&mut files);
+
+        assert!(files[0]
+            .contents
+            .contains("  id Int @id\n  posts Post[]\n  comments Comment[]\n}"));
+    }
+}
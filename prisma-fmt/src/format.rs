@@ -1,16 +1,22 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::{ast::reformat::Reformatter, SourceFile};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
+    path::Path,
 };
 
 use crate::FormatOpts;
 
 pub fn run(opts: FormatOpts) {
-    let datamodel_string = match opts.input {
-        Some(file_name) => {
-            fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
-        }
+    if let Some(directory) = opts.input.as_ref().filter(|path| path.is_dir()) {
+        return format_directory(directory, &opts);
+    }
+
+    let (datamodel_string, file_name) = match &opts.input {
+        Some(file_name) => (
+            fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display())),
+            file_name.display().to_string(),
+        ),
         None => {
             let mut buf = String::new();
 
@@ -18,19 +24,60 @@ pub fn run(opts: FormatOpts) {
                 .read_to_string(&mut buf)
                 .expect("Unable to read from stdin.");
 
-            buf
+            (buf, String::from("stdin"))
         }
     };
 
-    match opts.output {
+    let reformat = if opts.ast_only {
+        Reformatter::reformat_ast_to
+    } else {
+        Reformatter::reformat_to
+    };
+
+    let result = match &opts.output {
         Some(file_name) => {
-            let file = File::open(&file_name).expect(&format!("Unable to open file {}", file_name.display()));
+            let file = File::create(&file_name).expect(&format!("Unable to open file {}", file_name.display()));
             let mut stream = BufWriter::new(file);
 
-            Reformatter::reformat_to(&datamodel_string, &mut stream, opts.tabwidth);
+            reformat(&datamodel_string, &mut stream, opts.tabwidth)
         }
-        None => {
-            Reformatter::reformat_to(&datamodel_string, &mut io::stdout().lock(), opts.tabwidth);
+        None => reformat(&datamodel_string, &mut io::stdout().lock(), opts.tabwidth),
+    };
+
+    if let Err(errors) = result {
+        eprint!("{}", errors.to_pretty_string(&file_name, &datamodel_string));
+        std::process::exit(1);
+    }
+}
+
+/// Formats all schema files in the directory in place.
+fn format_directory(directory: &Path, opts: &FormatOpts) {
+    if opts.output.is_some() {
+        eprintln!("A directory of schema files can only be formatted in place, without an output file.");
+        std::process::exit(1);
+    }
+
+    let files = SourceFile::load_all(directory).expect(&format!("Unable to read {}", directory.display()));
+    let mut failed = false;
+
+    for file in files {
+        let mut formatted = Vec::new();
+        let result = if opts.ast_only {
+            Reformatter::reformat_ast_to(&file.contents, &mut formatted, opts.tabwidth)
+        } else {
+            Reformatter::reformat_to(&file.contents, &mut formatted, opts.tabwidth)
+        };
+
+        match result {
+            Ok(()) => fs::write(&file.name, formatted).expect(&format!("Unable to write file {}", file.name)),
+            Err(errors) => {
+                eprint!("{}", errors.to_pretty_string(&file.name, &file.contents));
+                failed = true;
+            }
         }
     }
+
+    if failed {
+        std::process::exit(1);
+    }
 }
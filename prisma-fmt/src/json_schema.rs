@@ -0,0 +1,35 @@
+use datamodel::{json::jsonschema, SourceFile};
+use std::io::{self, Read};
+
+use crate::JsonSchemaOpts;
+
+pub fn run(opts: JsonSchemaOpts) {
+    let files = match &opts.input {
+        Some(path) => SourceFile::load_all(path).expect(&format!("Unable to read {}", path.display())),
+        None => {
+            let mut datamodel_string = String::new();
+
+            io::stdin()
+                .read_to_string(&mut datamodel_string)
+                .expect("Unable to read from stdin.");
+
+            vec![SourceFile::new("stdin", &datamodel_string)]
+        }
+    };
+
+    let datamodel = match datamodel::parse_datamodel_from_files_and_ignore_env_errors(&files) {
+        Ok(datamodel) => datamodel,
+        Err(errors) => {
+            eprint!("{}", errors.to_pretty_string_in_files(&files));
+            std::process::exit(1);
+        }
+    };
+
+    let json = if opts.openapi {
+        jsonschema::render_to_openapi_components(&datamodel)
+    } else {
+        jsonschema::render_to_json_schema(&datamodel)
+    };
+
+    print!("{}", json)
+}
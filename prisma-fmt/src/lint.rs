@@ -1,37 +1,88 @@
-use crate::{LintOpts, MiniError};
-use datamodel::error::DatamodelError;
+use crate::{LintOpts, MiniEdit, MiniError, MiniFix, Severity};
+use datamodel::{
+    ast::Span,
+    error::{DatamodelError, DatamodelWarning, QuickFix},
+    SourceFile,
+};
 use serde_json;
 use std::io::{self, Read};
 
 pub fn run(opts: LintOpts) {
-    let mut datamodel_string = String::new();
+    let (files, from_directory) = match &opts.input {
+        Some(path) => (
+            SourceFile::load_all(path).expect(&format!("Unable to read {}", path.display())),
+            path.is_dir(),
+        ),
+        None => {
+            let mut datamodel_string = String::new();
 
-    io::stdin()
-        .read_to_string(&mut datamodel_string)
-        .expect("Unable to read from stdin.");
+            io::stdin()
+                .read_to_string(&mut datamodel_string)
+                .expect("Unable to read from stdin.");
 
-    let datamodel_result = if opts.no_env_errors {
-        datamodel::parse_datamodel_and_ignore_env_errors(&datamodel_string)
-    } else {
-        datamodel::parse_datamodel(&datamodel_string)
+            (vec![SourceFile::new("stdin", &datamodel_string)], false)
+        }
     };
 
-    match datamodel_result {
-        Err(err) => {
-            let mini_errors: Vec<MiniError> = err
-                .errors
-                .iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                })
-                .collect();
-
-            let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
-
-            print!("{}", json)
+    let datamodel_result = datamodel::parse_datamodel_from_files_with_warnings(&files, opts.no_env_errors);
+
+    let quick_fixes = datamodel::quick_fixes_for_files(&files, opts.no_env_errors);
+
+    let file_of = |span: Span| {
+        if from_directory {
+            files.get(span.file_id.0).map(|file| file.name.clone())
+        } else {
+            None
         }
-        _ => print!("[]"),
-    }
+    };
+
+    let fixes_for = |span: Span, text: &str| -> Vec<MiniFix> {
+        quick_fixes
+            .iter()
+            .filter(|fix: &&QuickFix| fix.span == span && fix.diagnostic == text)
+            .map(|fix| MiniFix {
+                title: fix.title.clone(),
+                edits: fix
+                    .edits
+                    .iter()
+                    .map(|edit| MiniEdit {
+                        start: edit.span.start,
+                        end: edit.span.end,
+                        text: edit.new_text.clone(),
+                        file: file_of(edit.span),
+                    })
+                    .collect(),
+            })
+            .collect()
+    };
+
+    let mini_errors: Vec<MiniError> = match datamodel_result {
+        Err(err) => err
+            .errors
+            .iter()
+            .map(|err: &DatamodelError| MiniError {
+                start: err.span().start,
+                end: err.span().end,
+                text: format!("{}", err),
+                severity: Severity::Error,
+                file: file_of(err.span()),
+                fixes: fixes_for(err.span(), &err.description()),
+            })
+            .collect(),
+        Ok((_, warnings)) => warnings
+            .iter()
+            .map(|warning: &DatamodelWarning| MiniError {
+                start: warning.span().start,
+                end: warning.span().end,
+                text: format!("{}", warning),
+                severity: Severity::Warning,
+                file: file_of(warning.span()),
+                fixes: fixes_for(warning.span(), &warning.description()),
+            })
+            .collect(),
+    };
+
+    let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
+
+    print!("{}", json)
 }
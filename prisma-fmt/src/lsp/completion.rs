@@ -0,0 +1,158 @@
+//! Completion for directive names and arguments.
+//!
+//! Completion is requested while typing, when the schema usually does not parse, so the context is
+//! derived from the text in front of the cursor instead of the AST.
+
+use super::directives::{builtin_directives, DirectiveTarget};
+use serde_json::{json, Value};
+
+/// The protocol's `CompletionItemKind`s we use.
+const KIND_PROPERTY: u8 = 10;
+const KIND_KEYWORD: u8 = 14;
+
+#[derive(Debug, PartialEq)]
+enum Context<'a> {
+    /// Typing the name of a directive, e.g. `@uni|` or `@@|`.
+    DirectiveName(DirectiveTarget),
+    /// Inside the argument list of a directive, e.g. `@relation(|`. Holds the name of the
+    /// directive and the arguments typed so far.
+    DirectiveArguments(DirectiveTarget, &'a str, &'a str),
+}
+
+pub fn completions(text: &str, offset: usize) -> Vec<Value> {
+    let offset = offset.min(text.len());
+    let line_start = text[..offset].rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+    let block = match enclosing_block(&text[..line_start]) {
+        Some(block) => block,
+        None => return Vec::new(),
+    };
+
+    match find_context(&text[line_start..offset], block) {
+        Some(Context::DirectiveName(target)) => builtin_directives(target)
+            .into_iter()
+            .map(|directive| {
+                json!({
+                    "label": directive.name,
+                    "kind": KIND_KEYWORD,
+                    "detail": format!("{}{}", target.prefix(), directive.name),
+                    "documentation": directive.documentation,
+                })
+            })
+            .collect(),
+        Some(Context::DirectiveArguments(target, name, typed_arguments)) => builtin_directives(target)
+            .into_iter()
+            .find(|directive| directive.name == name)
+            .map(|directive| directive.arguments)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|argument| !typed_arguments.contains(&format!("{}:", argument)))
+            .map(|argument| {
+                json!({
+                    "label": argument,
+                    "kind": KIND_PROPERTY,
+                    "insertText": format!("{}: ", argument),
+                })
+            })
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Block {
+    Model,
+    Enum,
+}
+
+/// The block the text ends in, if it is a model or an enum.
+fn enclosing_block(text: &str) -> Option<Block> {
+    for line in text.lines().rev() {
+        let line = line.trim();
+
+        if line.starts_with('}') {
+            return None;
+        }
+
+        if line.ends_with('{') {
+            return match line.split_whitespace().next() {
+                Some("model") | Some("type") => Some(Block::Model),
+                Some("enum") => Some(Block::Enum),
+                _ => None,
+            };
+        }
+    }
+
+    None
+}
+
+fn find_context(line: &str, block: Block) -> Option<Context<'_>> {
+    let at = line.rfind('@')?;
+    let is_block_directive = at > 0 && line[..at].ends_with('@');
+
+    let target = match (block, is_block_directive) {
+        (Block::Model, false) => DirectiveTarget::Field,
+        (Block::Model, true) => DirectiveTarget::Model,
+        (Block::Enum, false) => DirectiveTarget::EnumValue,
+        (Block::Enum, true) => DirectiveTarget::Enum,
+    };
+
+    let after_at = &line[at + 1..];
+    let name_length = after_at
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+        .unwrap_or_else(|| after_at.len());
+    let (name, rest) = after_at.split_at(name_length);
+
+    if rest.is_empty() {
+        return Some(Context::DirectiveName(target));
+    }
+
+    if rest.starts_with('(') && rest.matches('(').count() > rest.matches(')').count() {
+        return Some(Context::DirectiveArguments(target, name, &rest[1..]));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(text: &str) -> Vec<String> {
+        completions(text, text.len())
+            .into_iter()
+            .map(|item| item["label"].as_str().unwrap().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn field_directives_are_completed_after_an_at_sign() {
+        let labels = labels("model User {\n  id Int @");
+
+        assert!(labels.contains(&"id".to_owned()));
+        assert!(labels.contains(&"default".to_owned()));
+        assert!(!labels.contains(&"index".to_owned()));
+    }
+
+    #[test]
+    fn model_directives_are_completed_after_two_at_signs() {
+        let labels = labels("model User {\n  id Int\n\n  @@in");
+
+        assert!(labels.contains(&"index".to_owned()));
+        assert!(!labels.contains(&"default".to_owned()));
+    }
+
+    #[test]
+    fn arguments_that_were_not_typed_yet_are_completed() {
+        let labels = labels("model User {\n  id Int\n  @@index([id], name: \"a\", ");
+
+        assert_eq!(labels, vec!["fields", "sort", "type"]);
+    }
+
+    #[test]
+    fn nothing_is_completed_outside_of_models_and_enums() {
+        assert!(labels("datasource db {\n  provider = \"@").is_empty());
+        assert!(labels("model User {\n  id Int @id\n}\n@").is_empty());
+        assert!(labels("model User {\n  id Int @default(1) ").is_empty());
+    }
+}
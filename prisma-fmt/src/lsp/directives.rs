@@ -0,0 +1,58 @@
+use datamodel::validator::directive::{
+    new_builtin_enum_directives, new_builtin_enum_value_directives, new_builtin_field_directives,
+    new_builtin_model_directives, DirectiveListValidator,
+};
+
+/// The kind of object a directive is attached to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DirectiveTarget {
+    Field,
+    Model,
+    Enum,
+    EnumValue,
+}
+
+impl DirectiveTarget {
+    /// `@` for fields and enum values, `@@` for models and enums.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            DirectiveTarget::Field | DirectiveTarget::EnumValue => "@",
+            DirectiveTarget::Model | DirectiveTarget::Enum => "@@",
+        }
+    }
+}
+
+/// What editors need to know about a directive registered in the validator.
+#[derive(Debug)]
+pub struct DirectiveInfo {
+    pub name: String,
+    pub documentation: String,
+    pub arguments: Vec<&'static str>,
+}
+
+/// All builtin directives for the given target, ordered by name.
+pub fn builtin_directives(target: DirectiveTarget) -> Vec<DirectiveInfo> {
+    match target {
+        DirectiveTarget::Field => describe(&new_builtin_field_directives()),
+        DirectiveTarget::Model => describe(&new_builtin_model_directives()),
+        DirectiveTarget::Enum => describe(&new_builtin_enum_directives()),
+        DirectiveTarget::EnumValue => describe(&new_builtin_enum_value_directives()),
+    }
+}
+
+pub fn find_builtin_directive(target: DirectiveTarget, name: &str) -> Option<DirectiveInfo> {
+    builtin_directives(target)
+        .into_iter()
+        .find(|directive| directive.name == name)
+}
+
+fn describe<T: 'static>(validators: &DirectiveListValidator<T>) -> Vec<DirectiveInfo> {
+    validators
+        .directives()
+        .map(|directive| DirectiveInfo {
+            name: directive.directive_name().to_owned(),
+            documentation: directive.documentation().to_owned(),
+            arguments: directive.argument_names().to_vec(),
+        })
+        .collect()
+}
@@ -0,0 +1,328 @@
+//! A language server for schema files, speaking the Language Server Protocol over stdio.
+//!
+//! Documents are synced in full. Diagnostics are published on every change, and hover,
+//! go-to-definition, completion and formatting are answered from the latest text.
+
+mod completion;
+mod directives;
+mod navigation;
+mod protocol;
+
+use datamodel::ast::reformat::Reformatter;
+use protocol::*;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+};
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+pub fn run() {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut input = stdin.lock();
+    let mut output = stdout.lock();
+    let mut server = Server::default();
+
+    loop {
+        let message = match read_message(&mut input) {
+            Ok(Some(message)) => message,
+            Ok(None) => break,
+            Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                let response = error_response(Value::Null, PARSE_ERROR, &err.to_string());
+                send(&mut output, &response);
+                continue;
+            }
+            Err(err) => {
+                eprintln!("Unable to read from stdin: {}", err);
+                break;
+            }
+        };
+
+        if message.method.as_deref() == Some("exit") {
+            break;
+        }
+
+        let id = message.id.clone();
+        let outgoing = panic::catch_unwind(AssertUnwindSafe(|| server.handle(message)));
+
+        match (outgoing, id) {
+            (Ok(messages), _) => messages.iter().for_each(|message| send(&mut output, message)),
+            (Err(_), Some(id)) => send(&mut output, &error_response(id, INTERNAL_ERROR, "Internal error.")),
+            (Err(_), None) => {}
+        }
+    }
+
+    std::process::exit(if server.shutdown_requested { 0 } else { 1 });
+}
+
+fn send(output: &mut dyn Write, message: &Value) {
+    if let Err(err) = write_message(output, message) {
+        eprintln!("Unable to write to stdout: {}", err);
+        std::process::exit(1);
+    }
+}
+
+#[derive(Default)]
+struct Server {
+    /// The text of all open documents, by URI.
+    documents: HashMap<String, String>,
+    shutdown_requested: bool,
+}
+
+impl Server {
+    /// Handles one incoming message and returns the messages to send back.
+    fn handle(&mut self, message: Message) -> Vec<Value> {
+        let method = match message.method {
+            Some(method) => method,
+            // Responses to requests we never send.
+            None => return Vec::new(),
+        };
+
+        match message.id {
+            Some(id) => {
+                let response = match self.handle_request(&method, message.params) {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err((code, text)) => error_response(id, code, &text),
+                };
+
+                vec![response]
+            }
+            None => self.handle_notification(&method, message.params),
+        }
+    }
+
+    fn handle_request(&mut self, method: &str, params: Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": 1,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "completionProvider": { "triggerCharacters": ["@", "("] },
+                    "documentFormattingProvider": true,
+                },
+                "serverInfo": { "name": "prisma-fmt", "version": env!("GIT_HASH") },
+            })),
+            "shutdown" => {
+                self.shutdown_requested = true;
+
+                Ok(Value::Null)
+            }
+            "textDocument/hover" => {
+                let params: TextDocumentPositionParams = parse_params(params)?;
+
+                Ok(self.hover(&params).unwrap_or(Value::Null))
+            }
+            "textDocument/definition" => {
+                let params: TextDocumentPositionParams = parse_params(params)?;
+
+                Ok(self.definition(&params).unwrap_or(Value::Null))
+            }
+            "textDocument/completion" => {
+                let params: TextDocumentPositionParams = parse_params(params)?;
+                let items = match self.documents.get(&params.text_document.uri) {
+                    Some(text) => completion::completions(text, offset_at(text, params.position)),
+                    None => Vec::new(),
+                };
+
+                Ok(Value::Array(items))
+            }
+            "textDocument/formatting" => {
+                let params: DocumentFormattingParams = parse_params(params)?;
+
+                Ok(self.format(&params).unwrap_or(Value::Null))
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method `{}`.", method))),
+        }
+    }
+
+    fn handle_notification(&mut self, method: &str, params: Value) -> Vec<Value> {
+        match method {
+            "textDocument/didOpen" => match serde_json::from_value::<DidOpenTextDocumentParams>(params) {
+                Ok(params) => {
+                    let document = params.text_document;
+                    let diagnostics = diagnostics(&document.uri, &document.text);
+                    self.documents.insert(document.uri, document.text);
+
+                    vec![diagnostics]
+                }
+                Err(_) => Vec::new(),
+            },
+            "textDocument/didChange" => match serde_json::from_value::<DidChangeTextDocumentParams>(params) {
+                Ok(mut params) => match params.content_changes.pop() {
+                    Some(change) => {
+                        let uri = params.text_document.uri;
+                        let diagnostics = diagnostics(&uri, &change.text);
+                        self.documents.insert(uri, change.text);
+
+                        vec![diagnostics]
+                    }
+                    None => Vec::new(),
+                },
+                Err(_) => Vec::new(),
+            },
+            "textDocument/didClose" => match serde_json::from_value::<DidCloseTextDocumentParams>(params) {
+                Ok(params) => {
+                    let uri = params.text_document.uri;
+                    self.documents.remove(&uri);
+
+                    vec![publish_diagnostics(&uri, Vec::new())]
+                }
+                Err(_) => Vec::new(),
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    fn hover(&self, params: &TextDocumentPositionParams) -> Option<Value> {
+        let text = self.documents.get(&params.text_document.uri)?;
+        let (schema, _) = datamodel::parse_schema_ast_with_recovery(text);
+        let target = navigation::find_target(&schema, offset_at(text, params.position))?;
+        let (contents, span) = navigation::hover(&schema, &target)?;
+
+        Some(json!({
+            "contents": { "kind": "markdown", "value": contents },
+            "range": range_of(text, span),
+        }))
+    }
+
+    fn definition(&self, params: &TextDocumentPositionParams) -> Option<Value> {
+        let uri = &params.text_document.uri;
+        let text = self.documents.get(uri)?;
+        let (schema, _) = datamodel::parse_schema_ast_with_recovery(text);
+        let target = navigation::find_target(&schema, offset_at(text, params.position))?;
+        let span = navigation::definition(&schema, &target)?;
+
+        Some(json!({ "uri": uri, "range": range_of(text, span) }))
+    }
+
+    /// Replaces the whole document, as computing minimal edits would not buy editors anything.
+    fn format(&self, params: &DocumentFormattingParams) -> Option<Value> {
+        let text = self.documents.get(&params.text_document.uri)?;
+        let mut formatted = Vec::new();
+
+        Reformatter::reformat_to(text, &mut formatted, params.options.tab_size).ok()?;
+
+        let mut new_text = String::from_utf8(formatted).ok()?;
+        new_text.push('\n');
+
+        Some(json!([{
+            "range": range_of(text, datamodel::ast::Span::new(0, text.len())),
+            "newText": new_text,
+        }]))
+    }
+}
+
+fn diagnostics(uri: &str, text: &str) -> Value {
+    let files = [datamodel::SourceFile::new(uri, text)];
+
+    let diagnostics = match datamodel::parse_datamodel_from_files_with_warnings(&files, true) {
+        Ok((_, warnings)) => warnings
+            .iter()
+            .map(|warning| {
+                json!({
+                    "range": range_of(text, warning.span()),
+                    "severity": 2,
+                    "source": "prisma",
+                    "message": format!("{}", warning),
+                })
+            })
+            .collect(),
+        Err(errors) => errors
+            .errors
+            .iter()
+            .map(|err| {
+                json!({
+                    "range": range_of(text, err.span()),
+                    "severity": 1,
+                    "source": "prisma",
+                    "message": format!("{}", err),
+                })
+            })
+            .collect(),
+    };
+
+    publish_diagnostics(uri, diagnostics)
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Value>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": { "uri": uri, "diagnostics": diagnostics },
+    })
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, (i64, String)> {
+    serde_json::from_value(params).map_err(|err| (INVALID_PARAMS, err.to_string()))
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URI: &str = "file:///schema.prisma";
+
+    fn notification(method: &str, params: Value) -> Message {
+        Message {
+            id: None,
+            method: Some(method.to_owned()),
+            params,
+        }
+    }
+
+    fn open(server: &mut Server, text: &str) -> Vec<Value> {
+        let params = json!({ "textDocument": { "uri": URI, "languageId": "prisma", "version": 1, "text": text } });
+
+        server.handle(notification("textDocument/didOpen", params))
+    }
+
+    #[test]
+    fn errors_are_published_as_diagnostics() {
+        let mut server = Server::default();
+        let published = open(&mut server, "model User {\n  id Int @id\n  post Post\n}");
+        let diagnostics = &published[0]["params"]["diagnostics"];
+
+        assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+        assert_eq!(diagnostics[0]["range"]["start"], json!({ "line": 2, "character": 7 }));
+
+        let published = open(&mut server, "model User {\n  id Int @id\n}");
+
+        assert_eq!(published[0]["params"]["diagnostics"], json!([]));
+    }
+
+    #[test]
+    fn warnings_are_published_as_diagnostics() {
+        let mut server = Server::default();
+        let published = open(&mut server, "model Order {\n  id    Int @id\n  price Float\n}");
+        let diagnostics = &published[0]["params"]["diagnostics"];
+
+        assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+        assert_eq!(diagnostics[0]["severity"], json!(2));
+        assert_eq!(diagnostics[0]["range"]["start"], json!({ "line": 2, "character": 8 }));
+    }
+
+    #[test]
+    fn unknown_requests_are_answered_with_an_error() {
+        let mut server = Server::default();
+        let responses = server.handle(Message {
+            id: Some(json!(7)),
+            method: Some("textDocument/rename".to_owned()),
+            params: Value::Null,
+        });
+
+        assert_eq!(responses[0]["id"], json!(7));
+        assert_eq!(responses[0]["error"]["code"], json!(METHOD_NOT_FOUND));
+    }
+}
@@ -0,0 +1,212 @@
+//! Hover and go-to-definition, both based on the node of the schema AST under the cursor.
+
+use super::directives::{find_builtin_directive, DirectiveTarget};
+use datamodel::ast;
+
+/// The node of the AST under the cursor.
+#[derive(Debug)]
+pub enum Target<'a> {
+    Model(&'a ast::Model),
+    Enum(&'a ast::Enum),
+    EnumValue(&'a ast::EnumValue),
+    Field(&'a ast::Field),
+    /// The type of a field or type alias.
+    FieldType(&'a ast::Identifier),
+    Directive(&'a ast::Directive, DirectiveTarget),
+}
+
+pub fn find_target(schema: &ast::SchemaAst, offset: usize) -> Option<Target<'_>> {
+    for top in &schema.tops {
+        match top {
+            ast::Top::Model(model) if contains(model.span, offset) => return find_in_model(model, offset),
+            ast::Top::Enum(enm) if contains(enm.span, offset) => return find_in_enum(enm, offset),
+            ast::Top::Type(alias) if contains(alias.field_type.span, offset) => {
+                return Some(Target::FieldType(&alias.field_type))
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn find_in_model(model: &ast::Model, offset: usize) -> Option<Target<'_>> {
+    if contains(model.name.span, offset) {
+        return Some(Target::Model(model));
+    }
+
+    if let Some(field) = model.fields.iter().find(|field| contains(field.span, offset)) {
+        if contains(field.field_type.span, offset) {
+            return Some(Target::FieldType(&field.field_type));
+        }
+
+        return match field.directives.iter().find(|d| contains(d.span, offset)) {
+            Some(directive) => Some(Target::Directive(directive, DirectiveTarget::Field)),
+            None => Some(Target::Field(field)),
+        };
+    }
+
+    model
+        .directives
+        .iter()
+        .find(|directive| contains(directive.span, offset))
+        .map(|directive| Target::Directive(directive, DirectiveTarget::Model))
+}
+
+fn find_in_enum(enm: &ast::Enum, offset: usize) -> Option<Target<'_>> {
+    if contains(enm.name.span, offset) {
+        return Some(Target::Enum(enm));
+    }
+
+    if let Some(value) = enm.values.iter().find(|value| contains(value.span, offset)) {
+        return match value.directives.iter().find(|d| contains(d.span, offset)) {
+            Some(directive) => Some(Target::Directive(directive, DirectiveTarget::EnumValue)),
+            None => Some(Target::EnumValue(value)),
+        };
+    }
+
+    enm.directives
+        .iter()
+        .find(|directive| contains(directive.span, offset))
+        .map(|directive| Target::Directive(directive, DirectiveTarget::Enum))
+}
+
+/// The markdown shown when hovering the target, and the span it applies to.
+pub fn hover(schema: &ast::SchemaAst, target: &Target<'_>) -> Option<(String, ast::Span)> {
+    match target {
+        Target::Model(model) => Some((describe_model(model), model.name.span)),
+        Target::Enum(enm) => Some((describe_enum(enm), enm.name.span)),
+        Target::EnumValue(value) => Some((code_block(&value.name.name), value.name.span)),
+        Target::Field(field) => {
+            let mut field_type = field.field_type.name.clone();
+
+            match field.arity {
+                ast::FieldArity::Required => {}
+                ast::FieldArity::Optional => field_type.push('?'),
+                ast::FieldArity::List => field_type.push_str("[]"),
+            }
+
+            let text = code_block(&format!("{} {}", field.name.name, field_type));
+
+            Some((with_documentation(text, field.documentation.as_ref()), field.name.span))
+        }
+        Target::FieldType(identifier) => {
+            let text = if let Some(model) = schema.find_model(&identifier.name) {
+                describe_model(model)
+            } else if let Some(enm) = schema.find_enum(&identifier.name) {
+                describe_enum(enm)
+            } else if let Some(alias) = schema.find_type_alias(&identifier.name) {
+                let text = code_block(&format!("type {} = {}", alias.name.name, alias.field_type.name));
+
+                with_documentation(text, alias.documentation.as_ref())
+            } else {
+                return None;
+            };
+
+            Some((text, identifier.span))
+        }
+        Target::Directive(directive, directive_target) => {
+            let info = find_builtin_directive(*directive_target, &directive.name.name)?;
+            let mut text = code_block(&format!("{}{}", directive_target.prefix(), info.name));
+
+            if !info.documentation.is_empty() {
+                text.push_str("\n\n");
+                text.push_str(&info.documentation);
+            }
+
+            Some((text, directive.name.span))
+        }
+    }
+}
+
+/// The span of the declaration the target refers to, for go-to-definition.
+pub fn definition(schema: &ast::SchemaAst, target: &Target<'_>) -> Option<ast::Span> {
+    match target {
+        Target::FieldType(identifier) => schema
+            .find_model(&identifier.name)
+            .map(|model| model.name.span)
+            .or_else(|| schema.find_enum(&identifier.name).map(|enm| enm.name.span))
+            .or_else(|| schema.find_type_alias(&identifier.name).map(|alias| alias.name.span)),
+        _ => None,
+    }
+}
+
+fn describe_model(model: &ast::Model) -> String {
+    with_documentation(
+        code_block(&format!("model {}", model.name.name)),
+        model.documentation.as_ref(),
+    )
+}
+
+fn describe_enum(enm: &ast::Enum) -> String {
+    with_documentation(code_block(&format!("enum {}", enm.name.name)), enm.documentation.as_ref())
+}
+
+fn code_block(code: &str) -> String {
+    format!("```prisma\n{}\n```", code)
+}
+
+fn with_documentation(mut text: String, documentation: Option<&ast::Comment>) -> String {
+    if let Some(documentation) = documentation {
+        text.push_str("\n\n");
+        text.push_str(&documentation.text);
+    }
+
+    text
+}
+
+/// Spans include their end, so that a cursor right behind an identifier still hits it.
+fn contains(span: ast::Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+// A blog post.
+model Post {
+  id     Int    @id
+  author User
+}
+
+model User {
+  id Int @id
+}
+"#;
+
+    fn target_at(needle: &str) -> (ast::SchemaAst, usize) {
+        let schema = datamodel::parse_schema_ast(SCHEMA).unwrap();
+
+        (schema, SCHEMA.find(needle).unwrap() + 1)
+    }
+
+    #[test]
+    fn relation_field_types_lead_to_the_related_model() {
+        let (schema, offset) = target_at("User\n}");
+        let target = find_target(&schema, offset).unwrap();
+        let span = definition(&schema, &target).unwrap();
+
+        assert_eq!(&SCHEMA[span.start..span.end], "User");
+        assert_eq!(span.start, SCHEMA.find("model User").unwrap() + "model ".len());
+    }
+
+    #[test]
+    fn hovering_a_model_shows_its_documentation() {
+        let (schema, offset) = target_at("Post {");
+        let target = find_target(&schema, offset).unwrap();
+        let (text, _) = hover(&schema, &target).unwrap();
+
+        assert_eq!(text, "```prisma\nmodel Post\n```\n\nA blog post.");
+    }
+
+    #[test]
+    fn hovering_a_directive_shows_the_validator_documentation() {
+        let (schema, offset) = target_at("@id");
+        let target = find_target(&schema, offset + 1).unwrap();
+        let (text, _) = hover(&schema, &target).unwrap();
+
+        assert!(text.starts_with("```prisma\n@id\n```\n\n"));
+    }
+}
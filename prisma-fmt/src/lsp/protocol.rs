@@ -0,0 +1,220 @@
+use datamodel::ast::Span;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{self, BufRead, Write};
+
+/// A JSON-RPC request, notification or response, as sent by the client.
+#[derive(Debug, Deserialize)]
+pub struct Message {
+    /// Only set for requests and responses.
+    pub id: Option<Value>,
+    /// Only set for requests and notifications.
+    pub method: Option<String>,
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// A position in a document. `character` counts UTF-16 code units, as the protocol mandates.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub character: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentIdentifier {
+    pub uri: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentItem {
+    pub uri: String,
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidOpenTextDocumentParams {
+    pub text_document: TextDocumentItem,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextDocumentContentChangeEvent {
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChangeTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+    pub content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidCloseTextDocumentParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDocumentPositionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub position: Position,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormattingOptions {
+    pub tab_size: usize,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentFormattingParams {
+    pub text_document: TextDocumentIdentifier,
+    pub options: FormattingOptions,
+}
+
+/// Reads the next message. Returns `None` once the client closed the input.
+pub fn read_message(input: &mut dyn BufRead) -> io::Result<Option<Message>> {
+    let mut content_length: Option<usize> = None;
+
+    loop {
+        let mut line = String::new();
+
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        let mut header = line.splitn(2, ':');
+
+        if let (Some(name), Some(value)) = (header.next(), header.next()) {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = value.trim().parse().ok();
+            }
+        }
+    }
+
+    let content_length = content_length
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Missing Content-Length header."))?;
+
+    let mut body = vec![0; content_length];
+    input.read_exact(&mut body)?;
+
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+pub fn write_message(output: &mut dyn Write, message: &Value) -> io::Result<()> {
+    let body = serde_json::to_string(message)?;
+
+    write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    output.flush()
+}
+
+/// Converts a byte offset into a protocol position.
+pub fn position_at(text: &str, offset: usize) -> Position {
+    let mut position = Position { line: 0, character: 0 };
+
+    for (idx, c) in text.char_indices() {
+        if idx >= offset {
+            break;
+        }
+
+        if c == '\n' {
+            position.line += 1;
+            position.character = 0;
+        } else {
+            position.character += c.len_utf16();
+        }
+    }
+
+    position
+}
+
+/// Converts a protocol position into a byte offset. Positions past the end of a line are clamped
+/// to the end of that line.
+pub fn offset_at(text: &str, position: Position) -> usize {
+    let mut line = 0;
+    let mut character = 0;
+
+    for (idx, c) in text.char_indices() {
+        if line == position.line && (character >= position.character || c == '\n') {
+            return idx;
+        }
+
+        if c == '\n' {
+            line += 1;
+        } else if line == position.line {
+            character += c.len_utf16();
+        }
+    }
+
+    text.len()
+}
+
+pub fn range_of(text: &str, span: Span) -> Range {
+    Range {
+        start: position_at(text, span.start),
+        end: position_at(text, span.end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_and_offsets_roundtrip() {
+        let text = "model A {\n  name String // ä😀\n}";
+
+        for offset in text.char_indices().map(|(idx, _)| idx) {
+            assert_eq!(offset_at(text, position_at(text, offset)), offset);
+        }
+    }
+
+    #[test]
+    fn positions_count_utf16_code_units() {
+        let text = "// 😀x\nmodel";
+        let x = text.find('x').unwrap();
+
+        assert_eq!(position_at(text, x), Position { line: 0, character: 5 });
+        assert_eq!(position_at(text, text.len()), Position { line: 1, character: 5 });
+    }
+
+    #[test]
+    fn positions_past_the_end_of_a_line_are_clamped() {
+        let text = "ab\ncd";
+
+        assert_eq!(offset_at(text, Position { line: 0, character: 10 }), 2);
+        assert_eq!(offset_at(text, Position { line: 5, character: 0 }), text.len());
+    }
+
+    #[test]
+    fn messages_are_read_and_written_with_content_length_headers() {
+        let message = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "shutdown" });
+        let mut buffer = Vec::new();
+
+        write_message(&mut buffer, &message).unwrap();
+
+        let read = read_message(&mut buffer.as_slice()).unwrap().unwrap();
+
+        assert_eq!(read.id, Some(serde_json::json!(1)));
+        assert_eq!(read.method.as_deref(), Some("shutdown"));
+        assert!(read_message(&mut &b""[..]).unwrap().is_none());
+    }
+}
@@ -1,5 +1,9 @@
+mod diff;
+mod fix;
 mod format;
+mod json_schema;
 mod lint;
+mod lsp;
 
 use std::path::PathBuf;
 
@@ -11,12 +15,16 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// Specifies the schema file, or a directory of `.prisma` files, to lint. If none is given,
+    /// the schema is read from STDIN
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
 pub struct FormatOpts {
     /// Specifies the input file to use. If none is given, the input is read
-    /// from STDIN
+    /// from STDIN. Given a directory, all `.prisma` files in it are formatted in place
     #[structopt(short = "i", long)]
     input: Option<PathBuf>,
     /// Specifies the output file to use. If none is given, the output is
@@ -26,6 +34,47 @@ pub struct FormatOpts {
     /// Specifies wich tab width to use when formatting
     #[structopt(short = "s", long, default_value = "2")]
     tabwidth: usize,
+    /// Only reformats the syntax, without validating the schema or adding
+    /// implicit back relation fields
+    #[structopt(long)]
+    ast_only: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct FixOpts {
+    /// Specifies the schema file, or a directory of `.prisma` files, to fix in place. If none is
+    /// given, the schema is read from STDIN and the fixed schema written to STDOUT
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
+    /// Specifies wich tab width to use when rendering the fixed schema
+    #[structopt(short = "s", long, default_value = "2")]
+    tabwidth: usize,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct JsonSchemaOpts {
+    /// Specifies the schema file, or a directory of `.prisma` files, to export. If none is given,
+    /// the schema is read from STDIN
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
+    /// Renders the `components` section of an OpenAPI document instead of one JSON Schema
+    /// document per model and enum
+    #[structopt(long)]
+    openapi: bool,
+}
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct DiffOpts {
+    /// Specifies the schema file, or a directory of `.prisma` files, before the changes
+    #[structopt(long)]
+    previous: PathBuf,
+    /// Specifies the schema file, or a directory of `.prisma` files, after the changes
+    #[structopt(long)]
+    next: PathBuf,
+    /// Renders the changes as JSON instead of one line of text per changed model, enum or
+    /// datasource
+    #[structopt(long)]
+    json: bool,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -36,6 +85,14 @@ pub enum FmtOpts {
     Lint(LintOpts),
     /// Specifies format mode
     Format(FormatOpts),
+    /// Applies the automatic fixes for all lint diagnostics, then formats the schema
+    Fix(FixOpts),
+    /// Exports the models, composite types and enums of the schema as JSON Schema
+    JsonSchema(JsonSchemaOpts),
+    /// Summarizes the changes between two schemas, e.g. for code review
+    Diff(DiffOpts),
+    /// Starts a language server speaking the Language Server Protocol over stdio
+    Lsp,
 }
 
 #[derive(serde::Serialize)]
@@ -43,11 +100,49 @@ pub struct MiniError {
     pub start: usize,
     pub end: usize,
     pub text: String,
+    pub severity: Severity,
+    /// The file the error occurs in, when linting a directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Machine-applicable fixes for the error.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<MiniFix>,
+}
+
+#[derive(serde::Serialize)]
+pub struct MiniFix {
+    pub title: String,
+    pub edits: Vec<MiniEdit>,
+}
+
+/// Replaces the text between `start` and `end` with `text`.
+#[derive(serde::Serialize)]
+pub struct MiniEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+    /// The file to edit, when linting a directory. Fixes may edit other files than the one the
+    /// error occurs in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+#[derive(serde::Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The schema is invalid.
+    Error,
+    /// The schema is valid, but likely not what was intended.
+    Warning,
 }
 
 fn main() {
     match FmtOpts::from_args() {
         FmtOpts::Lint(opts) => lint::run(opts),
         FmtOpts::Format(opts) => format::run(opts),
+        FmtOpts::Fix(opts) => fix::run(opts),
+        FmtOpts::JsonSchema(opts) => json_schema::run(opts),
+        FmtOpts::Diff(opts) => diff::run(opts),
+        FmtOpts::Lsp => lsp::run(),
     }
 }
@@ -171,6 +171,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             is_auto_generated_int_id: false,
             behaviour: None,
             internal_enum: None,
+            composite_type: None,
             data_source_field: DataSourceField {
                 name: "id".to_owned(),
                 arity: dml::FieldArity::Optional,
@@ -188,6 +189,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             is_auto_generated_int_id: false,
             behaviour: None,
             internal_enum: None,
+            composite_type: None,
             data_source_field: DataSourceField {
                 name: "name".to_owned(),
                 arity: dml::FieldArity::Optional,
@@ -219,6 +221,7 @@ pub fn test_data_model() -> InternalDataModelRef {
         is_auto_generated_int_id: false,
         behaviour: None,
         internal_enum: None,
+        composite_type: None,
         data_source_field: DataSourceField {
             name: "name".to_owned(),
             arity: dml::FieldArity::Optional,
@@ -231,6 +234,7 @@ pub fn test_data_model() -> InternalDataModelRef {
         ModelTemplate {
             name: "User".to_owned(),
             is_embedded: false,
+            schema: None,
             fields: user_field_templates,
             manifestation: None,
             id_field_names: vec![],
@@ -239,6 +243,7 @@ pub fn test_data_model() -> InternalDataModelRef {
         ModelTemplate {
             name: "Site".to_owned(),
             is_embedded: false,
+            schema: None,
             fields: site_field_templates,
             manifestation: None,
             id_field_names: vec![],
@@ -250,6 +255,7 @@ pub fn test_data_model() -> InternalDataModelRef {
         models: model_templates,
         relations: vec![],
         enums: vec![],
+        composite_types: vec![],
         version: None,
     };
 
@@ -23,6 +23,8 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
         .into_iter()
         .fold(Insert::single_into(model.as_table()), |insert, db_name| {
             let value = args.take_field_value(&db_name).unwrap();
+            let value = field_value(model, &db_name, value);
+
             insert.value(db_name, value)
         });
 
@@ -41,7 +43,8 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
         .args
         .into_iter()
         .fold(Update::table(model.as_table()), |acc, (name, val)| {
-            acc.set(name, val.clone())
+            let val = field_value(model, &name, val);
+            acc.set(name, val)
         });
 
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
@@ -50,6 +53,23 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
     Ok(result)
 }
 
+/// Values of JSON fields, e.g. composite type fields, are passed around as serialized documents. They are bound as
+/// JSON parameters, so databases with a native JSON column type accept them.
+fn field_value(model: &ModelRef, db_name: &str, value: PrismaValue) -> ParameterizedValue<'static> {
+    let is_json = model
+        .map_scalar_db_field_name(db_name)
+        .map(|field| field.type_identifier == TypeIdentifier::Json)
+        .unwrap_or(false);
+
+    match value {
+        PrismaValue::String(s) if is_json => match serde_json::from_str(&s) {
+            Ok(json) => ParameterizedValue::Json(json),
+            Err(_) => ParameterizedValue::from(s),
+        },
+        value => value.into(),
+    }
+}
+
 pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
 
@@ -52,6 +52,18 @@ impl ParsedArgument {
     }
 }
 
+impl ParsedInputValue {
+    /// Converts the value into a JSON document, e.g. to store the value of a composite type field.
+    pub fn into_json(self) -> serde_json::Value {
+        match self {
+            Self::Single(pv) => serde_json::to_value(pv).expect("PrismaValues are always serializable to JSON."),
+            Self::List(values) => serde_json::Value::Array(values.into_iter().map(Self::into_json).collect()),
+            Self::Map(map) => serde_json::Value::Object(map.into_iter().map(|(k, v)| (k, v.into_json())).collect()),
+            Self::OrderBy(_) => unreachable!("Order by values never appear in composite values."),
+        }
+    }
+}
+
 pub trait ArgumentListLookup {
     fn lookup(&mut self, name: &str) -> Option<ParsedArgument>;
 }
@@ -180,7 +180,13 @@ fn handle_scalar_field(
     value: ParsedInputValue,
     op: &FilterOp,
 ) -> QueryGraphBuilderResult<Filter> {
-    let value: PrismaValue = value.try_into()?;
+    let value: PrismaValue = match value {
+        // Composite values are compared as whole documents.
+        ParsedInputValue::Map(map) if field.composite_type.is_some() => {
+            PrismaValue::String(ParsedInputValue::Map(map).into_json().to_string())
+        }
+        value => value.try_into()?,
+    };
     let dsf = field.data_source_field();
 
     Ok(match (op, value) {
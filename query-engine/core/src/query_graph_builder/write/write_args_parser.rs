@@ -36,6 +36,16 @@ impl WriteArgsParser {
 
                         args.args.insert(sf.db_name().clone(), set_value)
                     }
+                    Field::Scalar(sf) if sf.composite_type.is_some() => {
+                        let value = match v {
+                            ParsedInputValue::Map(map) => {
+                                PrismaValue::String(ParsedInputValue::Map(map).into_json().to_string())
+                            }
+                            v => v.try_into()?,
+                        };
+
+                        args.args.insert(sf.db_name().clone(), value)
+                    }
                     Field::Scalar(sf) => {
                         let value: PrismaValue = v.try_into()?;
                         args.args.insert(sf.db_name().clone(), value)
@@ -178,6 +178,9 @@ fn serialize_objects(
 
             if !field.field_type.is_object() {
                 object.insert(scalar_field_name.to_owned(), serialize_scalar(val, &field.field_type)?);
+            } else if model.fields().find_from_scalar(scalar_field_name).is_ok() {
+                // Composite type fields are stored as JSON documents and returned as a whole.
+                object.insert(scalar_field_name.to_owned(), serialize_composite(val)?);
             }
         }
 
@@ -311,6 +314,22 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> crate::Result<It
     }
 }
 
+fn serialize_composite(value: PrismaValue) -> crate::Result<Item> {
+    match value {
+        PrismaValue::Null => Ok(Item::Value(PrismaValue::Null)),
+        PrismaValue::String(s) => serde_json::from_str(&s).map(Item::Json).map_err(|err| {
+            CoreError::SerializationError(format!(
+                "Attempted to serialize invalid composite value '{}': {}",
+                s, err
+            ))
+        }),
+        pv => Err(CoreError::SerializationError(format!(
+            "Attempted to serialize composite value from non-JSON value '{}'",
+            pv
+        ))),
+    }
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -121,6 +121,7 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
+            TypeIdentifier::Json if sf.composite_type.is_some() => vec![&args.base],
             TypeIdentifier::Json => vec![],
         },
     };
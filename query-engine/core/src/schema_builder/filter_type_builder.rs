@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::DataSourceFieldRef;
+use prisma_models::{DataSourceFieldRef, InternalCompositeField, InternalCompositeType, InternalDataModel};
 
 /// Filter object and scalar filter object type builder.
 #[derive(Debug)]
@@ -8,7 +8,15 @@ pub struct FilterObjectTypeBuilder<'a> {
     input_object_cache: TypeRefCache<InputObjectType>,
 }
 
-impl<'a> InputBuilderExtensions for FilterObjectTypeBuilder<'a> {}
+impl<'a> InputBuilderExtensions for FilterObjectTypeBuilder<'a> {
+    fn map_composite_input_type(&self, field: &ScalarFieldRef) -> InputType {
+        let composite_type = field
+            .internal_composite_type()
+            .expect("A field with a composite type must always find its composite type.");
+
+        InputType::object(self.composite_input_object_type(&composite_type, &field.internal_data_model()))
+    }
+}
 
 impl<'a> CachedBuilder<InputObjectType> for FilterObjectTypeBuilder<'a> {
     fn get_cache(&self) -> &TypeRefCache<InputObjectType> {
@@ -120,6 +128,69 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         weak_ref
     }
 
+    /// Builds the input object of a composite type. It is shared by the create, update and filter inputs of all
+    /// fields embedding the composite type.
+    pub fn composite_input_object_type(
+        &self,
+        composite_type: &InternalCompositeType,
+        internal_data_model: &InternalDataModel,
+    ) -> InputObjectTypeRef {
+        let name = format!("{}Input", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|field| {
+                let typ = self.map_composite_field_input_type(field, internal_data_model);
+                let typ = if field.is_list { InputType::list(typ) } else { typ };
+                let typ = if field.is_required { typ } else { InputType::opt(typ) };
+
+                input_field(field.name.clone(), typ, None)
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    fn map_composite_field_input_type(
+        &self,
+        field: &InternalCompositeField,
+        internal_data_model: &InternalDataModel,
+    ) -> InputType {
+        if let Some(ref name) = field.composite_type {
+            let composite_type = internal_data_model
+                .find_composite_type(name)
+                .expect("A field with a composite type must always find its composite type.");
+
+            return InputType::object(self.composite_input_object_type(composite_type, internal_data_model));
+        }
+
+        match field.type_identifier {
+            TypeIdentifier::String => InputType::string(),
+            TypeIdentifier::Int => InputType::int(),
+            TypeIdentifier::Float => InputType::float(),
+            TypeIdentifier::Boolean => InputType::boolean(),
+            TypeIdentifier::UUID => InputType::uuid(),
+            TypeIdentifier::DateTime => InputType::date_time(),
+            TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Enum(ref name) => {
+                let internal_enum = internal_data_model
+                    .enums
+                    .iter()
+                    .find(|internal_enum| &internal_enum.name == name)
+                    .expect("A field with TypeIdentifier Enum must always have an enum.");
+
+                let et: EnumType = internal_enum.clone().into();
+                et.into()
+            }
+        }
+    }
+
     fn build_mongo_filter_object(&self, _model: ModelRef) -> InputObjectTypeRef {
         unimplemented!()
     }
@@ -118,6 +118,6 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
     }
 
     fn field_should_be_kept_for_create_input_type(field: &ScalarFieldRef) -> bool {
-        !field.is_auto_generated_int_id
+        !field.is_auto_generated_int_id && !field.is_read_only()
     }
 }
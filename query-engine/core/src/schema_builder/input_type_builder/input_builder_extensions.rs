@@ -15,6 +15,7 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::Boolean => InputType::boolean(),
             TypeIdentifier::UUID => InputType::uuid(),
             TypeIdentifier::DateTime => InputType::date_time(),
+            TypeIdentifier::Json if field.composite_type.is_some() => self.map_composite_input_type(&field),
             TypeIdentifier::Json => InputType::json(),
             TypeIdentifier::Enum(_) => self.map_enum_input_type(&field),
         };
@@ -36,6 +37,11 @@ pub trait InputBuilderExtensions {
         et.into()
     }
 
+    /// Composite values are plain JSON unless the builder is able to build typed input objects for them.
+    fn map_composite_input_type(&self, _field: &ScalarFieldRef) -> InputType {
+        InputType::json()
+    }
+
     fn map_required_data_source_field_input_type(&self, dsf: &DataSourceFieldRef) -> InputType {
         let typ: TypeIdentifier = dsf.field_type.into();
 
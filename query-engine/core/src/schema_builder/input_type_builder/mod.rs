@@ -274,7 +274,12 @@ impl<'a> InputTypeBuilderBase<'a> for InputTypeBuilder<'a> {
     }
 }
 
-impl<'a> InputBuilderExtensions for InputTypeBuilder<'a> {}
+impl<'a> InputBuilderExtensions for InputTypeBuilder<'a> {
+    fn map_composite_input_type(&self, field: &ScalarFieldRef) -> InputType {
+        self.get_filter_object_builder().map_composite_input_type(field)
+    }
+}
+
 impl<'a> CreateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 impl<'a> UpdateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 
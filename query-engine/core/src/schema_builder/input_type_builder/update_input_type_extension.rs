@@ -36,7 +36,12 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
         self.scalar_input_fields(
             model.name.clone(),
             "Update",
-            model.fields().scalar(),
+            model
+                .fields()
+                .scalar()
+                .into_iter()
+                .filter(|f| !f.is_read_only())
+                .collect(),
             |f: ScalarFieldRef| self.map_optional_input_type(&f),
             false,
         )
@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::OrderBy;
+use prisma_models::{InternalCompositeField, InternalCompositeType, OrderBy};
 
 #[derive(Debug)]
 pub struct ObjectTypeBuilder<'a> {
@@ -49,6 +49,12 @@ impl<'a> ObjectTypeBuilder<'a> {
             .expect("Invariant violation: Initialized object type skeleton for each model.")
     }
 
+    pub fn map_composite_object_type(&self, name: &str) -> ObjectTypeRef {
+        self.get_cache()
+            .get(name)
+            .expect("Invariant violation: Initialized object type skeleton for each composite type.")
+    }
+
     /// Initializes model object type cache on the query schema builder.
     fn compute_model_object_types(self) -> Self {
         // Compute initial cache.
@@ -59,6 +65,19 @@ impl<'a> ObjectTypeBuilder<'a> {
             )
         });
 
+        self.internal_data_model
+            .composite_types
+            .iter()
+            .for_each(|ct| self.cache(ct.name.clone(), Arc::new(ObjectType::new(ct.name.clone(), None))));
+
+        // Compute fields on all cached composite object types.
+        self.internal_data_model.composite_types.iter().for_each(|ct| {
+            let obj: ObjectTypeRef = self.map_composite_object_type(&ct.name);
+            let fields = self.compute_composite_fields(ct);
+
+            obj.into_arc().set_fields(fields);
+        });
+
         // Compute fields on all cached object types.
         self.internal_data_model.models().iter().for_each(|m| {
             let obj: ObjectTypeRef = self.map_model_object_type(m);
@@ -84,6 +103,48 @@ impl<'a> ObjectTypeBuilder<'a> {
             .collect()
     }
 
+    fn compute_composite_fields(&self, composite_type: &InternalCompositeType) -> Vec<Field> {
+        composite_type
+            .fields
+            .iter()
+            .map(|f| field(f.name.clone(), vec![], self.map_composite_field_output_type(f), None))
+            .collect()
+    }
+
+    fn map_composite_field_output_type(&self, composite_field: &InternalCompositeField) -> OutputType {
+        let output_type = match (&composite_field.composite_type, &composite_field.type_identifier) {
+            (Some(name), _) => OutputType::object(self.map_composite_object_type(name)),
+            (None, TypeIdentifier::Enum(name)) => {
+                let internal_enum = self
+                    .internal_data_model
+                    .enums
+                    .iter()
+                    .find(|internal_enum| &internal_enum.name == name)
+                    .expect(
+                        "Invariant violation: Enum fields are expected to have an internal_enum associated with them.",
+                    );
+
+                let et: EnumType = internal_enum.clone().into();
+                et.into()
+            }
+            (None, TypeIdentifier::String) => OutputType::string(),
+            (None, TypeIdentifier::Float) => OutputType::float(),
+            (None, TypeIdentifier::Boolean) => OutputType::boolean(),
+            (None, TypeIdentifier::Json) => OutputType::json(),
+            (None, TypeIdentifier::DateTime) => OutputType::date_time(),
+            (None, TypeIdentifier::UUID) => OutputType::uuid(),
+            (None, TypeIdentifier::Int) => OutputType::int(),
+        };
+
+        if composite_field.is_list {
+            OutputType::list(output_type)
+        } else if !composite_field.is_required {
+            OutputType::opt(output_type)
+        } else {
+            output_type
+        }
+    }
+
     pub fn map_field(&self, model_field: &ModelField) -> Field {
         field(
             model_field.name(),
@@ -109,6 +170,9 @@ impl<'a> ObjectTypeBuilder<'a> {
                 TypeIdentifier::Float => OutputType::float(),
                 TypeIdentifier::Boolean => OutputType::boolean(),
                 TypeIdentifier::Enum(_) => Self::map_enum_field(sf).into(),
+                TypeIdentifier::Json if sf.composite_type.is_some() => {
+                    OutputType::object(self.map_composite_object_type(sf.composite_type.as_ref().unwrap()))
+                }
                 TypeIdentifier::Json => OutputType::json(),
                 TypeIdentifier::DateTime => OutputType::date_time(),
                 TypeIdentifier::UUID => OutputType::uuid(),
@@ -172,7 +236,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             .all
             .iter()
             .filter(|field| match field {
-                ModelField::Scalar(sf) => !sf.is_list,
+                ModelField::Scalar(sf) => !sf.is_list && sf.composite_type.is_none(),
                 ModelField::Relation(rf) => {
                     !rf.relation().is_many_to_many()
                         && rf.is_inlined_on_enclosing_model()
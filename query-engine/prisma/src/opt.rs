@@ -1,7 +1,7 @@
 use crate::{error::PrismaError, PrismaResult};
-use datamodel::{Configuration, Datamodel};
+use datamodel::{Configuration, Datamodel, SourceFile};
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{ffi::OsStr, path::Path};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -44,9 +44,9 @@ pub struct PrismaOpt {
     /// The port the query engine should bind to.
     #[structopt(long, short, env, default_value = "4466")]
     pub port: u16,
-    /// Path to the Prisma datamodel file
-    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_file))]
-    datamodel_path: Option<String>,
+    /// Path to the Prisma datamodel file, or to a directory of `.prisma` files
+    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_files))]
+    datamodel_path: Option<DatamodelFiles>,
     /// Base64 encoded Prisma datamodel
     #[structopt(long, env = "PRISMA_DML", parse(try_from_str = parse_base64_string))]
     datamodel: Option<String>,
@@ -76,47 +76,42 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
-    fn datamodel_str(&self) -> PrismaResult<&str> {
-        let res = self
-            .datamodel
-            .as_ref()
-            .map(|dm| dm.as_str())
-            .or(self.datamodel_path.as_ref().map(|dm| dm.as_str()))
-            .ok_or_else(|| {
-                PrismaError::ConfigurationError(
-                    "Datamodel should be provided either as path or base64-encoded string.".into(),
-                )
-            })?;
-
-        Ok(res)
+    fn datamodel_files(&self) -> PrismaResult<Vec<SourceFile>> {
+        match (&self.datamodel, &self.datamodel_path) {
+            (Some(datamodel), _) => Ok(vec![SourceFile::new("schema.prisma", datamodel)]),
+            (None, Some(DatamodelFiles(files))) => Ok(files.clone()),
+            (None, None) => Err(PrismaError::ConfigurationError(
+                "Datamodel should be provided either as path or base64-encoded string.".into(),
+            )),
+        }
     }
 
     pub fn datamodel(&self, ignore_env_errors: bool) -> PrismaResult<Datamodel> {
-        let datamodel_str = self.datamodel_str()?;
+        let files = self.datamodel_files()?;
 
         let datamodel = if ignore_env_errors {
-            datamodel::parse_datamodel_and_ignore_env_errors(datamodel_str)
+            datamodel::parse_datamodel_from_files_and_ignore_env_errors(&files)
         } else {
-            datamodel::parse_datamodel(datamodel_str)
+            datamodel::parse_datamodel_from_files(&files)
         };
 
         match datamodel {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, files)),
             _ => Ok(datamodel?),
         }
     }
 
     pub fn configuration(&self, ignore_env_errors: bool) -> PrismaResult<Configuration> {
-        let datamodel_str = self.datamodel_str()?;
+        let files = self.datamodel_files()?;
 
         let config_result = if ignore_env_errors {
-            datamodel::parse_configuration_and_ignore_env_errors(datamodel_str)
+            datamodel::parse_configuration_from_files_and_ignore_env_errors(&files)
         } else {
-            datamodel::parse_configuration(datamodel_str)
+            datamodel::parse_configuration_from_files(&files)
         };
 
         match config_result {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, files)),
             Ok(mut configuration) => {
                 if let Some(ref overwrites) = self.overwrite_datasources {
                     let datasource_overwrites: Vec<SourceOverride> = serde_json::from_str(&overwrites)?;
@@ -153,12 +148,12 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
-fn load_datamodel_file(path: &OsStr) -> String {
-    let mut f = File::open(path).expect(&format!("Could not open datamodel file {:?}", path));
-    let mut datamodel = String::new();
+/// The files of a datamodel loaded from disk.
+#[derive(Debug, Clone)]
+pub struct DatamodelFiles(Vec<SourceFile>);
 
-    f.read_to_string(&mut datamodel)
-        .expect(&format!("Could not read datamodel file: {:?}", path));
+fn load_datamodel_files(path: &OsStr) -> DatamodelFiles {
+    let files = SourceFile::load_all(Path::new(path)).expect(&format!("Could not read datamodel file {:?}", path));
 
-    datamodel
+    DatamodelFiles(files)
 }
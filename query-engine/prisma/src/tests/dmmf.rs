@@ -1,7 +1,7 @@
 use prisma_models::DatamodelConverter;
-use query_core::{BuildMode, QuerySchema, QuerySchemaBuilder, SupportedCapabilities};
+use query_core::{BuildMode, QueryDocumentParser, QuerySchema, QuerySchemaBuilder, QueryValue, SupportedCapabilities};
 use serial_test::serial;
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 // Tests in this file run serially because the function `get_query_schema` depends on setting an env var.
 
@@ -96,6 +96,69 @@ fn must_not_fail_on_missing_env_vars_in_a_datasource() {
         .expect("finding BlogCreateInput");
 }
 
+#[test]
+#[serial]
+fn computed_fields_are_not_part_of_create_and_update_inputs() {
+    let dm = r#"
+        model Order {
+            id    Int @id
+            price Int
+            total Int @computed("price * 2")
+        }
+    "#;
+
+    let (query_schema, datamodel) = get_query_schema(dm);
+
+    let dmmf = crate::dmmf::render_dmmf(&datamodel, Arc::new(query_schema));
+
+    let inputs = &dmmf.schema.input_types;
+
+    for input_name in &["OrderCreateInput", "OrderUpdateInput", "OrderUpdateManyMutationInput"] {
+        let input = inputs
+            .iter()
+            .find(|input| input.name == *input_name)
+            .unwrap_or_else(|| panic!("finding {}", input_name));
+
+        let field_names: Vec<&str> = input.fields.iter().map(|f| f.name.as_str()).collect();
+
+        assert!(field_names.contains(&"price"), "{} has no `price` field", input_name);
+        assert!(!field_names.contains(&"total"), "{} has a `total` field", input_name);
+    }
+}
+
+#[test]
+#[serial]
+fn writing_a_computed_field_is_rejected() {
+    let dm = r#"
+        model Order {
+            id    Int @id
+            price Int
+            total Int @computed("price * 2")
+        }
+    "#;
+
+    let (query_schema, _) = get_query_schema(dm);
+
+    let create_order = query_schema
+        .find_mutation_field("createOneOrder")
+        .expect("finding createOneOrder");
+
+    let data = |with_total: bool| {
+        let mut data = BTreeMap::new();
+        data.insert("id".to_owned(), QueryValue::Int(1));
+        data.insert("price".to_owned(), QueryValue::Int(10));
+
+        if with_total {
+            data.insert("total".to_owned(), QueryValue::Int(20));
+        }
+
+        vec![("data".to_owned(), QueryValue::Object(data))]
+    };
+
+    assert!(QueryDocumentParser::parse_arguments(&create_order, &data(false)).is_ok());
+    assert!(QueryDocumentParser::parse_arguments(&create_order, &data(true)).is_err());
+}
+
 fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
     let dm = datamodel::parse_datamodel_and_ignore_env_errors(datamodel_string).unwrap();
     let internal_dm_template = DatamodelConverter::convert(&dm);
@@ -57,6 +57,7 @@ impl TestApi {
             assume_to_be_applied: Some(Vec::new()),
             datamodel: datamodel_string.clone(),
             migration_id: migration_id.clone(),
+            confirmed_renames: None,
         };
 
         self.migration_api.reset(&serde_json::Value::Null).await?;
@@ -66,6 +67,7 @@ impl TestApi {
             force: Some(true),
             migration_id,
             steps: result.datamodel_steps,
+            dry_run: None,
         };
 
         self.migration_api.apply_migration(&apply_input).await?;